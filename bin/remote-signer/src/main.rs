@@ -0,0 +1,110 @@
+use std::{env, fs, net::SocketAddr, path::Path};
+
+use miden_objects::{
+    account::AuthSecretKey,
+    crypto::dsa::rpo_falcon512::SecretKey,
+    utils::{Deserializable, Serializable},
+    Digest, Word,
+};
+use miden_tx::auth::{BasicAuthenticator, SignerServer};
+use rand::rngs::StdRng;
+
+const USAGE: &str = "Usage:
+    remote-signer generate <KEY_FILE>
+    remote-signer serve [--no-verify-messages] [--allow-non-loopback] <ADDRESS> <KEY_FILE>...";
+
+fn main() -> Result<(), String> {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+        ["generate", key_file] => generate_key(Path::new(key_file)),
+        ["serve", args @ ..] => {
+            let mut options = ServeOptions {
+                verify_messages: true,
+                allow_non_loopback: false,
+            };
+            let mut args = args;
+            while let [flag, rest @ ..] = args {
+                match *flag {
+                    "--no-verify-messages" => options.verify_messages = false,
+                    "--allow-non-loopback" => options.allow_non_loopback = true,
+                    flag if flag.starts_with("--") => return Err(USAGE.to_string()),
+                    _ => break,
+                }
+                args = rest;
+            }
+
+            match args {
+                [address, key_files @ ..] if !key_files.is_empty() => {
+                    serve(address, key_files.iter().map(Path::new), options)
+                },
+                _ => Err(USAGE.to_string()),
+            }
+        },
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+/// The options of the `serve` command.
+struct ServeOptions {
+    /// If true, requests for messages which were not derived from the signing request sent along
+    /// with them are rejected.
+    verify_messages: bool,
+    /// If true, the signer may listen at addresses other than loopback addresses. Since the signer
+    /// does not authenticate its clients, this exposes the keys to anyone who can reach the
+    /// address.
+    allow_non_loopback: bool,
+}
+
+/// Generates a new RPO Falcon 512 secret key, writes it to the specified file, and prints the
+/// corresponding public key.
+fn generate_key(key_file: &Path) -> Result<(), String> {
+    let secret_key = SecretKey::new();
+    let pub_key = Digest::from(Word::from(secret_key.public_key()));
+
+    fs::write(key_file, AuthSecretKey::RpoFalcon512(secret_key).to_bytes())
+        .map_err(|err| format!("failed to write key file {}: {err}", key_file.display()))?;
+
+    println!("{pub_key}");
+    Ok(())
+}
+
+/// Serves signature requests at the specified address using the keys in the specified files.
+fn serve<'a>(
+    address: &str,
+    key_files: impl Iterator<Item = &'a Path>,
+    options: ServeOptions,
+) -> Result<(), String> {
+    let address: SocketAddr =
+        address.parse().map_err(|err| format!("invalid address {address}: {err}"))?;
+    if !address.ip().is_loopback() && !options.allow_non_loopback {
+        return Err(format!(
+            "refusing to listen at non-loopback address {address}, as the signer does not \
+             authenticate its clients; pass --allow-non-loopback to listen at it anyway"
+        ));
+    }
+
+    let mut keys = Vec::new();
+    for key_file in key_files {
+        let bytes = fs::read(key_file)
+            .map_err(|err| format!("failed to read key file {}: {err}", key_file.display()))?;
+        let secret_key = AuthSecretKey::read_from_bytes(&bytes)
+            .map_err(|err| format!("failed to parse key file {}: {err}", key_file.display()))?;
+
+        let pub_key = match &secret_key {
+            AuthSecretKey::RpoFalcon512(secret_key) => Word::from(secret_key.public_key()),
+        };
+        println!("serving signatures for public key {}", Digest::from(pub_key));
+        keys.push((pub_key, secret_key));
+    }
+
+    let authenticator = BasicAuthenticator::<StdRng>::new(&keys);
+    let mut server = SignerServer::bind(address, authenticator)
+        .map_err(|err| format!("failed to listen at {address}: {err}"))?;
+    if !options.verify_messages {
+        server = server.without_message_verification();
+    }
+
+    println!("listening at {address}");
+    server.serve().map_err(|err| format!("failed to accept connection: {err}"))
+}
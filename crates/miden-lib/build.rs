@@ -296,18 +296,40 @@ const RPO_FALCON_AUTH_CODE: &str = "
     export.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
 ";
 
+const RPO_FALCON_MULTISIG_AUTH_CODE: &str = "
+    export.::miden::contracts::auth::multisig::auth_tx_rpo_falcon512_multisig
+";
+
 const BASIC_FUNGIBLE_FAUCET_CODE: &str = "
     export.::miden::contracts::faucets::basic_fungible::distribute
     export.::miden::contracts::faucets::basic_fungible::burn
 ";
 
+const BASIC_NON_FUNGIBLE_FAUCET_CODE: &str = "
+    export.::miden::contracts::faucets::basic_non_fungible::distribute
+    export.::miden::contracts::faucets::basic_non_fungible::burn
+    export.::miden::contracts::faucets::basic_non_fungible::is_issued
+";
+
+const ROLE_BASED_FUNGIBLE_FAUCET_CODE: &str = "
+    export.::miden::contracts::faucets::role_based_fungible::distribute
+    export.::miden::contracts::faucets::role_based_fungible::burn
+    export.::miden::contracts::faucets::role_based_fungible::set_roles
+    export.::miden::contracts::faucets::role_based_fungible::pause
+    export.::miden::contracts::faucets::role_based_fungible::unpause
+    export.::miden::contracts::faucets::role_based_fungible::set_max_supply
+";
+
 /// Compiles the default account components into a MASL library and stores the complied files in
 /// `target_dir`.
 fn compile_account_components(target_dir: &Path, assembler: Assembler) -> Result<()> {
     for (component_name, component_code) in [
         ("basic_wallet", BASIC_WALLET_CODE),
         ("rpo_falcon_512", RPO_FALCON_AUTH_CODE),
+        ("rpo_falcon_512_multisig", RPO_FALCON_MULTISIG_AUTH_CODE),
         ("basic_fungible_faucet", BASIC_FUNGIBLE_FAUCET_CODE),
+        ("basic_non_fungible_faucet", BASIC_NON_FUNGIBLE_FAUCET_CODE),
+        ("role_based_fungible_faucet", ROLE_BASED_FUNGIBLE_FAUCET_CODE),
     ] {
         let component_library = assembler.clone().assemble_library([component_code])?;
         let component_file_path =
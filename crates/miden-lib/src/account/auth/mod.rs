@@ -1,9 +1,12 @@
+use alloc::{collections::BTreeSet, vec::Vec};
+
 use miden_objects::{
-    account::{AccountComponent, StorageSlot},
+    account::{AccountComponent, StorageMap, StorageSlot},
     crypto::dsa::rpo_falcon512::PublicKey,
+    AccountError, Digest, Felt, Word, ZERO,
 };
 
-use crate::account::components::rpo_falcon_512_library;
+use crate::account::components::{rpo_falcon_512_library, rpo_falcon_512_multisig_library};
 
 /// An [`AccountComponent`] implementing the RpoFalcon512 signature scheme for authentication of
 /// transactions.
@@ -34,3 +37,83 @@ impl From<RpoFalcon512> for AccountComponent {
         .with_supports_all_types()
     }
 }
+
+/// An [`AccountComponent`] implementing M-of-N multi-signature authentication of transactions
+/// using the RpoFalcon512 signature scheme.
+///
+/// Its exported procedures are:
+/// - `auth_tx_rpo_falcon512_multisig`, which asks the host for a signature by each of the
+///   approvers, verifies the provided signatures and fails unless at least `threshold` of them are
+///   valid. The signatures are provided via the advice stack.
+///
+/// The storage layout of this component is:
+/// - Slot 0: `[threshold, num_approvers, 0, 0]`.
+/// - Slot 1: a map from `[0, 0, 0, i]` to the public key of the i-th approver.
+///
+/// This component supports all account types.
+pub struct RpoFalcon512Multisig {
+    threshold: u32,
+    approvers: Vec<PublicKey>,
+}
+
+impl RpoFalcon512Multisig {
+    /// Creates a new [`RpoFalcon512Multisig`] component requiring signatures by `threshold` of the
+    /// given `approvers`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `threshold` is zero or greater than the number of approvers.
+    /// - the same public key is contained in `approvers` more than once.
+    pub fn new(threshold: u32, approvers: Vec<PublicKey>) -> Result<Self, AccountError> {
+        if threshold == 0 || threshold as usize > approvers.len() {
+            return Err(AccountError::MultisigInvalidThreshold {
+                threshold,
+                num_approvers: approvers.len(),
+            });
+        }
+
+        let mut unique_approvers = BTreeSet::new();
+        for approver in approvers.iter() {
+            let pub_key = Digest::from(Word::from(*approver));
+            if !unique_approvers.insert(pub_key) {
+                return Err(AccountError::MultisigDuplicateApprover(pub_key));
+            }
+        }
+
+        Ok(Self { threshold, approvers })
+    }
+
+    /// Returns the number of approvers whose signatures are required to authenticate a
+    /// transaction.
+    pub fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    /// Returns the public keys of the approvers.
+    pub fn approvers(&self) -> &[PublicKey] {
+        &self.approvers
+    }
+}
+
+impl From<RpoFalcon512Multisig> for AccountComponent {
+    fn from(multisig: RpoFalcon512Multisig) -> Self {
+        let threshold_config = [
+            Felt::from(multisig.threshold),
+            Felt::from(multisig.approvers.len() as u32),
+            ZERO,
+            ZERO,
+        ];
+        let approvers = StorageMap::with_entries(multisig.approvers.into_iter().enumerate().map(
+            |(idx, pub_key)| {
+                (Digest::from([ZERO, ZERO, ZERO, Felt::from(idx as u32)]), Word::from(pub_key))
+            },
+        ));
+
+        AccountComponent::new(
+            rpo_falcon_512_multisig_library(),
+            vec![StorageSlot::Value(threshold_config), StorageSlot::Map(approvers)],
+        )
+        .expect("multisig component should satisfy the requirements of a valid account component")
+        .with_supports_all_types()
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "std")]
+use miden_objects::account::{AccountComponentMetadata, AccountComponentTemplate};
 use miden_objects::{
     assembly::Library,
     utils::{sync::LazyLock, Deserializable},
@@ -17,6 +19,15 @@ static RPO_FALCON_512_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
     Library::read_from_bytes(bytes).expect("Shipped Rpo Falcon 512 library is well-formed")
 });
 
+// Initialize the Rpo Falcon 512 Multisig library only once.
+static RPO_FALCON_512_MULTISIG_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(
+        env!("OUT_DIR"),
+        "/assets/account_components/rpo_falcon_512_multisig.masl"
+    ));
+    Library::read_from_bytes(bytes).expect("Shipped Rpo Falcon 512 Multisig library is well-formed")
+});
+
 // Initialize the Basic Fungible Faucet library only once.
 static BASIC_FUNGIBLE_FAUCET_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
     let bytes = include_bytes!(concat!(
@@ -26,6 +37,33 @@ static BASIC_FUNGIBLE_FAUCET_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
     Library::read_from_bytes(bytes).expect("Shipped Basic Fungible Faucet library is well-formed")
 });
 
+// Initialize the Basic Non-Fungible Faucet library only once.
+static BASIC_NON_FUNGIBLE_FAUCET_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(
+        env!("OUT_DIR"),
+        "/assets/account_components/basic_non_fungible_faucet.masl"
+    ));
+    Library::read_from_bytes(bytes)
+        .expect("Shipped Basic Non-Fungible Faucet library is well-formed")
+});
+
+// Initialize the Role-Based Fungible Faucet library only once.
+static ROLE_BASED_FUNGIBLE_FAUCET_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(
+        env!("OUT_DIR"),
+        "/assets/account_components/role_based_fungible_faucet.masl"
+    ));
+    Library::read_from_bytes(bytes)
+        .expect("Shipped Role-Based Fungible Faucet library is well-formed")
+});
+
+/// The component template metadata of the Role-Based Fungible Faucet.
+#[cfg(feature = "std")]
+const ROLE_BASED_FUNGIBLE_FAUCET_METADATA: &str = include_str!(concat!(
+    env!("OUT_DIR"),
+    "/asm/account_components/role_based_fungible_faucet.toml"
+));
+
 /// Returns the Basic Wallet Library.
 pub fn basic_wallet_library() -> Library {
     BASIC_WALLET_LIBRARY.clone()
@@ -36,7 +74,31 @@ pub fn rpo_falcon_512_library() -> Library {
     RPO_FALCON_512_LIBRARY.clone()
 }
 
+/// Returns the Rpo Falcon 512 Multisig Library.
+pub fn rpo_falcon_512_multisig_library() -> Library {
+    RPO_FALCON_512_MULTISIG_LIBRARY.clone()
+}
+
 /// Returns the Basic Fungible Faucet Library.
 pub fn basic_fungible_faucet_library() -> Library {
     BASIC_FUNGIBLE_FAUCET_LIBRARY.clone()
 }
+
+/// Returns the Basic Non-Fungible Faucet Library.
+pub fn basic_non_fungible_faucet_library() -> Library {
+    BASIC_NON_FUNGIBLE_FAUCET_LIBRARY.clone()
+}
+
+/// Returns the Role-Based Fungible Faucet Library.
+pub fn role_based_fungible_faucet_library() -> Library {
+    ROLE_BASED_FUNGIBLE_FAUCET_LIBRARY.clone()
+}
+
+/// Returns the component template of the Role-Based Fungible Faucet.
+#[cfg(feature = "std")]
+pub fn role_based_fungible_faucet_template() -> AccountComponentTemplate {
+    let metadata = AccountComponentMetadata::from_toml(ROLE_BASED_FUNGIBLE_FAUCET_METADATA)
+        .expect("Shipped Role-Based Fungible Faucet metadata is well-formed");
+
+    AccountComponentTemplate::new(metadata, role_based_fungible_faucet_library())
+}
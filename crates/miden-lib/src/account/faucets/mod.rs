@@ -1,14 +1,27 @@
+use alloc::collections::BTreeMap;
+
 use miden_objects::{
     account::{
-        Account, AccountBuilder, AccountComponent, AccountIdAnchor, AccountStorageMode,
-        AccountType, StorageSlot,
+        Account, AccountBuilder, AccountComponent, AccountId, AccountIdAnchor, AccountStorageMode,
+        AccountType, StorageMap, StorageSlot,
     },
     asset::{FungibleAsset, TokenSymbol},
-    AccountError, Felt, FieldElement, Word,
+    AccountError, Digest, Felt, FieldElement, Word,
 };
 
+#[cfg(feature = "std")]
+use miden_objects::account::AccountComponentTemplate;
+
 use super::AuthScheme;
-use crate::account::{auth::RpoFalcon512, components::basic_fungible_faucet_library};
+#[cfg(feature = "std")]
+use crate::account::components::role_based_fungible_faucet_template;
+use crate::account::{
+    auth::RpoFalcon512,
+    components::{
+        basic_fungible_faucet_library, basic_non_fungible_faucet_library,
+        role_based_fungible_faucet_library,
+    },
+};
 
 // BASIC FUNGIBLE FAUCET ACCOUNT COMPONENT
 // ================================================================================================
@@ -57,14 +70,21 @@ impl BasicFungibleFaucet {
 
         Ok(Self { symbol, decimals, max_supply })
     }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the token metadata of the faucet as it is stored in account storage.
+    fn metadata_word(&self) -> Word {
+        // Note: data is stored as [a0, a1, a2, a3] but loaded onto the stack as
+        // [a3, a2, a1, a0, ...]
+        [self.max_supply, Felt::from(self.decimals), self.symbol.into(), Felt::ZERO]
+    }
 }
 
 impl From<BasicFungibleFaucet> for AccountComponent {
     fn from(faucet: BasicFungibleFaucet) -> Self {
-        // Note: data is stored as [a0, a1, a2, a3] but loaded onto the stack as
-        // [a3, a2, a1, a0, ...]
-        let metadata =
-            [faucet.max_supply, Felt::from(faucet.decimals), faucet.symbol.into(), Felt::ZERO];
+        let metadata = faucet.metadata_word();
 
         AccountComponent::new(basic_fungible_faucet_library(), vec![StorageSlot::Value(metadata)])
             .expect("basic fungible faucet component should satisfy the requirements of a valid account component")
@@ -72,6 +92,205 @@ impl From<BasicFungibleFaucet> for AccountComponent {
     }
 }
 
+// BASIC NON-FUNGIBLE FAUCET ACCOUNT COMPONENT
+// ================================================================================================
+
+/// An [`AccountComponent`] implementing a basic non-fungible faucet.
+///
+/// Its exported procedures are:
+/// - `distribute`, which mints a non-fungible asset and creates a note for the provided recipient.
+/// - `burn`, which burns the provided non-fungible asset.
+/// - `is_issued`, which returns whether the provided non-fungible asset is issued.
+///
+/// The non-fungible asset to mint is built from its [`NonFungibleAssetDetails`] via
+/// [`NonFungibleAsset::new`](miden_objects::asset::NonFungibleAsset::new). The issued assets are
+/// tracked in the reserved storage map of the faucet, which prevents minting the same asset twice.
+///
+/// Both `distribute` and `burn` require authentication, so that only the owner of the faucet can
+/// mint and burn assets. Thus, this component must be combined with a component providing
+/// authentication.
+///
+/// This component supports accounts of type [`AccountType::NonFungibleFaucet`].
+///
+/// [`NonFungibleAssetDetails`]: miden_objects::asset::NonFungibleAssetDetails
+pub struct BasicNonFungibleFaucet;
+
+impl From<BasicNonFungibleFaucet> for AccountComponent {
+    fn from(_: BasicNonFungibleFaucet) -> Self {
+        AccountComponent::new(basic_non_fungible_faucet_library(), vec![])
+            .expect("basic non-fungible faucet component should satisfy the requirements of a valid account component")
+            .with_supported_type(AccountType::NonFungibleFaucet)
+    }
+}
+
+// ROLE-BASED FUNGIBLE FAUCET ACCOUNT COMPONENT
+// ================================================================================================
+
+/// The roles of an account in a [`RoleBasedFungibleFaucet`].
+///
+/// An account can have any combination of the admin, minter and burner roles. The mint allowance
+/// is the amount a minter may still mint, and is decreased by every mint.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FaucetRoles {
+    is_admin: bool,
+    is_minter: bool,
+    is_burner: bool,
+    mint_allowance: Felt,
+}
+
+impl FaucetRoles {
+    /// Returns a new [`FaucetRoles`] without any role.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Grants the admin role, which allows setting the roles of accounts, pausing and unpausing
+    /// the faucet, and setting its maximum supply.
+    pub fn with_admin(mut self) -> Self {
+        self.is_admin = true;
+        self
+    }
+
+    /// Grants the minter role with the provided mint allowance.
+    pub fn with_minter(mut self, mint_allowance: Felt) -> Self {
+        self.is_minter = true;
+        self.mint_allowance = mint_allowance;
+        self
+    }
+
+    /// Grants the burner role.
+    pub fn with_burner(mut self) -> Self {
+        self.is_burner = true;
+        self
+    }
+
+    /// Returns `true` if the admin role is granted.
+    pub fn is_admin(&self) -> bool {
+        self.is_admin
+    }
+
+    /// Returns `true` if the minter role is granted.
+    pub fn is_minter(&self) -> bool {
+        self.is_minter
+    }
+
+    /// Returns `true` if the burner role is granted.
+    pub fn is_burner(&self) -> bool {
+        self.is_burner
+    }
+
+    /// Returns the mint allowance.
+    pub fn mint_allowance(&self) -> Felt {
+        self.mint_allowance
+    }
+}
+
+impl From<FaucetRoles> for Word {
+    fn from(roles: FaucetRoles) -> Self {
+        [
+            Felt::from(roles.is_admin),
+            Felt::from(roles.is_minter),
+            Felt::from(roles.is_burner),
+            roles.mint_allowance,
+        ]
+    }
+}
+
+/// An [`AccountComponent`] implementing a fungible faucet whose minting, burning and
+/// administration are gated by the roles of the requesting accounts.
+///
+/// Its exported procedures are:
+/// - `distribute`, which mints an asset and creates a note for the provided recipient. It requires
+///   the minter role and decreases the mint allowance of the minter by the minted amount.
+/// - `burn`, which burns the provided asset. It requires the burner role.
+/// - `set_roles`, which sets the [`FaucetRoles`] of an account. It requires the admin role.
+/// - `pause` and `unpause`, which prevent and allow minting and burning. They require the admin
+///   role.
+/// - `set_max_supply`, which sets the maximum supply of the token. It requires the admin role.
+///
+/// Requests are made by sending notes to the faucet, and the procedures check the roles of the
+/// sender of the note which invokes them. Thus, the procedures can only be invoked from note
+/// scripts, and the component does not need to be combined with a component providing
+/// authentication.
+///
+/// The storage layout of this component is:
+/// - Slot 0: Token metadata of the faucet, laid out as in [`BasicFungibleFaucet`].
+/// - Slot 1: Pause flag of the faucet.
+/// - Slot 2: Map from [`RoleBasedFungibleFaucet::roles_key`] to the [`FaucetRoles`] of accounts.
+///
+/// This component supports accounts of type [`AccountType::FungibleFaucet`]. A matching
+/// component template is returned by [`RoleBasedFungibleFaucet::template`].
+pub struct RoleBasedFungibleFaucet {
+    metadata: BasicFungibleFaucet,
+    roles: BTreeMap<AccountId, FaucetRoles>,
+}
+
+impl RoleBasedFungibleFaucet {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Creates a new [`RoleBasedFungibleFaucet`] component from the given pieces of metadata, with
+    /// the admin role granted to `admin`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the metadata is invalid, see [`BasicFungibleFaucet::new`].
+    pub fn new(
+        symbol: TokenSymbol,
+        decimals: u8,
+        max_supply: Felt,
+        admin: AccountId,
+    ) -> Result<Self, AccountError> {
+        let metadata = BasicFungibleFaucet::new(symbol, decimals, max_supply)?;
+        let roles = BTreeMap::from([(admin, FaucetRoles::new().with_admin())]);
+
+        Ok(Self { metadata, roles })
+    }
+
+    /// Sets the initial roles of the provided account, replacing any roles set previously.
+    pub fn with_roles(mut self, account_id: AccountId, roles: FaucetRoles) -> Self {
+        self.roles.insert(account_id, roles);
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the component template of the role-based fungible faucet.
+    ///
+    /// The template expects the following placeholders to be provided at instantiation:
+    /// - `token_metadata.max_supply`, `token_metadata.decimals` and `token_metadata.symbol`, which
+    ///   are the token metadata of the faucet.
+    /// - `admin.prefix` and `admin.suffix`, which are the prefix and suffix of the ID of the
+    ///   account which is granted the admin role.
+    #[cfg(feature = "std")]
+    pub fn template() -> AccountComponentTemplate {
+        role_based_fungible_faucet_template()
+    }
+
+    /// Returns the key under which the roles of the provided account are stored in the roles map.
+    pub fn roles_key(account_id: AccountId) -> Digest {
+        Digest::from([Felt::ZERO, Felt::ZERO, account_id.suffix(), account_id.prefix().as_felt()])
+    }
+}
+
+impl From<RoleBasedFungibleFaucet> for AccountComponent {
+    fn from(faucet: RoleBasedFungibleFaucet) -> Self {
+        let metadata = faucet.metadata.metadata_word();
+        let roles =
+            StorageMap::with_entries(faucet.roles.into_iter().map(|(account_id, roles)| {
+                (RoleBasedFungibleFaucet::roles_key(account_id), roles.into())
+            }));
+
+        AccountComponent::new(
+            role_based_fungible_faucet_library(),
+            vec![StorageSlot::Value(metadata), StorageSlot::empty_value(), StorageSlot::Map(roles)],
+        )
+        .expect("role-based fungible faucet component should satisfy the requirements of a valid account component")
+        .with_supported_type(AccountType::FungibleFaucet)
+    }
+}
+
 // FUNGIBLE FAUCET
 // ================================================================================================
 
@@ -116,17 +335,105 @@ pub fn create_basic_fungible_faucet(
     Ok((account, account_seed))
 }
 
+// NON-FUNGIBLE FAUCET
+// ================================================================================================
+
+/// Creates a new faucet account with basic non-fungible faucet interface, account storage type and
+/// specified authentication scheme.
+///
+/// The basic non-fungible faucet interface exposes three procedures:
+/// - `distribute`, which mints a non-fungible asset and creates a note for the provided recipient.
+/// - `burn`, which burns the provided non-fungible asset.
+/// - `is_issued`, which returns whether the provided non-fungible asset is issued.
+///
+/// `distribute` and `burn` require authentication. The authentication procedure is defined by the
+/// specified authentication scheme.
+///
+/// The storage layout of the faucet account is:
+/// - Slot 0: Reserved slot for faucets, which holds the map of issued non-fungible assets.
+/// - Slot 1: Public Key of the authentication component.
+pub fn create_basic_non_fungible_faucet(
+    init_seed: [u8; 32],
+    id_anchor: AccountIdAnchor,
+    account_storage_mode: AccountStorageMode,
+    auth_scheme: AuthScheme,
+) -> Result<(Account, Word), AccountError> {
+    let auth_component: RpoFalcon512 = match auth_scheme {
+        AuthScheme::RpoFalcon512 { pub_key } => RpoFalcon512::new(pub_key),
+    };
+
+    let (account, account_seed) = AccountBuilder::new(init_seed)
+        .anchor(id_anchor)
+        .account_type(AccountType::NonFungibleFaucet)
+        .storage_mode(account_storage_mode)
+        .with_component(auth_component)
+        .with_component(BasicNonFungibleFaucet)
+        .build()?;
+
+    Ok((account, account_seed))
+}
+
+// ROLE-BASED FUNGIBLE FAUCET
+// ================================================================================================
+
+/// Creates a new faucet account with the role-based fungible faucet interface, account storage
+/// type, provided meta data (token symbol, decimals, max supply), and the admin role granted to
+/// `admin`.
+///
+/// The role-based faucet interface exposes six procedures:
+/// - `distribute`, which mints an asset and creates a note for the provided recipient.
+/// - `burn`, which burns the provided asset.
+/// - `set_roles`, which sets the roles of an account.
+/// - `pause` and `unpause`, which prevent and allow minting and burning.
+/// - `set_max_supply`, which sets the maximum supply of the token.
+///
+/// The procedures are authorized by the roles of the sender of the note which invokes them, see
+/// [`RoleBasedFungibleFaucet`]. Minters and burners are granted their roles by the admin.
+///
+/// The storage layout of the faucet account is:
+/// - Slot 0: Reserved slot for faucets.
+/// - Slot 1: Token metadata of the faucet.
+/// - Slot 2: Pause flag of the faucet.
+/// - Slot 3: Map of account roles.
+pub fn create_role_based_fungible_faucet(
+    init_seed: [u8; 32],
+    id_anchor: AccountIdAnchor,
+    symbol: TokenSymbol,
+    decimals: u8,
+    max_supply: Felt,
+    admin: AccountId,
+    account_storage_mode: AccountStorageMode,
+) -> Result<(Account, Word), AccountError> {
+    let (account, account_seed) = AccountBuilder::new(init_seed)
+        .anchor(id_anchor)
+        .account_type(AccountType::FungibleFaucet)
+        .storage_mode(account_storage_mode)
+        .with_component(RoleBasedFungibleFaucet::new(symbol, decimals, max_supply, admin)?)
+        .build()?;
+
+    Ok((account, account_seed))
+}
+
 // TESTS
 // ================================================================================================
 
 #[cfg(test)]
 mod tests {
     use miden_objects::{
-        block::BlockHeader, crypto::dsa::rpo_falcon512, digest, FieldElement, ONE,
+        account::{AccountType, InitStorageData, StorageMap, StoragePlaceholder, StorageValue},
+        block::BlockHeader,
+        crypto::dsa::rpo_falcon512,
+        digest,
+        testing::account_id::ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+        FieldElement, ONE,
     };
     use vm_processor::Word;
 
-    use super::{create_basic_fungible_faucet, AccountStorageMode, AuthScheme, Felt, TokenSymbol};
+    use super::{
+        create_basic_fungible_faucet, create_basic_non_fungible_faucet,
+        create_role_based_fungible_faucet, AccountComponent, AccountId, AccountStorageMode,
+        AuthScheme, FaucetRoles, Felt, RoleBasedFungibleFaucet, TokenSymbol,
+    };
 
     #[test]
     fn faucet_contract_creation() {
@@ -180,4 +487,108 @@ mod tests {
 
         assert!(faucet_account.is_faucet());
     }
+
+    #[test]
+    fn non_fungible_faucet_contract_creation() {
+        let pub_key = rpo_falcon512::PublicKey::new([ONE; 4]);
+        let auth_scheme: AuthScheme = AuthScheme::RpoFalcon512 { pub_key };
+
+        let anchor_block_header_mock = BlockHeader::mock(
+            0,
+            Some(digest!("0xaa")),
+            Some(digest!("0xbb")),
+            &[],
+            digest!("0xcc"),
+        );
+
+        let (faucet_account, _) = create_basic_non_fungible_faucet(
+            [7; 32],
+            (&anchor_block_header_mock).try_into().unwrap(),
+            AccountStorageMode::Public,
+            auth_scheme,
+        )
+        .unwrap();
+
+        // The reserved faucet slot should be initialized to an empty map.
+        assert_eq!(faucet_account.storage().get_item(0).unwrap(), StorageMap::new().root());
+
+        // The falcon auth component is the only component with storage, so its assigned storage
+        // slot for the public key will be 1.
+        assert_eq!(faucet_account.storage().get_item(1).unwrap(), Word::from(pub_key).into());
+        assert_eq!(faucet_account.storage().slots().len(), 2);
+
+        assert!(faucet_account.is_faucet());
+        assert_eq!(faucet_account.id().account_type(), AccountType::NonFungibleFaucet);
+    }
+
+    #[test]
+    fn role_based_faucet_contract_creation() {
+        let admin =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+        let token_symbol = TokenSymbol::try_from("STB").unwrap();
+
+        let anchor_block_header_mock = BlockHeader::mock(
+            0,
+            Some(digest!("0xaa")),
+            Some(digest!("0xbb")),
+            &[],
+            digest!("0xcc"),
+        );
+
+        let (faucet_account, _) = create_role_based_fungible_faucet(
+            [3; 32],
+            (&anchor_block_header_mock).try_into().unwrap(),
+            token_symbol,
+            6,
+            Felt::new(1000),
+            admin,
+            AccountStorageMode::Public,
+        )
+        .unwrap();
+
+        // The faucet component is the only component, so its metadata, pause flag and roles are
+        // stored in the slots following the reserved faucet slot.
+        assert_eq!(
+            faucet_account.storage().get_item(1).unwrap(),
+            [Felt::new(1000), Felt::new(6), token_symbol.into(), Felt::ZERO].into()
+        );
+        assert_eq!(faucet_account.storage().get_item(2).unwrap(), Word::default().into());
+        assert_eq!(
+            faucet_account
+                .storage()
+                .get_map_item(3, RoleBasedFungibleFaucet::roles_key(admin).into())
+                .unwrap(),
+            Word::from(FaucetRoles::new().with_admin())
+        );
+
+        assert!(faucet_account.is_faucet());
+    }
+
+    #[test]
+    fn role_based_faucet_template_matches_component() {
+        let admin =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+        let token_symbol = TokenSymbol::try_from("STB").unwrap();
+
+        let placeholder = |key: &str| StoragePlaceholder::new(key).unwrap();
+        let init_storage_data = InitStorageData::new([
+            (placeholder("token_metadata.max_supply"), StorageValue::Felt(Felt::new(1000))),
+            (placeholder("token_metadata.decimals"), StorageValue::Felt(Felt::new(6))),
+            (placeholder("token_metadata.symbol"), StorageValue::Felt(token_symbol.into())),
+            (placeholder("admin.prefix"), StorageValue::Felt(admin.prefix().as_felt())),
+            (placeholder("admin.suffix"), StorageValue::Felt(admin.suffix())),
+        ]);
+        let template_component = AccountComponent::from_template(
+            &RoleBasedFungibleFaucet::template(),
+            &init_storage_data,
+        )
+        .unwrap();
+
+        let faucet = RoleBasedFungibleFaucet::new(token_symbol, 6, Felt::new(1000), admin).unwrap();
+        let component = AccountComponent::from(faucet);
+
+        assert_eq!(template_component.storage_slots(), component.storage_slots());
+        assert_eq!(template_component.supported_types(), component.supported_types());
+        assert_eq!(template_component.library(), component.library());
+    }
 }
@@ -55,8 +55,15 @@ pub const ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_TWO_AND_THREE_MUST_BE_FUNGIBLE_FAUCE
 pub const ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_ZERO_MUST_BE_WITHIN_LIMITS: u32 = 0x00020023;
 pub const ERR_FUNGIBLE_ASSET_PROVIDED_FAUCET_ID_IS_INVALID: u32 = 0x0002004B;
 
+pub const ERR_HTLC_INVALID_PREIMAGE: u32 = 0x00020065;
+pub const ERR_HTLC_RECLAIM_ACCT_IS_NOT_SENDER: u32 = 0x00020066;
+pub const ERR_HTLC_RECLAIM_HEIGHT_NOT_REACHED: u32 = 0x00020067;
+pub const ERR_HTLC_WRONG_NUMBER_OF_INPUTS: u32 = 0x00020064;
+
 pub const ERR_KERNEL_PROCEDURE_OFFSET_OUT_OF_BOUNDS: u32 = 0x00020003;
 
+pub const ERR_MULTISIG_THRESHOLD_NOT_MET: u32 = 0x0002005C;
+
 pub const ERR_NON_FUNGIBLE_ASSET_ALREADY_EXISTS: u32 = 0x00020047;
 pub const ERR_NON_FUNGIBLE_ASSET_FAUCET_IS_NOT_ORIGIN: u32 = 0x00020027;
 pub const ERR_NON_FUNGIBLE_ASSET_FORMAT_ELEMENT_THREE_MUST_BE_FUNGIBLE_FAUCET_ID: u32 = 0x00020024;
@@ -98,6 +105,20 @@ pub const ERR_PROLOGUE_NUMBER_OF_NOTE_INPUTS_EXCEEDED_LIMIT: u32 = 0x0002004F;
 pub const ERR_PROLOGUE_PROVIDED_ACCOUNT_DATA_DOES_NOT_MATCH_ON_CHAIN_COMMITMENT: u32 = 0x0002003A;
 pub const ERR_PROLOGUE_PROVIDED_INPUT_ASSETS_INFO_DOES_NOT_MATCH_ITS_COMMITMENT: u32 = 0x0002003F;
 
+pub const ERR_PSWAP_FILL_AMOUNT_EXCEEDS_REQUESTED_AMOUNT: u32 = 0x00020062;
+pub const ERR_PSWAP_FILL_AMOUNT_OVERFLOW: u32 = 0x00020063;
+pub const ERR_PSWAP_WRONG_NUMBER_OF_ASSETS: u32 = 0x00020061;
+pub const ERR_PSWAP_WRONG_NUMBER_OF_INPUTS: u32 = 0x00020060;
+
+pub const ERR_ROLE_BASED_FAUCET_IS_PAUSED: u32 = 0x00020068;
+pub const ERR_ROLE_BASED_FAUCET_MAX_SUPPLY_BELOW_TOTAL_ISSUANCE: u32 = 0x0002006F;
+pub const ERR_ROLE_BASED_FAUCET_MAX_SUPPLY_TOO_LARGE: u32 = 0x0002006E;
+pub const ERR_ROLE_BASED_FAUCET_MINT_ALLOWANCE_EXCEEDED: u32 = 0x0002006C;
+pub const ERR_ROLE_BASED_FAUCET_ROLE_FLAG_IS_NOT_BOOLEAN: u32 = 0x0002006D;
+pub const ERR_ROLE_BASED_FAUCET_SENDER_IS_NOT_ADMIN: u32 = 0x00020069;
+pub const ERR_ROLE_BASED_FAUCET_SENDER_IS_NOT_BURNER: u32 = 0x0002006B;
+pub const ERR_ROLE_BASED_FAUCET_SENDER_IS_NOT_MINTER: u32 = 0x0002006A;
+
 pub const ERR_STORAGE_SLOT_INDEX_OUT_OF_BOUNDS: u32 = 0x0002000D;
 
 pub const ERR_SWAP_WRONG_NUMBER_OF_ASSETS: u32 = 0x00020056;
@@ -115,7 +136,7 @@ pub const ERR_VAULT_NON_FUNGIBLE_ASSET_ALREADY_EXISTS: u32 = 0x0002001C;
 pub const ERR_VAULT_NON_FUNGIBLE_ASSET_TO_REMOVE_NOT_FOUND: u32 = 0x0002001F;
 pub const ERR_VAULT_REMOVE_FUNGIBLE_ASSET_FAILED_INITIAL_VALUE_INVALID: u32 = 0x0002001E;
 
-pub const TX_KERNEL_ERRORS: [(u32, &str); 90] = [
+pub const TX_KERNEL_ERRORS: [(u32, &str); 107] = [
     (ERR_ACCOUNT_ANCHOR_BLOCK_HASH_MUST_NOT_BE_EMPTY, "Anchor block hash must not be empty"),
     (ERR_ACCOUNT_CODE_COMMITMENT_MISMATCH, "Computed account code commitment does not match recorded account code commitment"),
     (ERR_ACCOUNT_CODE_IS_NOT_UPDATABLE, "Account code must be updatable for it to be possible to set new code"),
@@ -161,8 +182,15 @@ pub const TX_KERNEL_ERRORS: [(u32, &str); 90] = [
     (ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_ZERO_MUST_BE_WITHIN_LIMITS, "Malformed fungible asset: ASSET[0] exceeds the maximum allowed amount"),
     (ERR_FUNGIBLE_ASSET_PROVIDED_FAUCET_ID_IS_INVALID, "Failed to build the fungible asset because the provided faucet id is not from a fungible faucet"),
 
+    (ERR_HTLC_INVALID_PREIMAGE, "HTLC's target account must provide the SHA-256 preimage of the hashlock"),
+    (ERR_HTLC_RECLAIM_ACCT_IS_NOT_SENDER, "HTLC's reclaimer is not the original sender"),
+    (ERR_HTLC_RECLAIM_HEIGHT_NOT_REACHED, "HTLC can not be reclaimed as the transaction's reference block is lower than the reclaim height"),
+    (ERR_HTLC_WRONG_NUMBER_OF_INPUTS, "HTLC scripts expect exactly 16 note inputs"),
+
     (ERR_KERNEL_PROCEDURE_OFFSET_OUT_OF_BOUNDS, "Provided kernel procedure offset is out of bounds"),
 
+    (ERR_MULTISIG_THRESHOLD_NOT_MET, "Number of valid approver signatures is below the threshold of the multisig account"),
+
     (ERR_NON_FUNGIBLE_ASSET_ALREADY_EXISTS, "Non-fungible asset that already exists in the note cannot be added again"),
     (ERR_NON_FUNGIBLE_ASSET_FAUCET_IS_NOT_ORIGIN, "The origin of the non-fungible asset is not this faucet"),
     (ERR_NON_FUNGIBLE_ASSET_FORMAT_ELEMENT_THREE_MUST_BE_FUNGIBLE_FAUCET_ID, "Malformed non-fungible asset: ASSET[3] is not a valid non-fungible faucet id"),
@@ -204,6 +232,20 @@ pub const TX_KERNEL_ERRORS: [(u32, &str); 90] = [
     (ERR_PROLOGUE_PROVIDED_ACCOUNT_DATA_DOES_NOT_MATCH_ON_CHAIN_COMMITMENT, "Account data provided does not match the commitment recorded on-chain"),
     (ERR_PROLOGUE_PROVIDED_INPUT_ASSETS_INFO_DOES_NOT_MATCH_ITS_COMMITMENT, "Provided info about assets of an input does not match its commitment"),
 
+    (ERR_PSWAP_FILL_AMOUNT_EXCEEDS_REQUESTED_AMOUNT, "PSWAP fill amount must not exceed the requested amount"),
+    (ERR_PSWAP_FILL_AMOUNT_OVERFLOW, "PSWAP product of the offered amount and the fill amount must fit into 64 bits"),
+    (ERR_PSWAP_WRONG_NUMBER_OF_ASSETS, "PSWAP script requires exactly 1 note asset"),
+    (ERR_PSWAP_WRONG_NUMBER_OF_INPUTS, "PSWAP script expects exactly 16 note inputs"),
+
+    (ERR_ROLE_BASED_FAUCET_IS_PAUSED, "Role-based faucet is paused"),
+    (ERR_ROLE_BASED_FAUCET_MAX_SUPPLY_BELOW_TOTAL_ISSUANCE, "Maximum supply of the role-based faucet cannot be lower than its total issuance"),
+    (ERR_ROLE_BASED_FAUCET_MAX_SUPPLY_TOO_LARGE, "Maximum supply of the role-based faucet exceeds the maximum amount of a fungible asset"),
+    (ERR_ROLE_BASED_FAUCET_MINT_ALLOWANCE_EXCEEDED, "Distribute would cause the mint allowance of the minter to be exceeded"),
+    (ERR_ROLE_BASED_FAUCET_ROLE_FLAG_IS_NOT_BOOLEAN, "Role flags of the role-based faucet must be booleans"),
+    (ERR_ROLE_BASED_FAUCET_SENDER_IS_NOT_ADMIN, "Sender of the note does not have the admin role of the role-based faucet"),
+    (ERR_ROLE_BASED_FAUCET_SENDER_IS_NOT_BURNER, "Sender of the note does not have the burner role of the role-based faucet"),
+    (ERR_ROLE_BASED_FAUCET_SENDER_IS_NOT_MINTER, "Sender of the note does not have the minter role of the role-based faucet"),
+
     (ERR_STORAGE_SLOT_INDEX_OUT_OF_BOUNDS, "Provided storage slot index is out of bounds"),
 
     (ERR_SWAP_WRONG_NUMBER_OF_ASSETS, "SWAP script requires exactly 1 note asset"),
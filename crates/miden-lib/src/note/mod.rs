@@ -2,7 +2,7 @@ use alloc::vec::Vec;
 
 use miden_objects::{
     account::AccountId,
-    asset::Asset,
+    asset::{Asset, FungibleAsset},
     block::BlockNumber,
     crypto::rand::FeltRng,
     note::{
@@ -82,6 +82,69 @@ pub fn create_p2idr_note<R: FeltRng>(
     Ok(Note::new(vault, metadata, recipient))
 }
 
+/// Generates an HTLC note - hash time-locked contract.
+///
+/// This script enables the transfer of assets from the `sender` account to the `target` account
+/// under the condition that the target provides the preimage of the `hashlock` when consuming the
+/// note. Additionally it adds the possibility for the sender to reclaim the assets if the note has
+/// not been consumed by the target before the specified `reclaim_height`, which makes it suitable
+/// for atomic swaps of assets across chains.
+///
+/// The hashlock is the SHA-256 hash of a 32-byte preimage, computed via
+/// [utils::build_htlc_hashlock()], and is stored in the note inputs encoded via
+/// [utils::encode_htlc_bytes()], i.e. as 8 big-endian u32 values. The target provides the preimage
+/// via the advice map, using the entry returned by [utils::build_htlc_preimage_advice()], and passes
+/// the key of the entry as the note args.
+///
+/// When consuming the note, the target also creates a public note for the sender whose inputs are
+/// the encoded preimage, which allows the sender to learn the preimage and to claim the
+/// counterpart of the swap. The details of this note can be obtained via
+/// [utils::build_htlc_preimage_note()], and the target should provide them as an expected output
+/// note of the transaction. The sender can recover the preimage from the note via
+/// [utils::extract_htlc_preimage()].
+///
+/// The passed-in `rng` is used to generate a serial number for the note. The returned note's tag
+/// is set to the target's account ID, and its execution hint is set to
+/// [NoteExecutionHint::Always], since the target can consume the note as soon as it learns the
+/// preimage. Note that the target can also consume the note after the reclaim height, as long as
+/// the sender has not reclaimed it.
+///
+/// # Errors
+/// Returns an error if deserialization or compilation of the `HTLC` script fails.
+#[allow(clippy::too_many_arguments)]
+pub fn create_htlc_note<R: FeltRng>(
+    sender: AccountId,
+    target: AccountId,
+    assets: Vec<Asset>,
+    note_type: NoteType,
+    aux: Felt,
+    hashlock: [u8; 32],
+    reclaim_height: BlockNumber,
+    rng: &mut R,
+) -> Result<Note, NoteError> {
+    let note_script = scripts::htlc();
+
+    let preimage_note_script_root: Word = scripts::htlc_preimage().hash().into();
+    let preimage_note_tag = NoteTag::from_account_id(sender, NoteExecutionMode::Local)?;
+
+    let mut inputs = utils::encode_htlc_bytes(&hashlock);
+    inputs.extend([
+        target.suffix(),
+        target.prefix().as_felt(),
+        reclaim_height.into(),
+        preimage_note_tag.inner().into(),
+    ]);
+    inputs.extend(preimage_note_script_root);
+    let inputs = NoteInputs::new(inputs)?;
+    let tag = NoteTag::from_account_id(target, NoteExecutionMode::Local)?;
+    let serial_num = rng.draw_word();
+
+    let vault = NoteAssets::new(assets)?;
+    let metadata = NoteMetadata::new(sender, note_type, tag, NoteExecutionHint::always(), aux)?;
+    let recipient = NoteRecipient::new(serial_num, note_script, inputs);
+    Ok(Note::new(vault, metadata, recipient))
+}
+
 /// Generates a SWAP note - swap of assets between two accounts - and returns the note as well as
 /// [NoteDetails] for the payback note.
 ///
@@ -137,3 +200,63 @@ pub fn create_swap_note<R: FeltRng>(
 
     Ok((note, payback_note))
 }
+
+/// Generates a PSWAP note - partially fillable swap of fungible assets between two accounts.
+///
+/// This script enables a swap of 2 fungible assets between the `sender` account and any other
+/// account that is willing to consume the note, either entirely or in part. The consumer specifies
+/// the amount of the `requested_asset` it provides via the note args `[fill_amount, 0, 0, 0]`,
+/// where a fill amount of 0 fills the note completely. The consumer will receive the proportional
+/// amount of the `offered_asset`, rounded down, and will create a new P2ID note with `sender` as
+/// target containing the fill amount of the `requested_asset`. If the note is not filled
+/// completely, a new PSWAP note with the remainder of both assets is created as well.
+///
+/// The details of the notes created when consuming the returned note can be obtained via
+/// [utils::build_partial_swap_fill_notes()].
+///
+/// # Errors
+/// Returns an error if deserialization or compilation of the `PSWAP` script fails.
+pub fn create_partial_swap_note<R: FeltRng>(
+    sender: AccountId,
+    offered_asset: FungibleAsset,
+    requested_asset: FungibleAsset,
+    note_type: NoteType,
+    aux: Felt,
+    rng: &mut R,
+) -> Result<Note, NoteError> {
+    let note_script = scripts::pswap();
+
+    let requested_asset_word: Word = requested_asset.into();
+    let p2id_script_root: Word = scripts::p2id().hash().into();
+    let payback_tag = NoteTag::from_account_id(sender, NoteExecutionMode::Local)?;
+
+    // build the tag for the SWAP use case
+    let tag = build_swap_tag(note_type, &offered_asset.into(), &requested_asset.into())?;
+
+    let inputs = NoteInputs::new(vec![
+        requested_asset_word[0],
+        requested_asset_word[1],
+        requested_asset_word[2],
+        requested_asset_word[3],
+        p2id_script_root[0],
+        p2id_script_root[1],
+        p2id_script_root[2],
+        p2id_script_root[3],
+        sender.suffix(),
+        sender.prefix().as_felt(),
+        payback_tag.inner().into(),
+        NoteExecutionHint::always().into(),
+        NoteExecutionHint::always().into(),
+        note_type.into(),
+        aux,
+        tag.inner().into(),
+    ])?;
+
+    let serial_num = rng.draw_word();
+
+    let metadata = NoteMetadata::new(sender, note_type, tag, NoteExecutionHint::always(), aux)?;
+    let assets = NoteAssets::new(vec![offered_asset.into()])?;
+    let recipient = NoteRecipient::new(serial_num, note_script, inputs);
+
+    Ok(Note::new(assets, metadata, recipient))
+}
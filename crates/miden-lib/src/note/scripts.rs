@@ -25,6 +25,28 @@ static SWAP_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
     NoteScript::new(program)
 });
 
+// Initialize the PSWAP note script only once
+static PSWAP_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/PSWAP.masb"));
+    let program = Program::read_from_bytes(bytes).expect("Shipped PSWAP script is well-formed");
+    NoteScript::new(program)
+});
+
+// Initialize the HTLC note script only once
+static HTLC_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/HTLC.masb"));
+    let program = Program::read_from_bytes(bytes).expect("Shipped HTLC script is well-formed");
+    NoteScript::new(program)
+});
+
+// Initialize the HTLC_PREIMAGE note script only once
+static HTLC_PREIMAGE_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/HTLC_PREIMAGE.masb"));
+    let program =
+        Program::read_from_bytes(bytes).expect("Shipped HTLC_PREIMAGE script is well-formed");
+    NoteScript::new(program)
+});
+
 /// Returns the P2ID (Pay-to-ID) note script.
 pub fn p2id() -> NoteScript {
     P2ID_SCRIPT.clone()
@@ -39,3 +61,19 @@ pub fn p2idr() -> NoteScript {
 pub fn swap() -> NoteScript {
     SWAP_SCRIPT.clone()
 }
+
+/// Returns the PSWAP (partially fillable SWAP note) note script.
+pub fn pswap() -> NoteScript {
+    PSWAP_SCRIPT.clone()
+}
+
+/// Returns the HTLC (Hash time-locked contract) note script.
+pub fn htlc() -> NoteScript {
+    HTLC_SCRIPT.clone()
+}
+
+/// Returns the HTLC_PREIMAGE note script, which discloses the preimage of an HTLC note to its
+/// sender.
+pub fn htlc_preimage() -> NoteScript {
+    HTLC_PREIMAGE_SCRIPT.clone()
+}
@@ -1,9 +1,14 @@
+use alloc::vec::Vec;
+
 use miden_objects::{
     account::AccountId,
-    asset::Asset,
-    note::{NoteExecutionMode, NoteInputs, NoteRecipient, NoteTag, NoteType},
-    NoteError, Word,
+    asset::{Asset, FungibleAsset},
+    note::{
+        NoteAssets, NoteDetails, NoteExecutionMode, NoteInputs, NoteRecipient, NoteTag, NoteType,
+    },
+    Digest, Felt, NoteError, Word, ONE,
 };
+use sha2::{Digest as _, Sha256};
 
 use crate::note::scripts;
 
@@ -21,6 +26,190 @@ pub fn build_p2id_recipient(
     Ok(NoteRecipient::new(serial_num, note_script, note_inputs))
 }
 
+/// Returns the hashlock of an HTLC note which can be consumed by providing the specified preimage.
+///
+/// The hashlock is the SHA-256 hash of the 32-byte preimage, and so an HTLC note can be locked with
+/// the same hashlock as an HTLC on another chain.
+pub fn build_htlc_hashlock(preimage: &[u8; 32]) -> [u8; 32] {
+    Sha256::digest(preimage).into()
+}
+
+/// Returns the key and the value of the advice map entry via which the target account of an HTLC
+/// note provides the specified preimage when consuming the note.
+///
+/// The value is the preimage encoded via [encode_htlc_bytes()] prefixed by its number of elements,
+/// and the key is the RPO256 hash of the encoded preimage. The key must be provided as the note
+/// args of the HTLC note.
+///
+/// This is the same entry via which the inputs of the note returned by
+/// [build_htlc_preimage_note()] are provided when the note is an expected output note of the
+/// transaction, as the inputs of this note are the encoded preimage.
+pub fn build_htlc_preimage_advice(preimage: &[u8; 32]) -> (Digest, Vec<Felt>) {
+    let inputs = NoteInputs::new(encode_htlc_bytes(preimage))
+        .expect("the encoded preimage should be valid note inputs");
+    (inputs.commitment(), inputs.format_for_advice())
+}
+
+/// Encodes the provided 32 bytes, i.e. a preimage or a hashlock of an HTLC note, as 8 elements,
+/// each holding 4 consecutive bytes as a big-endian u32 value.
+///
+/// This is the encoding expected by the SHA-256 implementation of the Miden standard library.
+pub fn encode_htlc_bytes(bytes: &[u8; 32]) -> Vec<Felt> {
+    bytes
+        .chunks_exact(4)
+        .map(|chunk| {
+            let word = u32::from_be_bytes(chunk.try_into().expect("chunk should have 4 bytes"));
+            Felt::from(word)
+        })
+        .collect()
+}
+
+/// Returns the details of the note which is created when the specified HTLC note is consumed by
+/// its target with the provided preimage.
+///
+/// The returned note discloses the preimage to the sender of the HTLC note. It carries no assets,
+/// and its inputs are the preimage encoded via [encode_htlc_bytes()]. The consumer of the HTLC note
+/// should provide this note as an expected output note of the transaction, as the recipient of the
+/// note is built by the note script.
+///
+/// # Errors
+/// Returns an error if the provided note is not an HTLC note.
+pub fn build_htlc_preimage_note(
+    htlc_note: &NoteDetails,
+    preimage: &[u8; 32],
+) -> Result<NoteDetails, NoteError> {
+    let inputs = htlc_note.inputs().values();
+    let preimage_note_script = scripts::htlc_preimage();
+    let preimage_note_script_root: Word = preimage_note_script.hash().into();
+    if htlc_note.script().hash() != scripts::htlc().hash()
+        || inputs.len() != 16
+        || inputs[12..16] != preimage_note_script_root
+    {
+        return Err(NoteError::HtlcNoteInvalid);
+    }
+
+    // the serial number of the preimage note is derived from the serial number of the HTLC note
+    let [s0, s1, s2, s3] = htlc_note.serial_num();
+    let recipient = NoteRecipient::new(
+        [s0, s1, s2, s3 + ONE],
+        preimage_note_script,
+        NoteInputs::new(encode_htlc_bytes(preimage))?,
+    );
+
+    Ok(NoteDetails::new(NoteAssets::default(), recipient))
+}
+
+/// Returns the preimage disclosed by the specified HTLC preimage note, or `None` if the note is not
+/// an HTLC preimage note.
+///
+/// The sender of an HTLC note can use this to learn the preimage from the outputs of the
+/// transaction which consumed the HTLC note.
+pub fn extract_htlc_preimage(preimage_note: &NoteDetails) -> Option<[u8; 32]> {
+    let inputs = preimage_note.inputs().values();
+    if preimage_note.script().hash() != scripts::htlc_preimage().hash() || inputs.len() != 8 {
+        return None;
+    }
+
+    let mut preimage = [0; 32];
+    for (chunk, value) in preimage.chunks_exact_mut(4).zip(inputs) {
+        let word = u32::try_from(value.as_int()).ok()?;
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+
+    Some(preimage)
+}
+
+/// Returns the details of the notes which are created when the specified PSWAP note is consumed
+/// with the provided fill amount of the requested asset.
+///
+/// The first returned note is the P2ID note for the creator of the PSWAP note containing the fill
+/// amount of the requested asset. The second returned note is the PSWAP note with the remainder of
+/// the offered and requested assets, which is only created if the note is not filled completely.
+///
+/// A `fill_amount` of 0 fills the note completely. The consumer of the PSWAP note should provide
+/// these notes as expected output notes of the transaction, as the recipients of the notes are
+/// built by the note script.
+///
+/// # Errors
+/// Returns an error if:
+/// - The provided note is not a PSWAP note.
+/// - The fill amount exceeds the requested amount of the note.
+/// - The product of the offered amount and the fill amount does not fit into 64 bits.
+pub fn build_partial_swap_fill_notes(
+    swap_note: &NoteDetails,
+    fill_amount: u64,
+) -> Result<(NoteDetails, Option<NoteDetails>), NoteError> {
+    let inputs = swap_note.inputs().values();
+    let p2id_script_root: Word = scripts::p2id().hash().into();
+    if swap_note.script().hash() != scripts::pswap().hash()
+        || inputs.len() != 16
+        || inputs[4..8] != p2id_script_root
+    {
+        return Err(NoteError::PartialSwapNoteInvalid);
+    }
+
+    let mut assets = swap_note.assets().iter();
+    let offered_asset = match (assets.next(), assets.next()) {
+        (Some(Asset::Fungible(asset)), None) => *asset,
+        _ => return Err(NoteError::PartialSwapNoteInvalid),
+    };
+    let requested_asset = FungibleAsset::try_from([inputs[0], inputs[1], inputs[2], inputs[3]])
+        .map_err(|_| NoteError::PartialSwapNoteInvalid)?;
+    let creator = AccountId::try_from([inputs[9], inputs[8]])
+        .map_err(|_| NoteError::PartialSwapNoteInvalid)?;
+
+    let requested_amount = requested_asset.amount();
+    let fill_amount = if fill_amount == 0 {
+        requested_amount
+    } else {
+        fill_amount
+    };
+    if fill_amount > requested_amount {
+        return Err(NoteError::PartialSwapFillAmountExceedsRequestedAmount {
+            fill_amount,
+            requested_amount,
+        });
+    }
+
+    let offered_amount = offered_asset.amount();
+    let fill_offered_amount = if fill_amount == requested_amount {
+        offered_amount
+    } else {
+        offered_amount
+            .checked_mul(fill_amount)
+            .ok_or(NoteError::PartialSwapFillAmountOverflow { offered_amount, fill_amount })?
+            / requested_amount
+    };
+
+    // the serial numbers of the created notes are derived from the serial number of the PSWAP note
+    let [s0, s1, s2, s3] = swap_note.serial_num();
+
+    let payback_asset = FungibleAsset::new(requested_asset.faucet_id(), fill_amount)
+        .map_err(|_| NoteError::PartialSwapNoteInvalid)?;
+    let payback_recipient = build_p2id_recipient(creator, [s0, s1, s2, s3 + ONE])?;
+    let payback_note =
+        NoteDetails::new(NoteAssets::new(vec![payback_asset.into()])?, payback_recipient);
+
+    if fill_amount == requested_amount {
+        return Ok((payback_note, None));
+    }
+
+    let remainder_asset =
+        FungibleAsset::new(offered_asset.faucet_id(), offered_amount - fill_offered_amount)
+            .map_err(|_| NoteError::PartialSwapNoteInvalid)?;
+    let mut remainder_inputs = inputs.to_vec();
+    remainder_inputs[0] = Felt::new(requested_amount - fill_amount);
+    let remainder_recipient = NoteRecipient::new(
+        [s0 + ONE, s1, s2, s3],
+        swap_note.script().clone(),
+        NoteInputs::new(remainder_inputs)?,
+    );
+    let remainder_note =
+        NoteDetails::new(NoteAssets::new(vec![remainder_asset.into()])?, remainder_recipient);
+
+    Ok((payback_note, Some(remainder_note)))
+}
+
 /// Returns a note tag for a swap note with the specified parameters.
 ///
 /// Use case ID for the returned tag is set to 0.
@@ -58,9 +247,101 @@ mod tests {
         self,
         account::{AccountIdVersion, AccountStorageMode, AccountType},
         asset::{FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+        crypto::rand::RpoRandomCoin,
+        Hasher, ZERO,
     };
 
     use super::*;
+    use crate::note::{create_htlc_note, create_partial_swap_note};
+
+    #[test]
+    fn partial_swap_fill_notes() {
+        let faucet_id = |first_byte: u8| {
+            let mut bytes = [0; 15];
+            bytes[0] = first_byte;
+            AccountId::dummy(
+                bytes,
+                AccountIdVersion::Version0,
+                AccountType::FungibleFaucet,
+                AccountStorageMode::Public,
+            )
+        };
+        let sender = AccountId::dummy(
+            [1; 15],
+            AccountIdVersion::Version0,
+            AccountType::RegularAccountUpdatableCode,
+            AccountStorageMode::Public,
+        );
+        let offered_asset = FungibleAsset::new(faucet_id(0xcd), 1000).unwrap();
+        let requested_asset = FungibleAsset::new(faucet_id(0xab), 3).unwrap();
+
+        let note = create_partial_swap_note(
+            sender,
+            offered_asset,
+            requested_asset,
+            NoteType::Public,
+            ZERO,
+            &mut RpoRandomCoin::new([ONE; 4]),
+        )
+        .unwrap();
+
+        // the offered amount for the fill is rounded down
+        let (payback_note, remainder_note) = build_partial_swap_fill_notes(&note, 1).unwrap();
+        let remainder_note = remainder_note.unwrap();
+        assert_eq!(
+            payback_note.assets().iter().next().unwrap(),
+            &FungibleAsset::new(requested_asset.faucet_id(), 1).unwrap().into()
+        );
+        assert_eq!(
+            payback_note.recipient(),
+            &build_p2id_recipient(sender, payback_note.serial_num()).unwrap()
+        );
+        assert_eq!(
+            remainder_note.assets().iter().next().unwrap(),
+            &FungibleAsset::new(offered_asset.faucet_id(), 667).unwrap().into()
+        );
+        assert_eq!(remainder_note.inputs().values()[0], Felt::new(2));
+        assert_eq!(remainder_note.inputs().values()[1..], note.inputs().values()[1..]);
+
+        // all created notes have distinct serial numbers
+        let (next_payback_note, _) = build_partial_swap_fill_notes(&remainder_note, 1).unwrap();
+        let serial_nums = [
+            note.serial_num(),
+            payback_note.serial_num(),
+            remainder_note.serial_num(),
+            next_payback_note.serial_num(),
+        ];
+        for (i, serial_num) in serial_nums.iter().enumerate() {
+            assert!(!serial_nums[i + 1..].contains(serial_num));
+        }
+
+        // a fill amount of 0 fills the note completely
+        let (payback_note, remainder_note) = build_partial_swap_fill_notes(&note, 0).unwrap();
+        assert!(remainder_note.is_none());
+        assert_eq!(payback_note.assets().iter().next().unwrap(), &Asset::from(requested_asset));
+
+        assert!(matches!(
+            build_partial_swap_fill_notes(&note, 4),
+            Err(NoteError::PartialSwapFillAmountExceedsRequestedAmount {
+                fill_amount: 4,
+                requested_amount: 3
+            })
+        ));
+
+        let (swap_note, _) = crate::note::create_swap_note(
+            sender,
+            offered_asset.into(),
+            requested_asset.into(),
+            NoteType::Public,
+            ZERO,
+            &mut RpoRandomCoin::new([ONE; 4]),
+        )
+        .unwrap();
+        assert!(matches!(
+            build_partial_swap_fill_notes(&swap_note, 1),
+            Err(NoteError::PartialSwapNoteInvalid)
+        ));
+    }
 
     #[test]
     fn swap_tag() {
@@ -119,4 +400,63 @@ mod tests {
 
         assert_eq!(actual_tag, expected_tag);
     }
+
+    #[test]
+    fn htlc_hashlock_encoding() {
+        // SHA-256 of 32 zero bytes
+        let hashlock = build_htlc_hashlock(&[0; 32]);
+        assert_eq!(hashlock[..4], [0x66, 0x68, 0x7a, 0xad]);
+        assert_eq!(hashlock[28..], [0x0d, 0x5f, 0x29, 0x25]);
+
+        // bytes are encoded as big-endian u32 values
+        let encoded = encode_htlc_bytes(&hashlock);
+        assert_eq!(encoded.len(), 8);
+        assert_eq!(encoded[0], Felt::new(0x66687aad));
+        assert_eq!(encoded[7], Felt::new(0x0d5f2925));
+
+        let (key, value) = build_htlc_preimage_advice(&[0; 32]);
+        assert_eq!(value, [&[Felt::new(8)][..], &[ZERO; 8]].concat());
+        assert_eq!(key, Hasher::hash_elements(&[ZERO; 8]));
+    }
+
+    #[test]
+    fn htlc_preimage_note() {
+        let sender = AccountId::dummy(
+            [1; 15],
+            AccountIdVersion::Version0,
+            AccountType::RegularAccountUpdatableCode,
+            AccountStorageMode::Public,
+        );
+        let target = AccountId::dummy(
+            [2; 15],
+            AccountIdVersion::Version0,
+            AccountType::RegularAccountUpdatableCode,
+            AccountStorageMode::Public,
+        );
+        let preimage: [u8; 32] = core::array::from_fn(|i| i as u8 * 7);
+
+        let note = create_htlc_note(
+            sender,
+            target,
+            vec![],
+            NoteType::Public,
+            ZERO,
+            build_htlc_hashlock(&preimage),
+            10.into(),
+            &mut RpoRandomCoin::new([ONE; 4]),
+        )
+        .unwrap();
+
+        let preimage_note = build_htlc_preimage_note(&note, &preimage).unwrap();
+        assert!(preimage_note.assets().is_empty());
+        assert_eq!(preimage_note.inputs().values(), encode_htlc_bytes(&preimage));
+        assert_eq!(extract_htlc_preimage(&preimage_note), Some(preimage));
+
+        // the preimage can only be extracted from HTLC preimage notes
+        assert_eq!(extract_htlc_preimage(&note), None);
+        assert!(matches!(
+            build_htlc_preimage_note(&preimage_note, &preimage),
+            Err(NoteError::HtlcNoteInvalid)
+        ));
+    }
 }
@@ -1,7 +1,10 @@
 use alloc::{boxed::Box, vec::Vec};
 use core::error::Error;
 
-use miden_objects::{note::NoteMetadata, AccountDeltaError, AssetError, Digest, Felt, NoteError};
+use miden_objects::{
+    account::AccountId, note::NoteMetadata, AccountDeltaError, AccountIdError, AssetError, Digest,
+    Felt, NoteError,
+};
 use thiserror::Error;
 
 // TRANSACTION KERNEL ERROR
@@ -51,8 +54,32 @@ pub enum TransactionKernelError {
     UnknownAccountProcedure(Digest),
     #[error("code commitment {0} is not in the advice provider")]
     UnknownCodeCommitment(Digest),
+    #[error("account code with commitment {0} which the account code was set to was not provided with the transaction arguments")]
+    MissingNewAccountCode(Digest),
     #[error("account storage slots number is missing in memory at address {0}")]
     AccountStorageSlotsNumMissing(u32),
+    #[error("foreign account ID extracted from the stack by the event handler is not well formed")]
+    MalformedForeignAccountId(#[source] AccountIdError),
+    #[error("data of foreign account {0} is not in the advice provider")]
+    MissingForeignAccountInputs(AccountId),
+    #[error("pointer to the current account data is missing in memory at address {0}")]
+    CurrentAccountDataPtrMissing(u32),
+    #[error("ID of the current account is missing in memory at address {0}")]
+    CurrentAccountIdMissing(u32),
+    #[error("storage slots of storage with commitment {0} are not in the advice provider")]
+    MissingStorageSlots(Digest),
+    #[error("Merkle path to key {map_key} in storage map with root {map_root} of account {account_id} is not in the advice provider")]
+    MissingStorageMapWitness {
+        account_id: AccountId,
+        map_root: Digest,
+        map_key: Digest,
+    },
+    #[error("Merkle path to key {vault_key} in asset vault with root {vault_root} of account {account_id} is not in the advice provider")]
+    MissingVaultAssetWitness {
+        account_id: AccountId,
+        vault_root: Digest,
+        vault_key: Digest,
+    },
 }
 
 // TRANSACTION EVENT PARSING ERROR
@@ -31,6 +31,16 @@ const NOTE_AFTER_CREATED: u32 = 0x2_000c; // 131084
 const NOTE_BEFORE_ADD_ASSET: u32 = 0x2_000d; // 131085
 const NOTE_AFTER_ADD_ASSET: u32 = 0x2_000e; // 131086
 
+const ACCOUNT_BEFORE_FOREIGN_LOAD: u32 = 0x2_000f; // 131087
+
+const ACCOUNT_STORAGE_BEFORE_GET_MAP_ITEM: u32 = 0x2_0010; // 131088
+
+const ACCOUNT_VAULT_BEFORE_GET_ASSET: u32 = 0x2_0011; // 131089
+
+const AUTH_REQUEST_SIGNATURE: u32 = 0x2_0012; // 131090
+
+const ACCOUNT_AFTER_SET_CODE: u32 = 0x2_0013; // 131091
+
 /// Events which may be emitted by a transaction kernel.
 ///
 /// The events are emitted via the `emit.<event_id>` instruction. The event ID is a 32-bit
@@ -63,6 +73,19 @@ pub enum TransactionEvent {
 
     NoteBeforeAddAsset = NOTE_BEFORE_ADD_ASSET,
     NoteAfterAddAsset = NOTE_AFTER_ADD_ASSET,
+
+    AccountBeforeForeignLoad = ACCOUNT_BEFORE_FOREIGN_LOAD,
+
+    AccountStorageBeforeGetMapItem = ACCOUNT_STORAGE_BEFORE_GET_MAP_ITEM,
+
+    AccountVaultBeforeGetAsset = ACCOUNT_VAULT_BEFORE_GET_ASSET,
+
+    /// Requests a signature over the message by the signer with the public key at the top of the
+    /// stack. Unlike other events, this event is emitted by authentication components rather than
+    /// by the transaction kernel, and thus it may be emitted from any context.
+    AuthRequestSignature = AUTH_REQUEST_SIGNATURE,
+
+    AccountAfterSetCode = ACCOUNT_AFTER_SET_CODE,
 }
 
 impl TransactionEvent {
@@ -114,6 +137,18 @@ impl TryFrom<u32> for TransactionEvent {
             NOTE_BEFORE_ADD_ASSET => Ok(TransactionEvent::NoteBeforeAddAsset),
             NOTE_AFTER_ADD_ASSET => Ok(TransactionEvent::NoteAfterAddAsset),
 
+            ACCOUNT_BEFORE_FOREIGN_LOAD => Ok(TransactionEvent::AccountBeforeForeignLoad),
+
+            ACCOUNT_STORAGE_BEFORE_GET_MAP_ITEM => {
+                Ok(TransactionEvent::AccountStorageBeforeGetMapItem)
+            },
+
+            ACCOUNT_VAULT_BEFORE_GET_ASSET => Ok(TransactionEvent::AccountVaultBeforeGetAsset),
+
+            AUTH_REQUEST_SIGNATURE => Ok(TransactionEvent::AuthRequestSignature),
+
+            ACCOUNT_AFTER_SET_CODE => Ok(TransactionEvent::AccountAfterSetCode),
+
             _ => Err(TransactionEventError::InvalidTransactionEvent(value)),
         }
     }
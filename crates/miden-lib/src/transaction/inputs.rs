@@ -18,10 +18,14 @@ use super::TransactionKernel;
 /// This includes the initial account, an optional account seed (required for new accounts), and
 /// the input note data, including core note data + authentication paths all the way to the root
 /// of one of chain MMR peaks.
+///
+/// If `include_account_trees` is false, the Merkle nodes and leaves of the account's storage maps
+/// and asset vault are not added to the advice inputs.
 pub(super) fn extend_advice_inputs(
     tx_inputs: &TransactionInputs,
     tx_args: &TransactionArgs,
     advice_inputs: &mut AdviceInputs,
+    include_account_trees: bool,
 ) {
     // TODO: remove this value and use a user input instead
     let kernel_version = 0;
@@ -31,7 +35,12 @@ pub(super) fn extend_advice_inputs(
     // build the advice map and Merkle store for relevant components
     add_kernel_hashes_to_advice_inputs(advice_inputs, kernel_version);
     add_chain_mmr_to_advice_inputs(tx_inputs.block_chain(), advice_inputs);
-    add_account_to_advice_inputs(tx_inputs.account(), tx_inputs.account_seed(), advice_inputs);
+    add_account_to_advice_inputs(
+        tx_inputs.account(),
+        tx_inputs.account_seed(),
+        advice_inputs,
+        include_account_trees,
+    );
     add_input_notes_to_advice_inputs(tx_inputs, tx_args, advice_inputs);
     advice_inputs.extend(tx_args.advice_inputs().clone());
 }
@@ -158,17 +167,20 @@ fn add_account_to_advice_inputs(
     account: &Account,
     account_seed: Option<Word>,
     inputs: &mut AdviceInputs,
+    include_account_trees: bool,
 ) {
     // --- account storage ----------------------------------------------------
     let storage = account.storage();
 
-    for slot in storage.slots() {
-        // if there are storage maps, we populate the merkle store and advice map
-        if let StorageSlot::Map(map) = slot {
-            // extend the merkle store and map with the storage maps
-            inputs.extend_merkle_store(map.inner_nodes());
-            // populate advice map with Sparse Merkle Tree leaf nodes
-            inputs.extend_map(map.leaves().map(|(_, leaf)| (leaf.hash(), leaf.to_elements())));
+    if include_account_trees {
+        for slot in storage.slots() {
+            // if there are storage maps, we populate the merkle store and advice map
+            if let StorageSlot::Map(map) = slot {
+                // extend the merkle store and map with the storage maps
+                inputs.extend_merkle_store(map.inner_nodes());
+                // populate advice map with Sparse Merkle Tree leaf nodes
+                inputs.extend_map(map.leaves().map(|(_, leaf)| (leaf.hash(), leaf.to_elements())));
+            }
         }
     }
 
@@ -176,14 +188,17 @@ fn add_account_to_advice_inputs(
     inputs.extend_map([(storage.commitment(), storage.as_elements())]);
 
     // --- account vault ------------------------------------------------------
-    let vault = account.vault();
+    if include_account_trees {
+        let vault = account.vault();
 
-    // extend the merkle store with account vault data
-    inputs.extend_merkle_store(vault.asset_tree().inner_nodes());
+        // extend the merkle store with account vault data
+        inputs.extend_merkle_store(vault.asset_tree().inner_nodes());
 
-    // populate advice map with Sparse Merkle Tree leaf nodes
-    inputs
-        .extend_map(vault.asset_tree().leaves().map(|(_, leaf)| (leaf.hash(), leaf.to_elements())));
+        // populate advice map with Sparse Merkle Tree leaf nodes
+        inputs.extend_map(
+            vault.asset_tree().leaves().map(|(_, leaf)| (leaf.hash(), leaf.to_elements())),
+        );
+    }
 
     // --- account code -------------------------------------------------------
     let code = account.code();
@@ -6,7 +6,8 @@ use miden_objects::{
     block::BlockNumber,
     crypto::merkle::{MerkleError, MerklePath},
     transaction::{
-        OutputNote, OutputNotes, TransactionArgs, TransactionInputs, TransactionOutputs,
+        ForeignAccountInputs, OutputNote, OutputNotes, TransactionArgs, TransactionInputs,
+        TransactionOutputs,
     },
     utils::serde::Deserializable,
     vm::{AdviceInputs, AdviceMap, Program, ProgramInfo, StackInputs, StackOutputs},
@@ -102,7 +103,34 @@ impl TransactionKernel {
         );
 
         let mut advice_inputs = init_advice_inputs.unwrap_or_default();
-        inputs::extend_advice_inputs(tx_inputs, tx_args, &mut advice_inputs);
+        inputs::extend_advice_inputs(tx_inputs, tx_args, &mut advice_inputs, true);
+
+        (stack_inputs, advice_inputs)
+    }
+
+    /// Same as [Self::prepare_inputs()], but the returned advice inputs do not contain the Merkle
+    /// nodes and leaves of the native account's storage maps and asset vault.
+    ///
+    /// When a transaction is executed with these inputs, the Merkle paths to the storage map
+    /// entries and vault assets accessed by the transaction must be provided on demand, in
+    /// response to the `AccountStorageBeforeGetMapItem`, `AccountStorageBeforeSetMapItem` and
+    /// `AccountVaultBeforeGetAsset` events emitted by the kernel.
+    pub fn prepare_lazy_inputs(
+        tx_inputs: &TransactionInputs,
+        tx_args: &TransactionArgs,
+        init_advice_inputs: Option<AdviceInputs>,
+    ) -> (StackInputs, AdviceInputs) {
+        let account = tx_inputs.account();
+
+        let stack_inputs = TransactionKernel::build_input_stack(
+            account.id(),
+            account.init_hash(),
+            tx_inputs.input_notes().commitment(),
+            tx_inputs.block_header().hash(),
+        );
+
+        let mut advice_inputs = init_advice_inputs.unwrap_or_default();
+        inputs::extend_advice_inputs(tx_inputs, tx_args, &mut advice_inputs, false);
 
         (stack_inputs, advice_inputs)
     }
@@ -201,6 +229,35 @@ impl TransactionKernel {
         Ok(())
     }
 
+    /// Extends the advice inputs with the data required to load the foreign account described by
+    /// the provided [ForeignAccountInputs].
+    ///
+    /// In addition to the data added by [Self::extend_advice_inputs_for_account()], this inserts
+    /// the Merkle nodes and leaves of all storage maps of the foreign account, so that the
+    /// procedures of this account can read from them.
+    pub fn extend_advice_inputs_for_foreign_account(
+        advice_inputs: &mut AdviceInputs,
+        foreign_account_inputs: &ForeignAccountInputs,
+    ) -> Result<(), MerkleError> {
+        Self::extend_advice_inputs_for_account(
+            advice_inputs,
+            foreign_account_inputs.account_header(),
+            foreign_account_inputs.account_code(),
+            foreign_account_inputs.storage_header(),
+            foreign_account_inputs.account_path(),
+        )?;
+
+        for map in foreign_account_inputs.storage_maps() {
+            // extend the merkle store and map with the storage maps
+            advice_inputs.extend_merkle_store(map.inner_nodes());
+            // populate advice map with Sparse Merkle Tree leaf nodes
+            advice_inputs
+                .extend_map(map.leaves().map(|(_, leaf)| (leaf.hash(), leaf.to_elements())));
+        }
+
+        Ok(())
+    }
+
     /// Builds the stack for expected transaction execution outputs.
     /// The transaction kernel's output stack is formed like so:
     ///
@@ -6,7 +6,7 @@ use miden_objects::{digest, Digest};
 // ================================================================================================
 
 /// Hashes of all dynamically executed procedures from the kernel 0.
-pub const KERNEL0_PROCEDURES: [Digest; 35] = [
+pub const KERNEL0_PROCEDURES: [Digest; 36] = [
     // account_get_initial_hash
     digest!("0x920898348bacd6d98a399301eb308478fd32b32eab019a5a6ef7a6b44abb61f6"),
     // account_get_current_hash
@@ -19,34 +19,36 @@ pub const KERNEL0_PROCEDURES: [Digest; 35] = [
     digest!("0xe33735d9c594a0515ee30348a4e988a10b941792a4823c69435a37310ec1fd5f"),
     // account_get_code_commitment
     digest!("0xf998788832427ab137eaee1c4d99aaea435754b861fcb1d00b1640345eb9afe7"),
+    // account_set_code
+    digest!("0xfd353c7072dae26c9a731f7ec2f56dc79bc05a26a55316f1d8a8afb15c70161c"),
     // account_get_storage_commitment
     digest!("0x723581bfeac05bc1f233083c80379c1cd049d0c77c0476fab9d0a896aaa76476"),
     // account_get_item
     digest!("0x05b08e5241a702b90b8623cd97ba83f7c608941dcc73ad4f2ffc71a7f04eb61e"),
     // account_set_item
-    digest!("0x1d87539d6557cc970fc78bfde945d867858c2e6183bee1dc88aceb9b0acfbe21"),
+    digest!("0xd567089c1ab0550af7e5ddf6fa4f3ca75af31e599674572915c6b02201cb0ad0"),
     // account_get_map_item
-    digest!("0xc00e1cd25d6a412624d8f69ec74630688868a56e1c7ad94125ef3eb0da80acd6"),
+    digest!("0x32dfb09818e5975a877456f8f20c256a1cde934187aa66d89ac8f7df5b642b93"),
     // account_set_map_item
-    digest!("0x8391236934b8353ffc13c6ea6fc61db30d1ac65406fafa0a1ba9153ef3d35c6c"),
+    digest!("0x414e1c2ae6d710a0bd4f2fbd2b2be2647b95b102873d27693377dfa3200af277"),
     // account_get_vault_commitment
     digest!("0x814bb6069c9b62fb40597f49547c9ae88603dd9a28f86e9ef0e54e8fc094a92e"),
     // account_add_asset
-    digest!("0xbdebb57af4389e79dcf94529225e0e94053958814624f9731a29d984426e39d0"),
+    digest!("0x456279e7c93a577dc3cae9bb7176b7c34f0e09e7270680ff1da913297d190798"),
     // account_remove_asset
-    digest!("0x9d006820999b7e30c6cfc1ffceac01ac5afbbccd1d8ddf787170b9854734135f"),
+    digest!("0x4df52995746ca9586c3e7b63e8bda21c5e2d1c8a6eb4b48e63e1267f9403b473"),
     // account_get_balance
-    digest!("0x75add0a0be7be690bb744a904408de0c5293b928f573f981280a96607b2b88ed"),
+    digest!("0xfaf7f0f5588c1c0840f728068eebac95bafbaf2ae2f0829ad86db1401882c04a"),
     // account_has_non_fungible_asset
-    digest!("0x653ab7a20e5af62c0022850726fef4f5fd6468f9de4cfc43b8fb6b9ff12e6b32"),
+    digest!("0x4f80744405f03d8f3f635ead1d7893b25f0636856e77dcfe6c9026131c4afffd"),
     // faucet_mint_asset
-    digest!("0x499a9fa3f670529c79c0eaafb07170ce13e003c2b08dda2dc4c2c12b3d96b9af"),
+    digest!("0x667689e225d6702b4399971fff5d85e41af610240ba4e3a87ee40dbe168853bc"),
     // faucet_burn_asset
-    digest!("0xa56c96b989d852fffad0b4ca17de4e15e5865b0e76ea0a40f03959c175bde175"),
+    digest!("0xeb4de2b276dd0f2ab4646407df5e0a135d6b9f40bbbe8b5381f435adaeb01a7b"),
     // faucet_get_total_fungible_asset_issuance
     digest!("0x7c46ed8cc84a0c5439285f715d1c867eb71131e9f0b1bbd65acea9dddc35bd96"),
     // faucet_is_non_fungible_asset_issued
-    digest!("0x2ebb03e088454d8da766957f00c81c2a4c31b74e3f20285716b3f505c7394bc4"),
+    digest!("0x33b24717930f50788c9e071d2b2c5012bc2944c3f0b0a9bb106e5f5c6349fc2f"),
     // note_get_assets_info
     digest!("0x34e4f1ea83eb4342ab8f5acec89962b2ab4b56d9c631e807d8e4dc8efd270bf2"),
     // note_add_asset
@@ -70,7 +72,7 @@ pub const KERNEL0_PROCEDURES: [Digest; 35] = [
     // tx_get_block_number
     digest!("0x297797dff54b8108dd2df254b95d43895d3f917ab10399efc62adaf861c905ae"),
     // tx_start_foreign_context
-    digest!("0xdaf9052e4c583124c5b56703d6b726ddce7a8b69333262d4570991df10b34ad2"),
+    digest!("0x315523dca707e0a09b06705a40d3d3625e8968d43962017470253d37baf9eb64"),
     // tx_end_foreign_context
     digest!("0x0a11755ed547d42974a83aeb7e1df9408aec0bb7423afdd8afdf2a50ef346832"),
     // tx_get_expiration_delta
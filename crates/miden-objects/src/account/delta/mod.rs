@@ -1,8 +1,8 @@
 use alloc::string::ToString;
 
 use super::{
-    Account, ByteReader, ByteWriter, Deserializable, DeserializationError, Felt, Serializable,
-    Word, ZERO,
+    Account, AccountCode, ByteReader, ByteWriter, Deserializable, DeserializationError, Felt,
+    Serializable, Word, ZERO,
 };
 use crate::AccountDeltaError;
 
@@ -23,13 +23,15 @@ pub use vault::{
 /// - storage: an [AccountStorageDelta] that contains the changes to the account storage.
 /// - vault: an [AccountVaultDelta] object that contains the changes to the account vault.
 /// - nonce: if the nonce of the account has changed, the new nonce is stored here.
-///
-/// TODO: add ability to trace account code updates.
+/// - code: if the code of the account has changed, the new code is stored here. Only the code of
+///   accounts of type [RegularAccountUpdatableCode](super::AccountType::RegularAccountUpdatableCode)
+///   can change.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct AccountDelta {
     storage: AccountStorageDelta,
     vault: AccountVaultDelta,
     nonce: Option<Felt>,
+    code: Option<AccountCode>,
 }
 
 impl AccountDelta {
@@ -47,9 +49,20 @@ impl AccountDelta {
         nonce: Option<Felt>,
     ) -> Result<Self, AccountDeltaError> {
         // nonce must be updated if either account storage or vault were updated
-        validate_nonce(nonce, &storage, &vault)?;
+        validate_nonce(nonce, &storage, &vault, None)?;
 
-        Ok(Self { storage, vault, nonce })
+        Ok(Self { storage, vault, nonce, code: None })
+    }
+
+    /// Sets the new code of the account to the provided code.
+    ///
+    /// # Errors
+    /// Returns an error if the nonce of this delta was either not updated or set to 0.
+    pub fn with_code(mut self, code: AccountCode) -> Result<Self, AccountDeltaError> {
+        validate_nonce(self.nonce, &self.storage, &self.vault, Some(&code))?;
+        self.code = Some(code);
+
+        Ok(self)
     }
 
     /// Merge another [AccountDelta] into this one.
@@ -63,6 +76,10 @@ impl AccountDelta {
             // Incoming nonce takes precedence.
             (old, new) => *old = new.or(*old),
         };
+        // Incoming code takes precedence.
+        if other.code.is_some() {
+            self.code = other.code;
+        }
         self.storage.merge(other.storage)?;
         self.vault.merge(other.vault)
     }
@@ -72,7 +89,7 @@ impl AccountDelta {
 
     /// Returns true if this account delta does not contain any updates.
     pub fn is_empty(&self) -> bool {
-        self.storage.is_empty() && self.vault.is_empty()
+        self.storage.is_empty() && self.vault.is_empty() && self.code.is_none()
     }
 
     /// Returns storage updates for this account delta.
@@ -90,9 +107,16 @@ impl AccountDelta {
         self.nonce
     }
 
+    /// Returns the new code of the account, if the code was changed.
+    pub fn code(&self) -> Option<&AccountCode> {
+        self.code.as_ref()
+    }
+
     /// Converts this storage delta into individual delta components.
-    pub fn into_parts(self) -> (AccountStorageDelta, AccountVaultDelta, Option<Felt>) {
-        (self.storage, self.vault, self.nonce)
+    pub fn into_parts(
+        self,
+    ) -> (AccountStorageDelta, AccountVaultDelta, Option<Felt>, Option<AccountCode>) {
+        (self.storage, self.vault, self.nonce, self.code)
     }
 }
 
@@ -162,11 +186,12 @@ impl AccountUpdateDetails {
 /// Converts an [Account] into an [AccountDelta] for initial delta construction.
 impl From<Account> for AccountDelta {
     fn from(account: Account) -> Self {
-        let (_id, vault, storage, _code, nonce) = account.into_parts();
+        let (_id, vault, storage, code, nonce) = account.into_parts();
         AccountDelta {
             storage: storage.into(),
             vault: (&vault).into(),
             nonce: Some(nonce),
+            code: Some(code),
         }
     }
 }
@@ -179,10 +204,14 @@ impl Serializable for AccountDelta {
         self.storage.write_into(target);
         self.vault.write_into(target);
         self.nonce.write_into(target);
+        self.code.write_into(target);
     }
 
     fn get_size_hint(&self) -> usize {
-        self.storage.get_size_hint() + self.vault.get_size_hint() + self.nonce.get_size_hint()
+        self.storage.get_size_hint()
+            + self.vault.get_size_hint()
+            + self.nonce.get_size_hint()
+            + self.code.get_size_hint()
     }
 }
 
@@ -191,11 +220,12 @@ impl Deserializable for AccountDelta {
         let storage = AccountStorageDelta::read_from(source)?;
         let vault = AccountVaultDelta::read_from(source)?;
         let nonce = <Option<Felt>>::read_from(source)?;
+        let code = <Option<AccountCode>>::read_from(source)?;
 
-        validate_nonce(nonce, &storage, &vault)
+        validate_nonce(nonce, &storage, &vault, code.as_ref())
             .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
 
-        Ok(Self { storage, vault, nonce })
+        Ok(Self { storage, vault, nonce, code })
     }
 }
 
@@ -244,17 +274,18 @@ impl Deserializable for AccountUpdateDetails {
 // HELPER FUNCTIONS
 // ================================================================================================
 
-/// Checks if the nonce was updated correctly given the provided storage and vault deltas.
+/// Checks if the nonce was updated correctly given the provided storage, vault and code deltas.
 ///
 /// # Errors
-/// Returns an error if storage or vault were updated, but the nonce was either not updated
+/// Returns an error if storage, vault or code were updated, but the nonce was either not updated
 /// or set to 0.
 fn validate_nonce(
     nonce: Option<Felt>,
     storage: &AccountStorageDelta,
     vault: &AccountVaultDelta,
+    code: Option<&AccountCode>,
 ) -> Result<(), AccountDeltaError> {
-    if !storage.is_empty() || !vault.is_empty() {
+    if !storage.is_empty() || !vault.is_empty() || code.is_some() {
         match nonce {
             Some(nonce) => {
                 if nonce == ZERO {
@@ -239,13 +239,15 @@ impl Account {
     // DATA MUTATORS
     // --------------------------------------------------------------------------------------------
 
-    /// Applies the provided delta to this account. This updates account vault, storage, and nonce
-    /// to the values specified by the delta.
+    /// Applies the provided delta to this account. This updates account vault, storage, code and
+    /// nonce to the values specified by the delta.
     ///
     /// # Errors
     /// Returns an error if:
     /// - Applying vault sub-delta to the vault of this account fails.
     /// - Applying storage sub-delta to the storage of this account fails.
+    /// - The delta updates the code of this account, but this account is not of type
+    ///   [AccountType::RegularAccountUpdatableCode].
     /// - The nonce specified in the provided delta smaller than or equal to the current account
     ///   nonce.
     pub fn apply_delta(&mut self, delta: &AccountDelta) -> Result<(), AccountError> {
@@ -258,6 +260,14 @@ impl Account {
         // update storage
         self.storage.apply_delta(delta.storage())?;
 
+        // update code
+        if let Some(code) = delta.code() {
+            if self.account_type() != AccountType::RegularAccountUpdatableCode {
+                return Err(AccountError::AccountCodeNotUpdatable(self.id()));
+            }
+            self.code = code.clone();
+        }
+
         // update nonce
         if let Some(nonce) = delta.nonce() {
             self.set_nonce(nonce)?;
@@ -403,10 +413,13 @@ mod tests {
         },
         asset::{Asset, AssetVault, FungibleAsset, NonFungibleAsset},
         testing::{
-            account_id::ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+            account_id::{
+                ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+                ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+            },
             storage::AccountStorageDeltaBuilder,
         },
-        AccountError,
+        AccountDeltaError, AccountError,
     };
 
     #[test]
@@ -572,6 +585,49 @@ mod tests {
         account.apply_delta(&account_delta).unwrap()
     }
 
+    #[test]
+    fn account_delta_with_code_is_applied_to_updatable_accounts() {
+        let library = Assembler::default().assemble_library(["export.foo add eq.1 end"]).unwrap();
+        let component = AccountComponent::new(library, vec![]).unwrap().with_supports_all_types();
+        let new_code =
+            AccountCode::from_components(&[component], AccountType::RegularAccountUpdatableCode)
+                .unwrap();
+
+        // a code update must be accompanied by a nonce update
+        let account_delta = AccountDelta::default();
+        assert_matches!(
+            account_delta.with_code(new_code.clone()),
+            Err(AccountDeltaError::InconsistentNonceUpdate(_))
+        );
+
+        let account_delta = AccountDelta::new(
+            AccountStorageDelta::default(),
+            AccountVaultDelta::default(),
+            Some(Felt::new(2)),
+        )
+        .unwrap()
+        .with_code(new_code.clone())
+        .unwrap();
+        assert!(!account_delta.is_empty());
+
+        let serialized = account_delta.to_bytes();
+        assert_eq!(AccountDelta::read_from_bytes(&serialized).unwrap(), account_delta);
+
+        // the code of an account with immutable code cannot be updated
+        let mut account = build_account(vec![], Felt::new(1), vec![]);
+        assert_matches!(
+            account.apply_delta(&account_delta),
+            Err(AccountError::AccountCodeNotUpdatable(_))
+        );
+
+        let (_, vault, storage, code, nonce) = account.into_parts();
+        let id = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        let mut account = Account::from_parts(id, vault, storage, code, nonce);
+        account.apply_delta(&account_delta).unwrap();
+        assert_eq!(account.code(), &new_code);
+        assert_eq!(account.nonce(), Felt::new(2));
+    }
+
     pub fn build_account_delta(
         added_assets: Vec<Asset>,
         removed_assets: Vec<Asset>,
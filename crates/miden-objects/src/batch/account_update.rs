@@ -0,0 +1,158 @@
+use alloc::vec::Vec;
+
+use crate::{
+    account::{delta::AccountUpdateDetails, AccountId},
+    errors::ProposedBatchError,
+    transaction::{ProvenTransaction, TransactionId},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    Digest,
+};
+
+// BATCH ACCOUNT UPDATE
+// ================================================================================================
+
+/// Represents the changes made to an account resulting from executing a batch of transactions.
+///
+/// Several transactions against the same account are merged into a single update, which
+/// transitions the account from the initial state of the first of these transactions to the final
+/// state of the last one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchAccountUpdate {
+    /// ID of the updated account.
+    account_id: AccountId,
+
+    /// Commitment to the state of the account before this update is applied.
+    ///
+    /// Equal to `Digest::default()` for new accounts.
+    initial_state_commitment: Digest,
+
+    /// Commitment to the state of the account after this update is applied.
+    final_state_commitment: Digest,
+
+    /// IDs of all transactions that updated the account, in the order of their execution.
+    transactions: Vec<TransactionId>,
+
+    /// A set of changes which can be applied to the previous account state (i.e. `initial_state`)
+    /// to get the new account state. For private accounts, this is set to
+    /// [AccountUpdateDetails::Private].
+    details: AccountUpdateDetails,
+}
+
+impl BatchAccountUpdate {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Creates a [BatchAccountUpdate] from the account update of the provided transaction.
+    pub fn from_transaction(transaction: &ProvenTransaction) -> Self {
+        let account_update = transaction.account_update();
+
+        Self {
+            account_id: transaction.account_id(),
+            initial_state_commitment: account_update.init_state_hash(),
+            final_state_commitment: account_update.final_state_hash(),
+            transactions: vec![transaction.id()],
+            details: account_update.details().clone(),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the updated account.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns a commitment to the state of the account before this update is applied.
+    pub fn initial_state_commitment(&self) -> Digest {
+        self.initial_state_commitment
+    }
+
+    /// Returns a commitment to the state of the account after this update is applied.
+    pub fn final_state_commitment(&self) -> Digest {
+        self.final_state_commitment
+    }
+
+    /// Returns the IDs of the transactions that updated the account, in the order of their
+    /// execution.
+    pub fn transactions(&self) -> &[TransactionId] {
+        &self.transactions
+    }
+
+    /// Returns the contained [AccountUpdateDetails].
+    pub fn details(&self) -> &AccountUpdateDetails {
+        &self.details
+    }
+
+    /// Returns `true` if the account update details are for a private account.
+    pub fn is_private(&self) -> bool {
+        self.details.is_private()
+    }
+
+    // MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Merges the account update of the provided transaction into this update.
+    ///
+    /// The transaction is expected to be executed against the final state of this update.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The transaction was executed against a different account.
+    /// - The initial state of the transaction does not match the final state of this update.
+    /// - The account update details of the transaction cannot be merged into the details of this
+    ///   update.
+    pub fn merge_proven_tx(&mut self, tx: &ProvenTransaction) -> Result<(), ProposedBatchError> {
+        if self.account_id != tx.account_id() {
+            return Err(ProposedBatchError::AccountUpdateIdMismatch {
+                transaction: tx.id(),
+                expected_account_id: self.account_id,
+                actual_account_id: tx.account_id(),
+            });
+        }
+
+        if self.final_state_commitment != tx.account_update().init_state_hash() {
+            return Err(ProposedBatchError::AccountUpdateInitialStateMismatch {
+                account_id: self.account_id,
+                transaction: tx.id(),
+                expected: self.final_state_commitment,
+                actual: tx.account_update().init_state_hash(),
+            });
+        }
+
+        // the details are taken out temporarily, as merging consumes them
+        let details = core::mem::replace(&mut self.details, AccountUpdateDetails::Private);
+        self.details = details.merge(tx.account_update().details().clone()).map_err(|source| {
+            ProposedBatchError::AccountUpdateMergeFailed { account_id: self.account_id, source }
+        })?;
+        self.final_state_commitment = tx.account_update().final_state_hash();
+        self.transactions.push(tx.id());
+
+        Ok(())
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for BatchAccountUpdate {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account_id.write_into(target);
+        self.initial_state_commitment.write_into(target);
+        self.final_state_commitment.write_into(target);
+        self.transactions.write_into(target);
+        self.details.write_into(target);
+    }
+}
+
+impl Deserializable for BatchAccountUpdate {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            account_id: AccountId::read_from(source)?,
+            initial_state_commitment: Digest::read_from(source)?,
+            final_state_commitment: Digest::read_from(source)?,
+            transactions: <Vec<TransactionId>>::read_from(source)?,
+            details: AccountUpdateDetails::read_from(source)?,
+        })
+    }
+}
@@ -0,0 +1,420 @@
+use alloc::{
+    collections::{btree_map::Entry, BTreeMap, BTreeSet},
+    string::ToString,
+    vec::Vec,
+};
+
+use crate::{
+    account::AccountId,
+    batch::{BatchAccountUpdate, BatchId, BatchNoteTree},
+    block::BlockNumber,
+    errors::ProposedBatchError,
+    note::{NoteId, Nullifier},
+    transaction::{InputNoteCommitment, InputNotes, OutputNote, ProvenTransaction, TransactionId},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    MAX_ACCOUNTS_PER_BATCH, MAX_INPUT_NOTES_PER_BATCH, MAX_OUTPUT_NOTES_PER_BATCH,
+};
+
+// PROPOSED BATCH
+// ================================================================================================
+
+/// A proposed batch of transactions with all the necessary data to validate it.
+///
+/// A proposed batch aggregates a set of [ProvenTransaction]s and ensures that together they form a
+/// valid batch:
+/// - Several transactions against the same account are chained into a single
+///   [BatchAccountUpdate], i.e. each of these transactions must be executed against the final
+///   state of the previous one, in the order in which they appear in the batch.
+/// - Notes which are created by one transaction and consumed as unauthenticated notes by another
+///   transaction of the batch are erased, i.e. they are neither input nor output notes of the
+///   batch.
+/// - The numbers of updated accounts, input notes, and output notes do not exceed the limits of a
+///   batch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProposedBatch {
+    /// The ID of the batch, computed from the IDs of its transactions.
+    id: BatchId,
+    /// The transactions of the batch, in the order of their execution.
+    transactions: Vec<ProvenTransaction>,
+    /// The updates of all accounts touched by the transactions of the batch.
+    account_updates: BTreeMap<AccountId, BatchAccountUpdate>,
+    /// The notes consumed by the batch, excluding the notes which were created in the batch.
+    input_notes: InputNotes<InputNoteCommitment>,
+    /// The notes created by the batch, excluding the notes which were consumed in the batch.
+    output_notes: Vec<OutputNote>,
+    /// The tree of the output notes of the batch.
+    batch_note_tree: BatchNoteTree,
+    /// The block number at which the batch expires, which is the minimum of the expiration block
+    /// numbers of its transactions.
+    batch_expiration_block_num: BlockNumber,
+}
+
+impl ProposedBatch {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Creates a new [ProposedBatch] from the provided transactions.
+    ///
+    /// The transactions are expected to be ordered such that transactions against the same account
+    /// appear in the order of their execution.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The batch does not contain any transactions.
+    /// - A transaction appears more than once in the batch.
+    /// - A transaction against an account is not executed against the final state of the previous
+    ///   transaction against the same account, or the updates of the account cannot be merged.
+    /// - A note is consumed by more than one transaction, or created by more than one transaction.
+    /// - A note created in the batch is consumed as an unauthenticated note whose header does not
+    ///   match the header of the created note.
+    /// - The number of updated accounts exceeds [MAX_ACCOUNTS_PER_BATCH].
+    /// - The number of input notes exceeds [MAX_INPUT_NOTES_PER_BATCH].
+    /// - The number of output notes exceeds [MAX_OUTPUT_NOTES_PER_BATCH].
+    pub fn new(transactions: Vec<ProvenTransaction>) -> Result<Self, ProposedBatchError> {
+        if transactions.is_empty() {
+            return Err(ProposedBatchError::EmptyTransactionBatch);
+        }
+
+        let mut transaction_ids = BTreeSet::new();
+        for tx in transactions.iter() {
+            if !transaction_ids.insert(tx.id()) {
+                return Err(ProposedBatchError::DuplicateTransaction { transaction_id: tx.id() });
+            }
+        }
+
+        // chain the updates of each account
+        // ----------------------------------------------------------------------------------------
+
+        let mut account_updates = BTreeMap::<AccountId, BatchAccountUpdate>::new();
+        for tx in transactions.iter() {
+            match account_updates.entry(tx.account_id()) {
+                Entry::Vacant(entry) => {
+                    entry.insert(BatchAccountUpdate::from_transaction(tx));
+                },
+                Entry::Occupied(mut entry) => entry.get_mut().merge_proven_tx(tx)?,
+            }
+        }
+
+        if account_updates.len() > MAX_ACCOUNTS_PER_BATCH {
+            return Err(ProposedBatchError::TooManyAccountUpdates(account_updates.len()));
+        }
+
+        // collect the notes created and consumed by the transactions
+        // ----------------------------------------------------------------------------------------
+
+        let mut created_notes = BTreeMap::<NoteId, (TransactionId, &OutputNote)>::new();
+        for tx in transactions.iter() {
+            for note in tx.output_notes().iter() {
+                if let Some((first_transaction_id, _)) =
+                    created_notes.insert(note.id(), (tx.id(), note))
+                {
+                    return Err(ProposedBatchError::DuplicateOutputNote {
+                        note_id: note.id(),
+                        first_transaction_id,
+                        second_transaction_id: tx.id(),
+                    });
+                }
+            }
+        }
+
+        let mut consumed_notes = BTreeMap::<Nullifier, TransactionId>::new();
+        let mut erased_notes = BTreeSet::<NoteId>::new();
+        let mut input_notes = Vec::new();
+        for tx in transactions.iter() {
+            for note in tx.input_notes().iter() {
+                if let Some(first_transaction_id) = consumed_notes.insert(note.nullifier(), tx.id())
+                {
+                    return Err(ProposedBatchError::DuplicateInputNote {
+                        note_nullifier: note.nullifier(),
+                        first_transaction_id,
+                        second_transaction_id: tx.id(),
+                    });
+                }
+
+                // unauthenticated notes created in the batch are erased from the inputs and outputs
+                if let Some(header) = note.header() {
+                    if let Some((_, output_note)) = created_notes.get(&header.id()) {
+                        if output_note.hash() != header.hash() {
+                            return Err(ProposedBatchError::NoteHashesMismatch {
+                                id: header.id(),
+                                input_hash: header.hash(),
+                                output_hash: output_note.hash(),
+                            });
+                        }
+                        erased_notes.insert(header.id());
+                        continue;
+                    }
+                }
+
+                input_notes.push(note.clone());
+            }
+        }
+
+        let output_notes: Vec<OutputNote> = transactions
+            .iter()
+            .flat_map(|tx| tx.output_notes().iter())
+            .filter(|note| !erased_notes.contains(&note.id()))
+            .cloned()
+            .collect();
+
+        if input_notes.len() > MAX_INPUT_NOTES_PER_BATCH {
+            return Err(ProposedBatchError::TooManyInputNotes(input_notes.len()));
+        }
+        if output_notes.len() > MAX_OUTPUT_NOTES_PER_BATCH {
+            return Err(ProposedBatchError::TooManyOutputNotes(output_notes.len()));
+        }
+
+        let input_notes = InputNotes::new(input_notes)
+            .expect("input notes should be unique and within the limits of a batch");
+        let batch_note_tree = BatchNoteTree::with_contiguous_leaves(
+            output_notes.iter().map(|note| (note.id(), note.metadata())),
+        )
+        .expect("number of output notes should not exceed the capacity of the batch note tree");
+
+        let batch_expiration_block_num = transactions
+            .iter()
+            .map(ProvenTransaction::expiration_block_num)
+            .min()
+            .expect("batch should contain at least one transaction");
+
+        Ok(Self {
+            id: BatchId::from_transactions(transactions.iter()),
+            transactions,
+            account_updates,
+            input_notes,
+            output_notes,
+            batch_note_tree,
+            batch_expiration_block_num,
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of this batch.
+    pub fn id(&self) -> BatchId {
+        self.id
+    }
+
+    /// Returns the transactions of this batch, in the order of their execution.
+    pub fn transactions(&self) -> &[ProvenTransaction] {
+        &self.transactions
+    }
+
+    /// Returns the updates of all accounts touched by the transactions of this batch.
+    pub fn account_updates(&self) -> &BTreeMap<AccountId, BatchAccountUpdate> {
+        &self.account_updates
+    }
+
+    /// Returns the notes consumed by this batch, excluding the notes which were created in the
+    /// batch.
+    ///
+    /// The commitment of the returned [InputNotes] is the input notes commitment of the batch.
+    pub fn input_notes(&self) -> &InputNotes<InputNoteCommitment> {
+        &self.input_notes
+    }
+
+    /// Returns the notes created by this batch, excluding the notes which were consumed in the
+    /// batch.
+    pub fn output_notes(&self) -> &[OutputNote] {
+        &self.output_notes
+    }
+
+    /// Returns the tree of the output notes of this batch.
+    pub fn batch_note_tree(&self) -> &BatchNoteTree {
+        &self.batch_note_tree
+    }
+
+    /// Returns the block number at which this batch expires.
+    pub fn batch_expiration_block_num(&self) -> BlockNumber {
+        self.batch_expiration_block_num
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Consumes this batch and returns its transactions, ID, account updates, input notes, output
+    /// notes and expiration block number.
+    #[allow(clippy::type_complexity)]
+    pub fn into_parts(
+        self,
+    ) -> (
+        Vec<ProvenTransaction>,
+        BatchId,
+        BTreeMap<AccountId, BatchAccountUpdate>,
+        InputNotes<InputNoteCommitment>,
+        Vec<OutputNote>,
+        BlockNumber,
+    ) {
+        (
+            self.transactions,
+            self.id,
+            self.account_updates,
+            self.input_notes,
+            self.output_notes,
+            self.batch_expiration_block_num,
+        )
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ProposedBatch {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.transactions.write_into(target);
+    }
+}
+
+impl Deserializable for ProposedBatch {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let transactions = <Vec<ProvenTransaction>>::read_from(source)?;
+
+        Self::new(transactions).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use assert_matches::assert_matches;
+
+    use super::ProposedBatch;
+    use crate::{
+        account::AccountId,
+        batch::{
+            test_utils::{mock_note, mock_proven_tx},
+            BatchNoteTree,
+        },
+        block::BlockNumber,
+        testing::account_id::{
+            ACCOUNT_ID_OFF_CHAIN_SENDER, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+        },
+        transaction::{InputNote, InputNoteCommitment, OutputNote},
+        Digest, ProposedBatchError,
+    };
+
+    fn account_id() -> AccountId {
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap()
+    }
+
+    #[test]
+    fn empty_batch_is_rejected() {
+        assert_matches!(
+            ProposedBatch::new(Vec::new()),
+            Err(ProposedBatchError::EmptyTransactionBatch)
+        );
+    }
+
+    #[test]
+    fn duplicate_transactions_are_rejected() {
+        let tx = mock_proven_tx(account_id(), 1, 2, Vec::new(), Vec::new(), 10);
+
+        let err = ProposedBatch::new(vec![tx.clone(), tx.clone()]).unwrap_err();
+        assert_matches!(err, ProposedBatchError::DuplicateTransaction { transaction_id } if transaction_id == tx.id());
+    }
+
+    #[test]
+    fn account_updates_are_chained() {
+        let sender = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        let tx1 = mock_proven_tx(account_id(), 1, 2, Vec::new(), Vec::new(), 10);
+        let tx2 = mock_proven_tx(sender, 5, 6, Vec::new(), Vec::new(), 7);
+        let tx3 = mock_proven_tx(account_id(), 2, 3, Vec::new(), Vec::new(), 12);
+
+        let batch = ProposedBatch::new(vec![tx1.clone(), tx2.clone(), tx3.clone()]).unwrap();
+
+        assert_eq!(batch.account_updates().len(), 2);
+        let update = batch.account_updates().get(&account_id()).unwrap();
+        assert_eq!(update.initial_state_commitment(), Digest::from([1u32, 0, 0, 0]));
+        assert_eq!(update.final_state_commitment(), Digest::from([3u32, 0, 0, 0]));
+        assert_eq!(update.transactions(), &[tx1.id(), tx3.id()]);
+        assert_eq!(batch.batch_expiration_block_num(), BlockNumber::from(7));
+
+        // the order of the transactions determines the ID of the batch
+        let reordered = ProposedBatch::new(vec![tx2, tx1, tx3]).unwrap();
+        assert_ne!(batch.id(), reordered.id());
+    }
+
+    #[test]
+    fn unchained_account_updates_are_rejected() {
+        let tx1 = mock_proven_tx(account_id(), 1, 2, Vec::new(), Vec::new(), 10);
+        let tx2 = mock_proven_tx(account_id(), 3, 4, Vec::new(), Vec::new(), 10);
+
+        let err = ProposedBatch::new(vec![tx1, tx2.clone()]).unwrap_err();
+        assert_matches!(err, ProposedBatchError::AccountUpdateInitialStateMismatch {
+            account_id: id, transaction, expected, actual
+        } if id == account_id()
+            && transaction == tx2.id()
+            && expected == Digest::from([2u32, 0, 0, 0])
+            && actual == Digest::from([3u32, 0, 0, 0]));
+    }
+
+    #[test]
+    fn notes_created_and_consumed_in_the_batch_are_erased() {
+        let sender = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        let erased_note = mock_note(1);
+        let output_note = mock_note(2);
+        let input_note = mock_note(3);
+
+        let tx1 = mock_proven_tx(
+            account_id(),
+            1,
+            2,
+            vec![InputNoteCommitment::from(input_note.nullifier())],
+            vec![OutputNote::Full(erased_note.clone()), OutputNote::Full(output_note.clone())],
+            10,
+        );
+        let tx2 = mock_proven_tx(
+            sender,
+            5,
+            6,
+            vec![InputNoteCommitment::from(InputNote::unauthenticated(erased_note))],
+            Vec::new(),
+            10,
+        );
+
+        let batch = ProposedBatch::new(vec![tx1, tx2]).unwrap();
+
+        assert_eq!(batch.input_notes().num_notes(), 1);
+        assert_eq!(batch.input_notes().get_note(0).nullifier(), input_note.nullifier());
+        assert_eq!(batch.output_notes(), &[OutputNote::Full(output_note.clone())]);
+        let expected_tree =
+            BatchNoteTree::with_contiguous_leaves([(output_note.id(), output_note.metadata())])
+                .unwrap();
+        assert_eq!(batch.batch_note_tree().root(), expected_tree.root());
+    }
+
+    #[test]
+    fn duplicate_notes_are_rejected() {
+        let sender = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        let note = mock_note(1);
+
+        let tx1 = mock_proven_tx(
+            account_id(),
+            1,
+            2,
+            vec![InputNoteCommitment::from(note.nullifier())],
+            vec![OutputNote::Full(note.clone())],
+            10,
+        );
+        let tx2 = mock_proven_tx(
+            sender,
+            5,
+            6,
+            vec![InputNoteCommitment::from(note.nullifier())],
+            vec![OutputNote::Full(note.clone())],
+            10,
+        );
+
+        let err = ProposedBatch::new(vec![tx1.clone(), tx2.clone()]).unwrap_err();
+        assert_matches!(err, ProposedBatchError::DuplicateOutputNote {
+            note_id, first_transaction_id, second_transaction_id
+        } if note_id == note.id()
+            && first_transaction_id == tx1.id()
+            && second_transaction_id == tx2.id());
+    }
+}
@@ -0,0 +1,148 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use crate::{
+    account::AccountId,
+    batch::{BatchAccountUpdate, BatchId, BatchNoteTree},
+    block::BlockNumber,
+    note::Nullifier,
+    transaction::{InputNoteCommitment, InputNotes, OutputNote, TransactionHeader},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
+
+// PROVEN BATCH
+// ================================================================================================
+
+/// A batch of proven transactions which is ready to be included in a block.
+///
+/// A proven batch contains the aggregated effects of its transactions, as computed by the
+/// [ProposedBatch](super::ProposedBatch) it was built from: the updates of all accounts touched by
+/// the batch, and the notes consumed and created by the batch. It also keeps the headers of its
+/// transactions, against which the account updates of the batch can be authenticated.
+///
+/// A proven batch does not carry a proof aggregating the proofs of its transactions. The proof of
+/// every transaction of the batch must therefore be verified before the batch is built, as done
+/// by the `LocalBatchProver` of the `miden-tx` crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProvenBatch {
+    id: BatchId,
+    transactions: Vec<TransactionHeader>,
+    account_updates: BTreeMap<AccountId, BatchAccountUpdate>,
+    input_notes: InputNotes<InputNoteCommitment>,
+    output_notes: Vec<OutputNote>,
+    batch_expiration_block_num: BlockNumber,
+}
+
+impl ProvenBatch {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Creates a new [ProvenBatch] from the provided parts.
+    pub fn new(
+        id: BatchId,
+        transactions: Vec<TransactionHeader>,
+        account_updates: BTreeMap<AccountId, BatchAccountUpdate>,
+        input_notes: InputNotes<InputNoteCommitment>,
+        output_notes: Vec<OutputNote>,
+        batch_expiration_block_num: BlockNumber,
+    ) -> Self {
+        Self {
+            id,
+            transactions,
+            account_updates,
+            input_notes,
+            output_notes,
+            batch_expiration_block_num,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of this batch.
+    pub fn id(&self) -> BatchId {
+        self.id
+    }
+
+    /// Returns the headers of the transactions of this batch, in the order of their execution.
+    pub fn transactions(&self) -> &[TransactionHeader] {
+        &self.transactions
+    }
+
+    /// Returns the updates of all accounts touched by the transactions of this batch.
+    pub fn account_updates(&self) -> &BTreeMap<AccountId, BatchAccountUpdate> {
+        &self.account_updates
+    }
+
+    /// Returns an iterator over the IDs of all accounts updated in this batch.
+    pub fn updated_accounts(&self) -> impl Iterator<Item = AccountId> + '_ {
+        self.account_updates.keys().copied()
+    }
+
+    /// Returns the notes consumed by this batch.
+    pub fn input_notes(&self) -> &InputNotes<InputNoteCommitment> {
+        &self.input_notes
+    }
+
+    /// Returns an iterator over the nullifiers of the notes consumed by this batch.
+    pub fn produced_nullifiers(&self) -> impl Iterator<Item = Nullifier> + '_ {
+        self.input_notes.iter().map(InputNoteCommitment::nullifier)
+    }
+
+    /// Returns the notes created by this batch.
+    pub fn output_notes(&self) -> &[OutputNote] {
+        &self.output_notes
+    }
+
+    /// Returns the tree of the output notes of this batch.
+    pub fn output_notes_tree(&self) -> BatchNoteTree {
+        BatchNoteTree::with_contiguous_leaves(
+            self.output_notes.iter().map(|note| (note.id(), note.metadata())),
+        )
+        .expect("number of output notes should not exceed the capacity of the batch note tree")
+    }
+
+    /// Returns the block number at which this batch expires.
+    pub fn batch_expiration_block_num(&self) -> BlockNumber {
+        self.batch_expiration_block_num
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ProvenBatch {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.id.write_into(target);
+        self.transactions.write_into(target);
+        target.write_usize(self.account_updates.len());
+        target.write_many(self.account_updates.values());
+        self.input_notes.write_into(target);
+        self.output_notes.write_into(target);
+        self.batch_expiration_block_num.write_into(target);
+    }
+}
+
+impl Deserializable for ProvenBatch {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let id = BatchId::read_from(source)?;
+        let transactions = <Vec<TransactionHeader>>::read_from(source)?;
+        let num_account_updates = source.read_usize()?;
+        let account_updates = source
+            .read_many::<BatchAccountUpdate>(num_account_updates)?
+            .into_iter()
+            .map(|update| (update.account_id(), update))
+            .collect();
+        let input_notes = InputNotes::<InputNoteCommitment>::read_from(source)?;
+        let output_notes = <Vec<OutputNote>>::read_from(source)?;
+        let batch_expiration_block_num = BlockNumber::read_from(source)?;
+
+        Ok(Self::new(
+            id,
+            transactions,
+            account_updates,
+            input_notes,
+            output_notes,
+            batch_expiration_block_num,
+        ))
+    }
+}
@@ -0,0 +1,71 @@
+use alloc::vec::Vec;
+
+use assembly::Assembler;
+use miden_air::HashFunction;
+use rand_xoshiro::{rand_core::SeedableRng, Xoshiro256PlusPlus};
+use winter_air::proof::Proof;
+
+use crate::{
+    account::AccountId,
+    batch::{ProposedBatch, ProvenBatch},
+    block::BlockNumber,
+    note::Note,
+    testing::{account_id::ACCOUNT_ID_OFF_CHAIN_SENDER, note::NoteBuilder},
+    transaction::{
+        InputNoteCommitment, OutputNote, ProvenTransaction, ProvenTransactionBuilder,
+        TransactionHeader,
+    },
+    vm::ExecutionProof,
+    Digest,
+};
+
+/// Returns a note with a random serial number derived from the provided seed.
+pub(crate) fn mock_note(seed: u64) -> Note {
+    let sender = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+    NoteBuilder::new(sender, Xoshiro256PlusPlus::seed_from_u64(seed))
+        .build(&Assembler::default())
+        .unwrap()
+}
+
+/// Returns a transaction with a dummy proof which transitions the account from the state
+/// `[initial_state, 0, 0, 0]` to the state `[final_state, 0, 0, 0]`.
+///
+/// An initial state of `0` corresponds to a new account.
+pub(crate) fn mock_proven_tx(
+    account_id: AccountId,
+    initial_state: u32,
+    final_state: u32,
+    input_notes: Vec<InputNoteCommitment>,
+    output_notes: Vec<OutputNote>,
+    expiration_block_num: u32,
+) -> ProvenTransaction {
+    let proof = ExecutionProof::new(Proof::new_dummy(), HashFunction::Blake3_192);
+
+    ProvenTransactionBuilder::new(
+        account_id,
+        Digest::from([initial_state, 0, 0, 0]),
+        Digest::from([final_state, 0, 0, 0]),
+        Digest::default(),
+        BlockNumber::from(expiration_block_num),
+        proof,
+    )
+    .add_input_notes(input_notes)
+    .add_output_notes(output_notes)
+    .build()
+    .unwrap()
+}
+
+/// Returns a [ProvenBatch] built from the provided transactions without proving them.
+pub(crate) fn mock_proven_batch(transactions: Vec<ProvenTransaction>) -> ProvenBatch {
+    let (transactions, id, account_updates, input_notes, output_notes, expiration_block_num) =
+        ProposedBatch::new(transactions).unwrap().into_parts();
+
+    ProvenBatch::new(
+        id,
+        transactions.iter().map(TransactionHeader::from).collect(),
+        account_updates,
+        input_notes,
+        output_notes,
+        expiration_block_num,
+    )
+}
@@ -0,0 +1,440 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use super::BlockAccountUpdate;
+use crate::{
+    account::AccountId,
+    crypto::merkle::{LeafIndex, MerklePath, MutationSet, SimpleSmt},
+    errors::AccountTreeError,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    Digest, Word, ACCOUNT_TREE_DEPTH, EMPTY_WORD,
+};
+
+// ACCOUNT TREE
+// ================================================================================================
+
+/// A sparse Merkle tree of the state commitments of all accounts in the chain.
+///
+/// Each account is stored in the leaf at the index given by the prefix of its ID. To keep the
+/// state of every account provable, the tree guarantees that no two accounts in it share the same
+/// ID prefix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountTree {
+    smt: SimpleSmt<ACCOUNT_TREE_DEPTH>,
+    account_ids: BTreeMap<LeafIndex<ACCOUNT_TREE_DEPTH>, AccountId>,
+}
+
+impl AccountTree {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The depth of the account tree.
+    pub const DEPTH: u8 = ACCOUNT_TREE_DEPTH;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty [AccountTree].
+    pub fn new() -> Self {
+        Self {
+            smt: SimpleSmt::new().expect("account tree depth should be valid"),
+            account_ids: BTreeMap::new(),
+        }
+    }
+
+    /// Returns a new [AccountTree] containing the provided accounts with the provided state
+    /// commitments.
+    ///
+    /// # Errors
+    /// Returns an error if an account appears more than once, or if two accounts share the same ID
+    /// prefix.
+    pub fn with_entries(
+        entries: impl IntoIterator<Item = (AccountId, Digest)>,
+    ) -> Result<Self, AccountTreeError> {
+        let mut tree = Self::new();
+        let mutations = tree.compute_mutations(entries)?;
+        tree.apply_mutations(mutations)?;
+
+        Ok(tree)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of the account tree.
+    pub fn root(&self) -> Digest {
+        self.smt.root()
+    }
+
+    /// Returns the number of accounts in the tree.
+    pub fn num_accounts(&self) -> usize {
+        self.account_ids.len()
+    }
+
+    /// Returns an iterator over the accounts in the tree and their state commitments.
+    pub fn entries(&self) -> impl Iterator<Item = (AccountId, Digest)> + '_ {
+        self.account_ids
+            .iter()
+            .map(|(index, account_id)| (*account_id, Digest::from(self.smt.get_leaf(index))))
+    }
+
+    /// Returns `true` if the provided account is in the tree.
+    pub fn contains(&self, account_id: AccountId) -> bool {
+        self.account_ids.get(&LeafIndex::from(account_id)) == Some(&account_id)
+    }
+
+    /// Returns the state commitment of the provided account, or `None` if the account is not in
+    /// the tree.
+    pub fn get(&self, account_id: AccountId) -> Option<Digest> {
+        let index = LeafIndex::from(account_id);
+        (self.account_ids.get(&index) == Some(&account_id))
+            .then(|| Digest::from(self.smt.get_leaf(&index)))
+    }
+
+    /// Returns a witness for the state commitment of the provided account as of the current root
+    /// of the tree.
+    ///
+    /// If the account is not in the tree, the witness commits to the empty state commitment, and
+    /// so proves that the account does not exist yet, unless another account with the same ID
+    /// prefix is in the tree, in which case the witness does not verify against the root.
+    pub fn open(&self, account_id: AccountId) -> AccountWitness {
+        let index = LeafIndex::from(account_id);
+        let state_commitment = self.get(account_id).unwrap_or_default();
+        AccountWitness::new(account_id, state_commitment, self.smt.open(&index).path)
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Computes the mutations which set the state commitments of the provided accounts, without
+    /// changing the tree.
+    ///
+    /// Accounts which are not in the tree yet are added to it.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - An account appears more than once in the provided updates.
+    /// - A new account shares its ID prefix with an account in the tree, or with another new
+    ///   account.
+    pub fn compute_mutations(
+        &self,
+        updates: impl IntoIterator<Item = (AccountId, Digest)>,
+    ) -> Result<AccountMutationSet, AccountTreeError> {
+        let mut new_leaves = BTreeMap::<LeafIndex<ACCOUNT_TREE_DEPTH>, (AccountId, Word)>::new();
+        for (account_id, state_commitment) in updates {
+            let index = LeafIndex::from(account_id);
+            if let Some(&existing_account_id) = self.account_ids.get(&index) {
+                if existing_account_id != account_id {
+                    return Err(AccountTreeError::DuplicateIdPrefix {
+                        account_id,
+                        existing_account_id,
+                    });
+                }
+            }
+
+            if let Some((existing_account_id, _)) =
+                new_leaves.insert(index, (account_id, state_commitment.into()))
+            {
+                return Err(if existing_account_id == account_id {
+                    AccountTreeError::DuplicateAccountId(account_id)
+                } else {
+                    AccountTreeError::DuplicateIdPrefix { account_id, existing_account_id }
+                });
+            }
+        }
+
+        let account_ids = new_leaves.values().map(|(account_id, _)| *account_id).collect();
+        let mutations = self
+            .smt
+            .compute_mutations(new_leaves.into_iter().map(|(index, (_, value))| (index, value)));
+
+        Ok(AccountMutationSet { mutations, account_ids })
+    }
+
+    /// Applies the provided mutations to the tree, and returns the mutations which revert them.
+    ///
+    /// Applying the returned mutations restores the tree to its state before this call, including
+    /// removing the accounts which were added by the provided mutations.
+    ///
+    /// # Errors
+    /// Returns an error if the mutations were computed against a different root of the tree, in
+    /// which case the tree is left unchanged.
+    pub fn apply_mutations(
+        &mut self,
+        mutations: AccountMutationSet,
+    ) -> Result<AccountMutationSet, AccountTreeError> {
+        let AccountMutationSet { mutations, account_ids } = mutations;
+
+        // keep the values of the changed leaves to update the account IDs once the mutations are
+        // applied; leaves whose value does not change are not part of the mutations
+        let new_values: Vec<_> = account_ids
+            .iter()
+            .filter_map(|account_id| {
+                let index = LeafIndex::from(*account_id);
+                let value = mutations.new_pairs().get(&index)?;
+                Some((index, *account_id, *value))
+            })
+            .collect();
+
+        let reversion = self
+            .smt
+            .apply_mutations_with_reversion(mutations)
+            .map_err(AccountTreeError::ApplyMutationsFailed)?;
+
+        for (index, account_id, value) in new_values {
+            if value == EMPTY_WORD {
+                self.account_ids.remove(&index);
+            } else {
+                self.account_ids.insert(index, account_id);
+            }
+        }
+
+        Ok(AccountMutationSet { mutations: reversion, account_ids })
+    }
+
+    /// Applies the provided account updates of a block to the tree, and returns the mutations
+    /// which revert them.
+    ///
+    /// Either all updates are applied, or, if an error is returned, the tree is left unchanged.
+    ///
+    /// # Errors
+    /// Returns an error if the updates cannot be applied to the tree, see
+    /// [AccountTree::compute_mutations].
+    pub fn apply_block_updates(
+        &mut self,
+        updates: &[BlockAccountUpdate],
+    ) -> Result<AccountMutationSet, AccountTreeError> {
+        let mutations = self.compute_mutations(
+            updates.iter().map(|update| (update.account_id(), update.new_state_hash())),
+        )?;
+
+        self.apply_mutations(mutations)
+    }
+}
+
+impl Default for AccountTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ACCOUNT MUTATION SET
+// ================================================================================================
+
+/// A set of changes to the [AccountTree] computed by [AccountTree::compute_mutations].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountMutationSet {
+    mutations: MutationSet<ACCOUNT_TREE_DEPTH, LeafIndex<ACCOUNT_TREE_DEPTH>, Word>,
+    account_ids: Vec<AccountId>,
+}
+
+impl AccountMutationSet {
+    /// Returns the root of the account tree the mutations were computed against.
+    pub fn old_root(&self) -> Digest {
+        self.mutations.old_root()
+    }
+
+    /// Returns the root of the account tree after the mutations are applied.
+    pub fn root(&self) -> Digest {
+        self.mutations.root()
+    }
+
+    /// Returns the IDs of the accounts updated by the mutations.
+    pub fn account_ids(&self) -> &[AccountId] {
+        &self.account_ids
+    }
+}
+
+// ACCOUNT WITNESS
+// ================================================================================================
+
+/// A proof that an account has a given state commitment as of a given root of the [AccountTree].
+///
+/// An empty state commitment proves that the account does not exist.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountWitness {
+    account_id: AccountId,
+    state_commitment: Digest,
+    path: MerklePath,
+}
+
+impl AccountWitness {
+    /// Creates a new [AccountWitness] from the provided account ID, state commitment and the path
+    /// from the leaf of the account to the root of the account tree.
+    fn new(account_id: AccountId, state_commitment: Digest, path: MerklePath) -> Self {
+        Self { account_id, state_commitment, path }
+    }
+
+    /// Returns the ID of the account this witness is for.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the state commitment of the account.
+    pub fn state_commitment(&self) -> Digest {
+        self.state_commitment
+    }
+
+    /// Returns the index of the leaf of the account in the account tree.
+    pub fn leaf_index(&self) -> LeafIndex<ACCOUNT_TREE_DEPTH> {
+        LeafIndex::from(self.account_id)
+    }
+
+    /// Returns the path from the leaf of the account to the root of the account tree.
+    pub fn path(&self) -> &MerklePath {
+        &self.path
+    }
+
+    /// Returns the root of the account tree this witness resolves to.
+    pub fn compute_root(&self) -> Digest {
+        self.path
+            .compute_root(self.leaf_index().value(), self.state_commitment)
+            .expect("path depth should match the depth of the account tree")
+    }
+
+    /// Returns `true` if this witness proves the state commitment of the account as of the
+    /// provided root of the account tree.
+    pub fn verify(&self, root: Digest) -> bool {
+        self.compute_root() == root
+    }
+
+    /// Consumes this witness and returns its account ID, state commitment and path.
+    pub fn into_parts(self) -> (AccountId, Digest, MerklePath) {
+        (self.account_id, self.state_commitment, self.path)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for AccountWitness {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account_id.write_into(target);
+        self.state_commitment.write_into(target);
+        self.path.write_into(target);
+    }
+}
+
+impl Deserializable for AccountWitness {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account_id = AccountId::read_from(source)?;
+        let state_commitment = Digest::read_from(source)?;
+        let path = MerklePath::read_from(source)?;
+        if path.depth() != ACCOUNT_TREE_DEPTH {
+            return Err(DeserializationError::InvalidValue(format!(
+                "account witness path has depth {} but the account tree has depth {ACCOUNT_TREE_DEPTH}",
+                path.depth()
+            )));
+        }
+
+        Ok(Self::new(account_id, state_commitment, path))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::{AccountTree, AccountWitness};
+    use crate::{
+        account::AccountId,
+        testing::account_id::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+        },
+        utils::serde::{Deserializable, Serializable},
+        AccountTreeError, Digest,
+    };
+
+    fn account_id(id: u128) -> AccountId {
+        AccountId::try_from(id).unwrap()
+    }
+
+    fn state(seed: u32) -> Digest {
+        Digest::from([seed, 1, 2, 3])
+    }
+
+    #[test]
+    fn witnesses_prove_account_state() {
+        let existing = account_id(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN);
+        let absent = account_id(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN);
+        let tree = AccountTree::with_entries([(existing, state(1))]).unwrap();
+        let root = tree.root();
+
+        assert_eq!(tree.num_accounts(), 1);
+        assert_eq!(tree.get(existing), Some(state(1)));
+        assert_eq!(tree.get(absent), None);
+
+        let witness = tree.open(existing);
+        assert_eq!(witness.state_commitment(), state(1));
+        assert!(witness.verify(root));
+
+        let witness = tree.open(absent);
+        assert_eq!(witness.state_commitment(), Digest::default());
+        assert!(witness.verify(root));
+
+        let deserialized = AccountWitness::read_from_bytes(&witness.to_bytes()).unwrap();
+        assert_eq!(deserialized, witness);
+    }
+
+    #[test]
+    fn accounts_with_the_same_id_prefix_are_rejected() {
+        let account = account_id(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN);
+        // same prefix as the account above but a different suffix
+        let colliding = account_id(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN ^ 0x100);
+        assert_eq!(account.prefix(), colliding.prefix());
+
+        let mut tree = AccountTree::with_entries([(account, state(1))]).unwrap();
+        assert_matches!(
+            tree.compute_mutations([(colliding, state(2))]),
+            Err(AccountTreeError::DuplicateIdPrefix { account_id, existing_account_id })
+                if account_id == colliding && existing_account_id == account
+        );
+        assert_matches!(
+            AccountTree::with_entries([(account, state(1)), (colliding, state(2))]),
+            Err(AccountTreeError::DuplicateIdPrefix { .. })
+        );
+        assert_matches!(
+            AccountTree::with_entries([(account, state(1)), (account, state(2))]),
+            Err(AccountTreeError::DuplicateAccountId(id)) if id == account
+        );
+
+        // a witness of the colliding account does not verify
+        assert!(!tree.open(colliding).verify(tree.root()));
+
+        // updating the existing account is fine, including to its current state
+        for new_state in [state(2), state(2)] {
+            let mutations = tree.compute_mutations([(account, new_state)]).unwrap();
+            tree.apply_mutations(mutations).unwrap();
+            assert_eq!(tree.get(account), Some(new_state));
+        }
+    }
+
+    #[test]
+    fn applied_mutations_can_be_reverted() {
+        let account = account_id(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN);
+        let new_account = account_id(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN);
+        let mut tree = AccountTree::with_entries([(account, state(1))]).unwrap();
+        let initial_tree = tree.clone();
+
+        let mutations =
+            tree.compute_mutations([(account, state(2)), (new_account, state(3))]).unwrap();
+        let reversion = tree.apply_mutations(mutations.clone()).unwrap();
+        assert_eq!(tree.root(), mutations.root());
+        assert_eq!(tree.get(account), Some(state(2)));
+        assert_eq!(tree.get(new_account), Some(state(3)));
+
+        // mutations computed against a different root are rejected
+        assert_matches!(
+            tree.apply_mutations(mutations),
+            Err(AccountTreeError::ApplyMutationsFailed(_))
+        );
+
+        tree.apply_mutations(reversion).unwrap();
+        assert_eq!(tree, initial_tree);
+        assert!(!tree.contains(new_account));
+    }
+}
@@ -0,0 +1,814 @@
+use alloc::{
+    collections::{btree_map::Entry, BTreeMap, BTreeSet},
+    vec::Vec,
+};
+
+use super::{
+    compute_tx_hash, AccountTree, Block, BlockAccountUpdate, BlockHeader, BlockNoteIndex,
+    BlockNoteTree, NoteBatch, NullifierTree, ProposedBlock,
+};
+use crate::{
+    account::{delta::AccountUpdateDetails, AccountId},
+    batch::ProvenBatch,
+    crypto::merkle::Mmr,
+    errors::BlockBuilderError,
+    note::{NoteId, NoteInclusionProof, Nullifier},
+    transaction::{OutputNote, TransactionId},
+    Digest,
+};
+
+// BLOCK BUILDER
+// ================================================================================================
+
+/// Builds blocks on top of the state of the chain defined by the latest block.
+///
+/// The state of the chain consists of:
+/// - The header of the latest block.
+/// - The account tree, which maps account IDs to the commitments to their current states.
+/// - The nullifier tree, which maps the nullifiers of consumed notes to the number of the block in
+///   which they were consumed.
+/// - The chain MMR, which contains the hashes of all blocks of the chain, including the latest one.
+///
+/// Building a block from a set of [ProvenBatch]es validates the batches against this state and,
+/// if the block is valid, advances the state to the new block. If the block is invalid, the state
+/// is left unchanged.
+#[derive(Debug, Clone)]
+pub struct BlockBuilder {
+    prev_header: BlockHeader,
+    account_tree: AccountTree,
+    nullifier_tree: NullifierTree,
+    chain_mmr: Mmr,
+}
+
+impl BlockBuilder {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [BlockBuilder] instantiated from the state of the chain as of the provided
+    /// block header.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The root of the account tree or of the nullifier tree does not match the corresponding
+    ///   root of the block header.
+    /// - The chain MMR does not contain exactly the blocks up to and including the provided block,
+    ///   or the MMR without the provided block does not match the chain root of the block header.
+    pub fn new(
+        prev_header: BlockHeader,
+        account_tree: AccountTree,
+        nullifier_tree: NullifierTree,
+        chain_mmr: Mmr,
+    ) -> Result<Self, BlockBuilderError> {
+        if account_tree.root() != prev_header.account_root() {
+            return Err(BlockBuilderError::AccountRootMismatch {
+                expected: prev_header.account_root(),
+                actual: account_tree.root(),
+            });
+        }
+
+        if nullifier_tree.root() != prev_header.nullifier_root() {
+            return Err(BlockBuilderError::NullifierRootMismatch {
+                expected: prev_header.nullifier_root(),
+                actual: nullifier_tree.root(),
+            });
+        }
+
+        if chain_mmr.forest() != prev_header.block_num().as_usize() + 1 {
+            return Err(BlockBuilderError::ChainLengthMismatch {
+                prev_block_num: prev_header.block_num(),
+                actual: chain_mmr.forest(),
+            });
+        }
+
+        // the chain root of a block commits to all blocks preceding it
+        let chain_root = chain_mmr
+            .peaks_at(chain_mmr.forest() - 1)
+            .expect("forest of the previous block should not exceed the forest of the chain MMR")
+            .hash_peaks();
+        if chain_root != prev_header.chain_root() {
+            return Err(BlockBuilderError::ChainRootMismatch {
+                expected: prev_header.chain_root(),
+                actual: chain_root,
+            });
+        }
+
+        Ok(Self {
+            prev_header,
+            account_tree,
+            nullifier_tree,
+            chain_mmr,
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the header of the latest block of the chain.
+    pub fn prev_header(&self) -> &BlockHeader {
+        &self.prev_header
+    }
+
+    /// Returns the account tree as of the latest block of the chain.
+    pub fn account_tree(&self) -> &AccountTree {
+        &self.account_tree
+    }
+
+    /// Returns the nullifier tree as of the latest block of the chain.
+    pub fn nullifier_tree(&self) -> &NullifierTree {
+        &self.nullifier_tree
+    }
+
+    /// Returns the chain MMR, which contains the hashes of all blocks up to and including the
+    /// latest block of the chain.
+    pub fn chain_mmr(&self) -> &Mmr {
+        &self.chain_mmr
+    }
+
+    /// Consumes this builder and returns the header of the latest block, the account tree, the
+    /// nullifier tree and the chain MMR.
+    pub fn into_parts(self) -> (BlockHeader, AccountTree, NullifierTree, Mmr) {
+        (self.prev_header, self.account_tree, self.nullifier_tree, self.chain_mmr)
+    }
+
+    // BLOCK BUILDING
+    // --------------------------------------------------------------------------------------------
+
+    /// Builds the next block of the chain from the provided batches and advances the state of the
+    /// chain to the new block.
+    ///
+    /// The built block is not proven, i.e. the proof hash of its header is set to
+    /// [Digest::default()]. See [BlockBuilder::propose_block()] for details on how the block is
+    /// built.
+    ///
+    /// # Errors
+    /// Returns an error if the block cannot be built, see [BlockBuilder::propose_block()].
+    pub fn build_block(
+        &mut self,
+        batches: Vec<ProvenBatch>,
+        timestamp: u32,
+        note_proofs: &BTreeMap<NoteId, (BlockHeader, NoteInclusionProof)>,
+    ) -> Result<Block, BlockBuilderError> {
+        let proposed_block = self.propose_block(batches, timestamp, note_proofs)?;
+        self.apply_block(proposed_block, Digest::default())
+    }
+
+    /// Builds the next block of the chain from the provided batches without advancing the state of
+    /// the chain.
+    ///
+    /// The batches are applied in the provided order, i.e. a batch updating an account must
+    /// start from the state of the account left by the previous batches of the block. Notes which
+    /// are created by a batch and consumed as unauthenticated notes by another batch of the block
+    /// are erased from the block.
+    ///
+    /// All other unauthenticated notes must have been created in earlier blocks of the chain, and
+    /// are authenticated via `note_proofs`, which maps the IDs of these notes to the header of the
+    /// block in which each note was created and the proof of inclusion of the note in the note
+    /// tree of that block. The headers are authenticated against the chain MMR. Authenticated notes
+    /// are then consumed like any other note, i.e. their nullifiers are added to the block.
+    ///
+    /// The returned block can be proven and then added to the chain via
+    /// [BlockBuilder::apply_block()].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The timestamp is not greater than the timestamp of the latest block.
+    /// - A batch appears more than once, or a batch expired before the new block.
+    /// - A batch updates an account from a state other than its current state, or the updates of
+    ///   an account cannot be merged.
+    /// - A note is consumed more than once, or was already consumed in an earlier block.
+    /// - An unauthenticated note is neither created in the block nor has an inclusion proof, or its
+    ///   header does not match the header of the created note.
+    /// - The block header provided with an inclusion proof is not a block of the chain, or the
+    ///   inclusion proof is invalid.
+    /// - The resulting block is invalid, e.g. it exceeds the limits of a block.
+    pub fn propose_block(
+        &self,
+        batches: Vec<ProvenBatch>,
+        timestamp: u32,
+        note_proofs: &BTreeMap<NoteId, (BlockHeader, NoteInclusionProof)>,
+    ) -> Result<ProposedBlock, BlockBuilderError> {
+        let block_num = self.prev_header.block_num().child();
+
+        if timestamp <= self.prev_header.timestamp() {
+            return Err(BlockBuilderError::TimestampNotIncreasing {
+                previous: self.prev_header.timestamp(),
+                timestamp,
+            });
+        }
+
+        let mut batch_ids = BTreeSet::new();
+        for batch in batches.iter() {
+            if !batch_ids.insert(batch.id()) {
+                return Err(BlockBuilderError::DuplicateBatch(batch.id()));
+            }
+            if batch.batch_expiration_block_num() < block_num {
+                return Err(BlockBuilderError::ExpiredBatch {
+                    batch_id: batch.id(),
+                    expiration_block_num: batch.batch_expiration_block_num(),
+                    block_num,
+                });
+            }
+        }
+
+        let updated_accounts = self.compute_account_updates(&batches)?;
+        let (nullifiers, output_note_batches) = self.compute_notes(&batches, note_proofs)?;
+
+        // compute the new state of the chain
+        // ----------------------------------------------------------------------------------------
+
+        let account_mutations = self
+            .account_tree
+            .compute_mutations(
+                updated_accounts
+                    .iter()
+                    .map(|update| (update.account_id(), update.new_state_hash())),
+            )
+            .map_err(BlockBuilderError::AccountTreeUpdateFailed)?;
+        let nullifier_mutations = self
+            .nullifier_tree
+            .compute_mutations(nullifiers.iter().copied(), block_num)
+            .expect("nullifiers of the block should be unique and unspent");
+
+        let mut note_tree_entries = Vec::new();
+        for (batch_idx, notes) in output_note_batches.iter().enumerate() {
+            for (note_idx_in_batch, note) in notes.iter().enumerate() {
+                let index = BlockNoteIndex::new(batch_idx, note_idx_in_batch)
+                    .map_err(BlockBuilderError::InvalidBlock)?;
+                note_tree_entries.push((index, note.id(), *note.metadata()));
+            }
+        }
+        let note_tree = BlockNoteTree::with_entries(note_tree_entries)
+            .expect("note indices should be unique and within the capacity of the note tree");
+
+        let tx_hash = compute_tx_hash(updated_accounts.iter().flat_map(|update| {
+            update
+                .transactions()
+                .iter()
+                .map(|transaction_id| (*transaction_id, update.account_id()))
+        }));
+
+        let header = BlockHeader::new(
+            self.prev_header.version(),
+            self.prev_header.hash(),
+            block_num,
+            self.chain_mmr.peaks().hash_peaks(),
+            account_mutations.root(),
+            nullifier_mutations.root(),
+            note_tree.root(),
+            tx_hash,
+            self.prev_header.kernel_root(),
+            // the proof hash is set once the block is applied
+            Digest::default(),
+            timestamp,
+        );
+
+        let block = Block::new(header, updated_accounts, output_note_batches, nullifiers)
+            .map_err(BlockBuilderError::InvalidBlock)?;
+
+        Ok(ProposedBlock::new(block, account_mutations, nullifier_mutations))
+    }
+
+    /// Adds the provided proposed block to the chain, setting the proof hash of its header to the
+    /// provided hash, and returns the resulting block.
+    ///
+    /// # Errors
+    /// Returns an error if the proposed block does not build on the latest block of the chain.
+    pub fn apply_block(
+        &mut self,
+        proposed_block: ProposedBlock,
+        proof_hash: Digest,
+    ) -> Result<Block, BlockBuilderError> {
+        let (mut block, account_mutations, nullifier_mutations) = proposed_block.into_parts();
+        let header = block.header;
+        if header.prev_hash() != self.prev_header.hash() {
+            return Err(BlockBuilderError::ProposedBlockPrevHashMismatch {
+                expected: self.prev_header.hash(),
+                actual: header.prev_hash(),
+            });
+        }
+
+        block.header = BlockHeader::new(
+            header.version(),
+            header.prev_hash(),
+            header.block_num(),
+            header.chain_root(),
+            header.account_root(),
+            header.nullifier_root(),
+            header.note_root(),
+            header.tx_hash(),
+            header.kernel_root(),
+            proof_hash,
+            header.timestamp(),
+        );
+
+        // advance the state of the chain to the new block
+        // ----------------------------------------------------------------------------------------
+
+        self.account_tree
+            .apply_mutations(account_mutations)
+            .expect("account mutations should be computed against the current account tree");
+        self.nullifier_tree
+            .apply_mutations(nullifier_mutations)
+            .expect("nullifier mutations should be computed against the current nullifier tree");
+        self.chain_mmr.add(block.header.hash());
+        self.prev_header = block.header;
+
+        Ok(block)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Merges the account updates of the provided batches into a single update per account,
+    /// checking that each update starts from the current state of the account.
+    fn compute_account_updates(
+        &self,
+        batches: &[ProvenBatch],
+    ) -> Result<Vec<BlockAccountUpdate>, BlockBuilderError> {
+        let mut updates =
+            BTreeMap::<AccountId, (Digest, AccountUpdateDetails, Vec<TransactionId>)>::new();
+
+        for batch in batches.iter() {
+            for (&account_id, update) in batch.account_updates() {
+                let current_state = match updates.get(&account_id) {
+                    Some((state, ..)) => *state,
+                    None => self.account_tree.get(account_id).unwrap_or_default(),
+                };
+                if update.initial_state_commitment() != current_state {
+                    return Err(BlockBuilderError::StaleAccountState {
+                        account_id,
+                        batch_id: batch.id(),
+                        current_state,
+                        batch_initial_state: update.initial_state_commitment(),
+                    });
+                }
+
+                match updates.entry(account_id) {
+                    Entry::Vacant(entry) => {
+                        entry.insert((
+                            update.final_state_commitment(),
+                            update.details().clone(),
+                            update.transactions().to_vec(),
+                        ));
+                    },
+                    Entry::Occupied(mut entry) => {
+                        let (state, details, transactions) = entry.get_mut();
+                        let merged = core::mem::replace(details, AccountUpdateDetails::Private)
+                            .merge(update.details().clone())
+                            .map_err(|source| BlockBuilderError::AccountUpdateMergeFailed {
+                                account_id,
+                                source,
+                            })?;
+                        *details = merged;
+                        *state = update.final_state_commitment();
+                        transactions.extend_from_slice(update.transactions());
+                    },
+                }
+            }
+        }
+
+        Ok(updates
+            .into_iter()
+            .map(|(account_id, (state, details, transactions))| {
+                BlockAccountUpdate::new(account_id, state, details, transactions)
+            })
+            .collect())
+    }
+
+    /// Returns the nullifiers of the notes consumed in the block and the notes created in the
+    /// block, grouped by batch, after erasing the notes which are both created and consumed in the
+    /// block.
+    ///
+    /// Unauthenticated notes which are not created in the block are authenticated via the provided
+    /// inclusion proofs.
+    fn compute_notes(
+        &self,
+        batches: &[ProvenBatch],
+        note_proofs: &BTreeMap<NoteId, (BlockHeader, NoteInclusionProof)>,
+    ) -> Result<(Vec<Nullifier>, Vec<NoteBatch>), BlockBuilderError> {
+        let created_notes: BTreeMap<NoteId, &OutputNote> = batches
+            .iter()
+            .flat_map(|batch| batch.output_notes().iter())
+            .map(|note| (note.id(), note))
+            .collect();
+
+        let mut consumed_nullifiers = BTreeSet::new();
+        let mut erased_notes = BTreeSet::new();
+        let mut nullifiers = Vec::new();
+        for note in batches.iter().flat_map(|batch| batch.input_notes().iter()) {
+            let nullifier = note.nullifier();
+            if !consumed_nullifiers.insert(nullifier) {
+                return Err(BlockBuilderError::DuplicateNullifier(nullifier));
+            }
+
+            if let Some(header) = note.header() {
+                match created_notes.get(&header.id()) {
+                    Some(output_note) => {
+                        if output_note.hash() != header.hash() {
+                            return Err(BlockBuilderError::NoteHashesMismatch {
+                                id: header.id(),
+                                input_hash: header.hash(),
+                                output_hash: output_note.hash(),
+                            });
+                        }
+                        erased_notes.insert(header.id());
+                        continue;
+                    },
+                    // notes created in earlier blocks are consumed as authenticated notes
+                    None => self.authenticate_note(header.id(), header.hash(), note_proofs)?,
+                }
+            }
+
+            if let Some(block_num) = self.nullifier_tree.get_block_num(&nullifier) {
+                return Err(BlockBuilderError::NullifierAlreadySpent { nullifier, block_num });
+            }
+            nullifiers.push(nullifier);
+        }
+
+        let output_note_batches = batches
+            .iter()
+            .map(|batch| {
+                batch
+                    .output_notes()
+                    .iter()
+                    .filter(|note| !erased_notes.contains(&note.id()))
+                    .cloned()
+                    .collect()
+            })
+            .collect();
+
+        Ok((nullifiers, output_note_batches))
+    }
+
+    /// Checks that the note with the specified ID and hash was created in a block of the chain,
+    /// using the inclusion proof provided for it.
+    fn authenticate_note(
+        &self,
+        note_id: NoteId,
+        note_hash: Digest,
+        note_proofs: &BTreeMap<NoteId, (BlockHeader, NoteInclusionProof)>,
+    ) -> Result<(), BlockBuilderError> {
+        let (note_block, proof) = note_proofs
+            .get(&note_id)
+            .ok_or(BlockBuilderError::UnauthenticatedNoteNotFound(note_id))?;
+
+        // the chain MMR contains the hashes of all blocks up to and including the latest block
+        let block_num = proof.location().block_num();
+        let block_hash = self.chain_mmr.get(block_num.as_usize()).ok();
+        if note_block.block_num() != block_num || block_hash != Some(note_block.hash()) {
+            return Err(BlockBuilderError::NoteBlockNotFound { note_id, block_num });
+        }
+
+        proof
+            .note_path()
+            .verify(
+                proof.location().node_index_in_block().into(),
+                note_hash,
+                &note_block.note_root(),
+            )
+            .map_err(|_| BlockBuilderError::InvalidNoteInclusionProof(note_id))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::{collections::BTreeMap, vec::Vec};
+
+    use assert_matches::assert_matches;
+
+    use super::BlockBuilder;
+    use crate::{
+        account::AccountId,
+        batch::test_utils::{mock_note, mock_proven_batch, mock_proven_tx},
+        block::{AccountTree, BlockHeader, BlockNumber, NullifierTree},
+        crypto::merkle::Mmr,
+        note::{NoteInclusionProof, Nullifier},
+        testing::account_id::{
+            ACCOUNT_ID_OFF_CHAIN_SENDER, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+        },
+        transaction::{InputNote, InputNoteCommitment, OutputNote},
+        BlockBuilderError, Digest,
+    };
+
+    const GENESIS_TIMESTAMP: u32 = 1_700_000_000;
+
+    fn genesis_builder() -> BlockBuilder {
+        let account_tree = AccountTree::new();
+        let nullifier_tree = NullifierTree::new();
+        let mut chain_mmr = Mmr::new();
+
+        let genesis = BlockHeader::new(
+            0,
+            Digest::default(),
+            BlockNumber::GENESIS,
+            chain_mmr.peaks().hash_peaks(),
+            account_tree.root(),
+            nullifier_tree.root(),
+            Digest::default(),
+            Digest::default(),
+            Digest::default(),
+            Digest::default(),
+            GENESIS_TIMESTAMP,
+        );
+        chain_mmr.add(genesis.hash());
+
+        BlockBuilder::new(genesis, account_tree, nullifier_tree, chain_mmr).unwrap()
+    }
+
+    fn account_id() -> AccountId {
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap()
+    }
+
+    #[test]
+    fn build_block_from_batches() {
+        let mut builder = genesis_builder();
+        let genesis = *builder.prev_header();
+        let genesis_chain_root = builder.chain_mmr().peaks().hash_peaks();
+
+        let sender = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+        let erased_note = mock_note(1);
+        let output_note = mock_note(2);
+        let spent_nullifier = mock_note(3).nullifier();
+
+        let batch1 = mock_proven_batch(vec![mock_proven_tx(
+            account_id(),
+            0,
+            1,
+            vec![InputNoteCommitment::from(spent_nullifier)],
+            vec![OutputNote::Full(erased_note.clone()), OutputNote::Full(output_note.clone())],
+            10,
+        )]);
+        let batch2 = mock_proven_batch(vec![
+            mock_proven_tx(sender, 0, 5, Vec::new(), Vec::new(), 10),
+            mock_proven_tx(
+                account_id(),
+                1,
+                2,
+                vec![InputNoteCommitment::from(InputNote::unauthenticated(erased_note))],
+                Vec::new(),
+                10,
+            ),
+        ]);
+
+        let block = builder
+            .build_block(vec![batch1, batch2], GENESIS_TIMESTAMP + 1, &BTreeMap::new())
+            .unwrap();
+        let header = block.header();
+
+        // the updates of the account in both batches are merged
+        assert_eq!(block.updated_accounts().len(), 2);
+        let update = block
+            .updated_accounts()
+            .iter()
+            .find(|update| update.account_id() == account_id())
+            .unwrap();
+        assert_eq!(update.new_state_hash(), Digest::from([2u32, 0, 0, 0]));
+        assert_eq!(update.transactions().len(), 2);
+
+        // the note created and consumed in the block is erased
+        assert_eq!(block.nullifiers(), &[spent_nullifier]);
+        assert_eq!(block.output_note_batches(), &[vec![OutputNote::Full(output_note)], Vec::new()]);
+
+        // the header commits to the new state of the chain
+        assert_eq!(header.block_num(), BlockNumber::from(1));
+        assert_eq!(header.prev_hash(), genesis.hash());
+        assert_eq!(header.chain_root(), genesis_chain_root);
+        assert_eq!(header.account_root(), builder.account_tree().root());
+        assert_eq!(header.nullifier_root(), builder.nullifier_tree().root());
+        assert_eq!(header.note_root(), block.build_note_tree().root());
+        assert_eq!(header.tx_hash(), block.compute_tx_hash());
+        assert_eq!(builder.account_tree().get(account_id()), Some(Digest::from([2u32, 0, 0, 0])));
+        assert_eq!(
+            builder.nullifier_tree().get_block_num(&spent_nullifier),
+            Some(BlockNumber::from(1))
+        );
+        assert_eq!(builder.chain_mmr().forest(), 2);
+        assert_eq!(builder.prev_header(), &header);
+
+        // the state of the chain can be used to build the next block
+        let (prev_header, account_tree, nullifier_tree, chain_mmr) = builder.into_parts();
+        BlockBuilder::new(prev_header, account_tree, nullifier_tree, chain_mmr).unwrap();
+    }
+
+    #[test]
+    fn invalid_blocks_leave_state_unchanged() {
+        let mut builder = genesis_builder();
+        let nullifier = mock_note(1).nullifier();
+        let spend = |initial_state, final_state, nullifier: Nullifier| {
+            mock_proven_batch(vec![mock_proven_tx(
+                account_id(),
+                initial_state,
+                final_state,
+                vec![InputNoteCommitment::from(nullifier)],
+                Vec::new(),
+                10,
+            )])
+        };
+
+        builder
+            .build_block(vec![spend(0, 1, nullifier)], GENESIS_TIMESTAMP + 1, &BTreeMap::new())
+            .unwrap();
+        let header = *builder.prev_header();
+
+        // the note was already consumed in block 1
+        let err = builder
+            .build_block(vec![spend(1, 2, nullifier)], GENESIS_TIMESTAMP + 2, &BTreeMap::new())
+            .unwrap_err();
+        assert_matches!(err, BlockBuilderError::NullifierAlreadySpent { nullifier: n, block_num }
+            if n == nullifier && block_num == BlockNumber::from(1));
+
+        // the account is not in state 2
+        let err = builder
+            .build_block(
+                vec![spend(2, 3, mock_note(2).nullifier())],
+                GENESIS_TIMESTAMP + 2,
+                &BTreeMap::new(),
+            )
+            .unwrap_err();
+        assert_matches!(err, BlockBuilderError::StaleAccountState { current_state, batch_initial_state, .. }
+            if current_state == Digest::from([1u32, 0, 0, 0])
+                && batch_initial_state == Digest::from([2u32, 0, 0, 0]));
+
+        // the batch expired at block 10
+        let mut expired_builder = builder.clone();
+        for i in 0..9 {
+            expired_builder
+                .build_block(Vec::new(), GENESIS_TIMESTAMP + 2 + i, &BTreeMap::new())
+                .unwrap();
+        }
+        let err = expired_builder
+            .build_block(
+                vec![spend(1, 2, mock_note(2).nullifier())],
+                GENESIS_TIMESTAMP + 20,
+                &BTreeMap::new(),
+            )
+            .unwrap_err();
+        assert_matches!(err, BlockBuilderError::ExpiredBatch { block_num, .. }
+            if block_num == BlockNumber::from(11));
+
+        assert_eq!(builder.prev_header(), &header);
+        builder
+            .build_block(
+                vec![spend(1, 2, mock_note(2).nullifier())],
+                GENESIS_TIMESTAMP + 2,
+                &BTreeMap::new(),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn unauthenticated_notes_from_earlier_blocks() {
+        let mut builder = genesis_builder();
+        let note = mock_note(1);
+        let create = mock_proven_batch(vec![mock_proven_tx(
+            account_id(),
+            0,
+            1,
+            vec![InputNoteCommitment::from(mock_note(2).nullifier())],
+            vec![OutputNote::Full(note.clone())],
+            10,
+        )]);
+        let note_block = builder
+            .build_block(vec![create], GENESIS_TIMESTAMP + 1, &BTreeMap::new())
+            .unwrap();
+
+        let (note_index, _) = note_block.notes().next().unwrap();
+        let proof = NoteInclusionProof::new(
+            note_block.header().block_num(),
+            note_index.leaf_index_value(),
+            note_block.build_note_tree().get_note_path(note_index),
+        )
+        .unwrap();
+        let consume = |initial_state, final_state| {
+            mock_proven_batch(vec![mock_proven_tx(
+                account_id(),
+                initial_state,
+                final_state,
+                vec![InputNoteCommitment::from(InputNote::unauthenticated(note.clone()))],
+                Vec::new(),
+                10,
+            )])
+        };
+
+        // the note cannot be authenticated without an inclusion proof
+        assert_matches!(
+            builder.propose_block(vec![consume(1, 2)], GENESIS_TIMESTAMP + 2, &BTreeMap::new()),
+            Err(BlockBuilderError::UnauthenticatedNoteNotFound(note_id)) if note_id == note.id()
+        );
+
+        // the block of the proof must be a block of the chain
+        let other_block = BlockHeader::new(
+            0,
+            Digest::default(),
+            note_block.header().block_num(),
+            Digest::default(),
+            Digest::default(),
+            Digest::default(),
+            note_block.header().note_root(),
+            Digest::default(),
+            Digest::default(),
+            Digest::default(),
+            GENESIS_TIMESTAMP + 1,
+        );
+        let note_proofs = BTreeMap::from([(note.id(), (other_block, proof.clone()))]);
+        assert_matches!(
+            builder.propose_block(vec![consume(1, 2)], GENESIS_TIMESTAMP + 2, &note_proofs),
+            Err(BlockBuilderError::NoteBlockNotFound { note_id, block_num })
+                if note_id == note.id() && block_num == BlockNumber::from(1)
+        );
+
+        // the proof must open the note in the note tree of the block
+        let invalid_proof = NoteInclusionProof::new(
+            note_block.header().block_num(),
+            note_index.leaf_index_value() + 1,
+            proof.note_path().clone(),
+        )
+        .unwrap();
+        let note_proofs = BTreeMap::from([(note.id(), (note_block.header(), invalid_proof))]);
+        assert_matches!(
+            builder.propose_block(vec![consume(1, 2)], GENESIS_TIMESTAMP + 2, &note_proofs),
+            Err(BlockBuilderError::InvalidNoteInclusionProof(note_id)) if note_id == note.id()
+        );
+
+        // the authenticated note is consumed like an authenticated note
+        let note_proofs = BTreeMap::from([(note.id(), (note_block.header(), proof))]);
+        let block = builder
+            .build_block(vec![consume(1, 2)], GENESIS_TIMESTAMP + 2, &note_proofs)
+            .unwrap();
+        assert_eq!(block.nullifiers(), &[note.nullifier()]);
+        assert_eq!(
+            builder.nullifier_tree().get_block_num(&note.nullifier()),
+            Some(BlockNumber::from(2))
+        );
+
+        // and cannot be consumed again
+        let err = builder
+            .build_block(vec![consume(2, 3)], GENESIS_TIMESTAMP + 3, &note_proofs)
+            .unwrap_err();
+        assert_matches!(err, BlockBuilderError::NullifierAlreadySpent { nullifier, block_num }
+            if nullifier == note.nullifier() && block_num == BlockNumber::from(2));
+    }
+
+    #[test]
+    fn proposed_block_is_applied_with_proof_hash() {
+        let mut builder = genesis_builder();
+        let genesis = *builder.prev_header();
+        let batch = mock_proven_batch(vec![mock_proven_tx(
+            account_id(),
+            0,
+            1,
+            vec![InputNoteCommitment::from(mock_note(1).nullifier())],
+            Vec::new(),
+            10,
+        )]);
+
+        // proposing a block does not change the state of the chain
+        let proposed = builder
+            .propose_block(vec![batch], GENESIS_TIMESTAMP + 1, &BTreeMap::new())
+            .unwrap();
+        assert_eq!(proposed.header().proof_hash(), Digest::default());
+        assert_eq!(builder.prev_header(), &genesis);
+        assert_eq!(builder.chain_mmr().forest(), 1);
+
+        let proof_hash = Digest::from([7u32, 0, 0, 0]);
+        let block = builder.apply_block(proposed.clone(), proof_hash).unwrap();
+        assert_eq!(block.header().proof_hash(), proof_hash);
+        assert_eq!(block.header().account_root(), proposed.header().account_root());
+        assert_eq!(builder.prev_header(), &block.header());
+        assert_eq!(builder.account_tree().root(), block.header().account_root());
+
+        // the proposed block no longer builds on the latest block
+        assert_matches!(
+            builder.apply_block(proposed, proof_hash),
+            Err(BlockBuilderError::ProposedBlockPrevHashMismatch { expected, actual })
+                if expected == block.hash() && actual == genesis.hash()
+        );
+    }
+
+    #[test]
+    fn chain_state_must_match_previous_header() {
+        let (genesis, account_tree, nullifier_tree, chain_mmr) = genesis_builder().into_parts();
+
+        let mut other_nullifier_tree = nullifier_tree.clone();
+        other_nullifier_tree
+            .mark_spent(mock_note(1).nullifier(), BlockNumber::from(1))
+            .unwrap();
+        assert_matches!(
+            BlockBuilder::new(
+                genesis,
+                account_tree.clone(),
+                other_nullifier_tree,
+                chain_mmr.clone()
+            ),
+            Err(BlockBuilderError::NullifierRootMismatch { .. })
+        );
+
+        assert_matches!(
+            BlockBuilder::new(genesis, account_tree, nullifier_tree, Mmr::new()),
+            Err(BlockBuilderError::ChainLengthMismatch { actual: 0, .. })
+        );
+    }
+}
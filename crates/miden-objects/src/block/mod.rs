@@ -11,6 +11,14 @@ mod block_number;
 pub use block_number::BlockNumber;
 mod note_tree;
 pub use note_tree::{BlockNoteIndex, BlockNoteTree};
+mod builder;
+pub use builder::BlockBuilder;
+mod proposed_block;
+pub use proposed_block::ProposedBlock;
+mod nullifier_tree;
+pub use nullifier_tree::{NullifierTree, NullifierWitness};
+mod account_tree;
+pub use account_tree::{AccountMutationSet, AccountTree, AccountWitness};
 
 use crate::{
     account::{delta::AccountUpdateDetails, AccountId},
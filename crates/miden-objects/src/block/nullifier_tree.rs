@@ -0,0 +1,374 @@
+use alloc::collections::BTreeSet;
+
+use super::BlockNumber;
+use crate::{
+    crypto::merkle::{MerkleError, MutationSet, Smt, SmtProof, SMT_DEPTH},
+    errors::NullifierTreeError,
+    note::Nullifier,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    Digest, Felt, Word, EMPTY_WORD, ZERO,
+};
+
+// NULLIFIER TREE
+// ================================================================================================
+
+/// A sparse Merkle tree of the nullifiers of all notes consumed in the chain.
+///
+/// Each nullifier is mapped to the number of the block in which it was spent, encoded as
+/// `[block_num, 0, 0, 0]`. Nullifiers which were not spent are mapped to [EMPTY_WORD], which
+/// allows proving that a nullifier was not spent as of a given root of the tree.
+///
+/// Since nullifiers are spent only by blocks following the genesis block, a nullifier can never be
+/// spent in the genesis block.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NullifierTree {
+    smt: Smt,
+}
+
+impl NullifierTree {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The depth of the nullifier tree.
+    pub const DEPTH: u8 = SMT_DEPTH;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty [NullifierTree].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new [NullifierTree] in which the provided nullifiers are spent at the provided
+    /// block numbers.
+    ///
+    /// # Errors
+    /// Returns an error if a nullifier appears more than once, or is spent in the genesis block.
+    pub fn with_entries(
+        entries: impl IntoIterator<Item = (Nullifier, BlockNumber)>,
+    ) -> Result<Self, NullifierTreeError> {
+        let mut tree = Self::new();
+        for (nullifier, block_num) in entries {
+            tree.mark_spent(nullifier, block_num)?;
+        }
+
+        Ok(tree)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of the nullifier tree.
+    pub fn root(&self) -> Digest {
+        self.smt.root()
+    }
+
+    /// Returns the number of spent nullifiers in the tree.
+    pub fn num_nullifiers(&self) -> usize {
+        self.smt.entries().count()
+    }
+
+    /// Returns an iterator over the spent nullifiers and the numbers of the blocks in which they
+    /// were spent.
+    pub fn entries(&self) -> impl Iterator<Item = (Nullifier, BlockNumber)> + '_ {
+        self.smt.entries().map(|(nullifier, value)| {
+            (Nullifier::from(*nullifier), Self::leaf_value_to_block_num(*value))
+        })
+    }
+
+    /// Returns the number of the block in which the provided nullifier was spent, or `None` if the
+    /// nullifier was not spent.
+    pub fn get_block_num(&self, nullifier: &Nullifier) -> Option<BlockNumber> {
+        let value = self.smt.get_value(&nullifier.inner());
+        (value != EMPTY_WORD).then(|| Self::leaf_value_to_block_num(value))
+    }
+
+    /// Returns a witness which proves whether the provided nullifier was spent as of the current
+    /// root of the tree.
+    pub fn open(&self, nullifier: &Nullifier) -> NullifierWitness {
+        NullifierWitness::new(*nullifier, self.smt.open(&nullifier.inner()))
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Marks the provided nullifier as spent in the provided block.
+    ///
+    /// # Errors
+    /// Returns an error if the nullifier was already spent, or if the block is the genesis block.
+    pub fn mark_spent(
+        &mut self,
+        nullifier: Nullifier,
+        block_num: BlockNumber,
+    ) -> Result<(), NullifierTreeError> {
+        self.mark_spent_many([nullifier], block_num)
+    }
+
+    /// Marks all provided nullifiers as spent in the provided block.
+    ///
+    /// Either all nullifiers are marked as spent, or, if an error is returned, the tree is left
+    /// unchanged.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the nullifiers was already spent.
+    /// - A nullifier appears more than once in the provided nullifiers.
+    /// - Any nullifier is spent in the genesis block.
+    pub fn mark_spent_many(
+        &mut self,
+        nullifiers: impl IntoIterator<Item = Nullifier>,
+        block_num: BlockNumber,
+    ) -> Result<(), NullifierTreeError> {
+        let mutations = self.compute_mutations(nullifiers, block_num)?;
+        self.apply_mutations(mutations)
+            .expect("mutations should be computed against the current nullifier tree");
+
+        Ok(())
+    }
+
+    /// Computes the mutations which mark all provided nullifiers as spent in the provided block,
+    /// without changing the tree.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the nullifiers was already spent.
+    /// - A nullifier appears more than once in the provided nullifiers.
+    /// - Any nullifier is spent in the genesis block.
+    pub fn compute_mutations(
+        &self,
+        nullifiers: impl IntoIterator<Item = Nullifier>,
+        block_num: BlockNumber,
+    ) -> Result<MutationSet<SMT_DEPTH, Digest, Word>, NullifierTreeError> {
+        let mut new_nullifiers = BTreeSet::new();
+        for nullifier in nullifiers {
+            if block_num == BlockNumber::GENESIS {
+                return Err(NullifierTreeError::SpentInGenesisBlock);
+            }
+            if let Some(spent_in) = self.get_block_num(&nullifier) {
+                return Err(NullifierTreeError::NullifierAlreadySpent {
+                    nullifier,
+                    block_num: spent_in,
+                });
+            }
+            if !new_nullifiers.insert(nullifier) {
+                return Err(NullifierTreeError::DuplicateNullifier(nullifier));
+            }
+        }
+
+        let value = Self::block_num_to_leaf_value(block_num);
+        Ok(self.smt.compute_mutations(
+            new_nullifiers.into_iter().map(|nullifier| (nullifier.inner(), value)),
+        ))
+    }
+
+    /// Applies the provided mutations to the tree.
+    ///
+    /// # Errors
+    /// Returns an error if the mutations were computed against a different root of the tree.
+    pub fn apply_mutations(
+        &mut self,
+        mutations: MutationSet<SMT_DEPTH, Digest, Word>,
+    ) -> Result<(), MerkleError> {
+        self.smt.apply_mutations(mutations)
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the value under which a nullifier spent in the provided block is stored.
+    fn block_num_to_leaf_value(block_num: BlockNumber) -> Word {
+        [Felt::from(block_num), ZERO, ZERO, ZERO]
+    }
+
+    /// Returns the number of the block encoded in the provided value of a spent nullifier.
+    fn leaf_value_to_block_num(value: Word) -> BlockNumber {
+        BlockNumber::from(value[0].as_int() as u32)
+    }
+}
+
+// NULLIFIER WITNESS
+// ================================================================================================
+
+/// A proof that a nullifier was spent in a given block, or that it was not spent, as of a given
+/// root of the [NullifierTree].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NullifierWitness {
+    nullifier: Nullifier,
+    proof: SmtProof,
+}
+
+impl NullifierWitness {
+    /// Creates a new [NullifierWitness] from the provided nullifier and the opening of its leaf in
+    /// the nullifier tree.
+    fn new(nullifier: Nullifier, proof: SmtProof) -> Self {
+        Self { nullifier, proof }
+    }
+
+    /// Returns the nullifier this witness is for.
+    pub fn nullifier(&self) -> Nullifier {
+        self.nullifier
+    }
+
+    /// Returns the opening of the leaf of the nullifier in the nullifier tree.
+    pub fn proof(&self) -> &SmtProof {
+        &self.proof
+    }
+
+    /// Returns the number of the block in which the nullifier was spent according to this
+    /// witness, or `None` if the nullifier was not spent.
+    pub fn block_num(&self) -> Option<BlockNumber> {
+        let value = self
+            .proof
+            .get(&self.nullifier.inner())
+            .expect("proof should open the leaf of the nullifier");
+        (value != EMPTY_WORD).then(|| NullifierTree::leaf_value_to_block_num(value))
+    }
+
+    /// Returns the root of the nullifier tree this witness resolves to.
+    pub fn compute_root(&self) -> Digest {
+        self.proof.compute_root()
+    }
+
+    /// Returns `true` if this witness proves that the nullifier was spent in the provided block
+    /// as of the provided root of the nullifier tree.
+    pub fn verify_spent(&self, root: Digest, block_num: BlockNumber) -> bool {
+        block_num != BlockNumber::GENESIS
+            && self.proof.verify_membership(
+                &self.nullifier.inner(),
+                &NullifierTree::block_num_to_leaf_value(block_num),
+                &root,
+            )
+    }
+
+    /// Returns `true` if this witness proves that the nullifier was not spent as of the provided
+    /// root of the nullifier tree.
+    pub fn verify_unspent(&self, root: Digest) -> bool {
+        self.proof.verify_membership(&self.nullifier.inner(), &EMPTY_WORD, &root)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for NullifierWitness {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.nullifier.write_into(target);
+        self.proof.write_into(target);
+    }
+}
+
+impl Deserializable for NullifierWitness {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let nullifier = Nullifier::read_from(source)?;
+        let proof = SmtProof::read_from(source)?;
+        if proof.get(&nullifier.inner()).is_none() {
+            return Err(DeserializationError::InvalidValue(format!(
+                "proof does not open the leaf of nullifier {nullifier}"
+            )));
+        }
+
+        Ok(Self::new(nullifier, proof))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use assert_matches::assert_matches;
+
+    use super::NullifierTree;
+    use crate::{
+        block::{BlockNumber, NullifierWitness},
+        note::Nullifier,
+        utils::serde::{Deserializable, Serializable},
+        Digest, NullifierTreeError,
+    };
+
+    fn nullifier(seed: u32) -> Nullifier {
+        Nullifier::from(Digest::from([seed, 0, 0, seed]))
+    }
+
+    #[test]
+    fn spent_nullifiers_are_tagged_with_block_num() {
+        let tree = NullifierTree::with_entries([
+            (nullifier(1), BlockNumber::from(3)),
+            (nullifier(2), BlockNumber::from(5)),
+        ])
+        .unwrap();
+
+        assert_eq!(tree.num_nullifiers(), 2);
+        assert_eq!(tree.get_block_num(&nullifier(1)), Some(BlockNumber::from(3)));
+        assert_eq!(tree.get_block_num(&nullifier(2)), Some(BlockNumber::from(5)));
+        assert_eq!(tree.get_block_num(&nullifier(3)), None);
+
+        let mut entries: Vec<_> = tree.entries().collect();
+        entries.sort();
+        assert_eq!(
+            entries,
+            [(nullifier(1), BlockNumber::from(3)), (nullifier(2), BlockNumber::from(5))]
+        );
+    }
+
+    #[test]
+    fn witnesses_prove_inclusion_and_non_inclusion() {
+        let mut tree = NullifierTree::new();
+        tree.mark_spent(nullifier(1), BlockNumber::from(3)).unwrap();
+        let root = tree.root();
+
+        let spent = tree.open(&nullifier(1));
+        assert_eq!(spent.block_num(), Some(BlockNumber::from(3)));
+        assert_eq!(spent.compute_root(), root);
+        assert!(spent.verify_spent(root, BlockNumber::from(3)));
+        assert!(!spent.verify_spent(root, BlockNumber::from(4)));
+        assert!(!spent.verify_unspent(root));
+
+        let unspent = tree.open(&nullifier(2));
+        assert_eq!(unspent.block_num(), None);
+        assert!(unspent.verify_unspent(root));
+        assert!(!unspent.verify_spent(root, BlockNumber::from(3)));
+
+        // the witness of an unspent nullifier does not hold once the nullifier is spent
+        tree.mark_spent(nullifier(2), BlockNumber::from(4)).unwrap();
+        assert!(!unspent.verify_unspent(tree.root()));
+
+        let witness = NullifierWitness::read_from_bytes(&spent.to_bytes()).unwrap();
+        assert_eq!(witness, spent);
+    }
+
+    #[test]
+    fn batch_insertion_is_atomic() {
+        let mut tree = NullifierTree::new();
+        tree.mark_spent(nullifier(1), BlockNumber::from(1)).unwrap();
+        let root = tree.root();
+
+        assert_matches!(
+            tree.mark_spent_many([nullifier(2), nullifier(1)], BlockNumber::from(2)),
+            Err(NullifierTreeError::NullifierAlreadySpent { nullifier: n, block_num })
+                if n == nullifier(1) && block_num == BlockNumber::from(1)
+        );
+        assert_matches!(
+            tree.mark_spent_many([nullifier(2), nullifier(2)], BlockNumber::from(2)),
+            Err(NullifierTreeError::DuplicateNullifier(n)) if n == nullifier(2)
+        );
+        assert_matches!(
+            tree.mark_spent_many([nullifier(2)], BlockNumber::GENESIS),
+            Err(NullifierTreeError::SpentInGenesisBlock)
+        );
+        assert_eq!(tree.root(), root);
+        assert_eq!(tree.get_block_num(&nullifier(2)), None);
+
+        // spending no nullifiers is valid in any block
+        tree.mark_spent_many([], BlockNumber::GENESIS).unwrap();
+        assert_eq!(tree.root(), root);
+
+        tree.mark_spent_many([nullifier(2), nullifier(3)], BlockNumber::from(2))
+            .unwrap();
+        assert_eq!(tree.num_nullifiers(), 3);
+        assert_eq!(tree.get_block_num(&nullifier(3)), Some(BlockNumber::from(2)));
+    }
+}
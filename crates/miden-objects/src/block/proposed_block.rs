@@ -0,0 +1,65 @@
+use super::{AccountMutationSet, Block, BlockHeader};
+use crate::{
+    crypto::merkle::{MutationSet, SMT_DEPTH},
+    Digest, Word,
+};
+
+// PROPOSED BLOCK
+// ================================================================================================
+
+/// A block which was built on top of the state of the chain by a
+/// [BlockBuilder](super::BlockBuilder), but which has not been proven yet.
+///
+/// The header of a proposed block does not commit to a proof. A proposed block becomes part of the
+/// chain once it is applied to the builder which proposed it, together with the hash of its proof.
+#[derive(Debug, Clone)]
+pub struct ProposedBlock {
+    block: Block,
+    account_mutations: AccountMutationSet,
+    nullifier_mutations: MutationSet<SMT_DEPTH, Digest, Word>,
+}
+
+impl ProposedBlock {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Creates a new [ProposedBlock] from the block and the mutations of the account and nullifier
+    /// trees it results in.
+    pub(super) fn new(
+        block: Block,
+        account_mutations: AccountMutationSet,
+        nullifier_mutations: MutationSet<SMT_DEPTH, Digest, Word>,
+    ) -> Self {
+        Self {
+            block,
+            account_mutations,
+            nullifier_mutations,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the proposed block.
+    ///
+    /// The proof hash of the header of the returned block is set to [Digest::default()].
+    pub fn block(&self) -> &Block {
+        &self.block
+    }
+
+    /// Returns the header of the proposed block.
+    pub fn header(&self) -> BlockHeader {
+        self.block.header()
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Consumes this proposed block and returns the block and the mutations of the account and
+    /// nullifier trees.
+    pub(super) fn into_parts(
+        self,
+    ) -> (Block, AccountMutationSet, MutationSet<SMT_DEPTH, Digest, Word>) {
+        (self.block, self.account_mutations, self.nullifier_mutations)
+    }
+}
@@ -12,16 +12,19 @@ use super::{
     asset::{FungibleAsset, NonFungibleAsset},
     crypto::merkle::MerkleError,
     note::NoteId,
-    Digest, Word, MAX_ACCOUNTS_PER_BLOCK, MAX_BATCHES_PER_BLOCK, MAX_INPUT_NOTES_PER_BLOCK,
-    MAX_OUTPUT_NOTES_PER_BATCH, MAX_OUTPUT_NOTES_PER_BLOCK,
+    Digest, Word, MAX_ACCOUNTS_PER_BATCH, MAX_ACCOUNTS_PER_BLOCK, MAX_BATCHES_PER_BLOCK,
+    MAX_INPUT_NOTES_PER_BATCH, MAX_INPUT_NOTES_PER_BLOCK, MAX_OUTPUT_NOTES_PER_BATCH,
+    MAX_OUTPUT_NOTES_PER_BLOCK,
 };
 use crate::{
     account::{
         AccountCode, AccountIdPrefix, AccountStorage, AccountType, PlaceholderType,
         StoragePlaceholder,
     },
+    batch::BatchId,
     block::BlockNumber,
     note::{NoteAssets, NoteExecutionHint, NoteTag, NoteType, Nullifier},
+    transaction::TransactionId,
     ACCOUNT_UPDATE_MAX_SIZE, MAX_INPUTS_PER_NOTE, MAX_INPUT_NOTES_PER_TX, MAX_OUTPUT_NOTES_PER_TX,
 };
 
@@ -72,6 +75,8 @@ pub enum AccountError {
     AccountCodeProcedureStorageSizeTooLarge(Digest, Felt),
     #[error("account procedure {0}'s final two elements must be Felt::ZERO")]
     AccountCodeProcedureInvalidPadding(Digest),
+    #[error("code of account {0} cannot be updated because the account is not of type regular account with updatable code")]
+    AccountCodeNotUpdatable(AccountId),
     #[error("failed to assemble account component:\n{}", PrintDiagnostic::new(.0))]
     AccountComponentAssemblyError(Report),
     #[error("failed to merge components into one account code mast forest")]
@@ -88,6 +93,10 @@ pub enum AccountError {
     FungibleFaucetTooManyDecimals { actual: u8, max: u8 },
     #[error("faucet metadata max supply is {actual} which exceeds max value of {max}")]
     FungibleFaucetMaxSupplyTooLarge { actual: u64, max: u64 },
+    #[error("multisig threshold is {threshold} but it must be between 1 and the number of approvers {num_approvers}")]
+    MultisigInvalidThreshold { threshold: u32, num_approvers: usize },
+    #[error("multisig approver with public key {0} is defined multiple times")]
+    MultisigDuplicateApprover(Digest),
     #[error("account header data has length {actual} but it must be of length {expected}")]
     HeaderDataIncorrectLength { actual: usize, expected: usize },
     #[error("new account nonce {new} is less than the current nonce {current}")]
@@ -251,6 +260,8 @@ pub enum NoteError {
     DuplicateFungibleAsset(AccountId),
     #[error("duplicate non fungible asset {0} in note")]
     DuplicateNonFungibleAsset(NonFungibleAsset),
+    #[error("note is not an HTLC note")]
+    HtlcNoteInvalid,
     #[error("note type {0:?} is inconsistent with note tag {1}")]
     InconsistentNoteTag(NoteType, u64),
     #[error("adding fungible asset amounts would exceed maximum allowed amount")]
@@ -288,6 +299,12 @@ pub enum NoteError {
     NoteScriptAssemblyError(Report),
     #[error("failed to deserialize note script")]
     NoteScriptDeserializationError(#[source] DeserializationError),
+    #[error("note is not a partially fillable swap note")]
+    PartialSwapNoteInvalid,
+    #[error("fill amount {fill_amount} exceeds the requested amount {requested_amount} of the partially fillable swap note")]
+    PartialSwapFillAmountExceedsRequestedAmount { fill_amount: u64, requested_amount: u64 },
+    #[error("product of the offered amount {offered_amount} and the fill amount {fill_amount} of the partially fillable swap note does not fit into 64 bits")]
+    PartialSwapFillAmountOverflow { offered_amount: u64, fill_amount: u64 },
     #[error("public use case requires a public note but note is of type {0:?}")]
     PublicUseCaseRequiresPublicNote(NoteType),
     #[error("note contains {0} assets which exceeds the maximum of {max}", max = NoteAssets::MAX_NUM_ASSETS)]
@@ -353,6 +370,18 @@ pub enum TransactionInputError {
     DuplicateInputNote(Nullifier),
     #[error("ID {expected} of the new account does not match the ID {actual} computed from the provided seed")]
     InconsistentAccountSeed { expected: AccountId, actual: AccountId },
+    #[error("foreign account {account_id} has code commitment {actual} which does not match the commitment {expected} in its header")]
+    InconsistentForeignAccountCode {
+        account_id: AccountId,
+        expected: Digest,
+        actual: Digest,
+    },
+    #[error("foreign account {account_id} has storage commitment {actual} which does not match the commitment {expected} in its header")]
+    InconsistentForeignAccountStorage {
+        account_id: AccountId,
+        expected: Digest,
+        actual: Digest,
+    },
     #[error("chain mmr has length {actual} which does not match block number {expected} ")]
     InconsistentChainLength {
         expected: BlockNumber,
@@ -429,6 +458,59 @@ pub enum ProvenTransactionError {
     },
 }
 
+// PROPOSED BATCH ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum ProposedBatchError {
+    #[error("transaction batch must contain at least one transaction")]
+    EmptyTransactionBatch,
+    #[error("transaction with id {transaction_id} appears more than once in the batch")]
+    DuplicateTransaction { transaction_id: TransactionId },
+    #[error("too many accounts updated in the batch (max: {MAX_ACCOUNTS_PER_BATCH}, actual: {0})")]
+    TooManyAccountUpdates(usize),
+    #[error("too many input notes in the batch (max: {MAX_INPUT_NOTES_PER_BATCH}, actual: {0})")]
+    TooManyInputNotes(usize),
+    #[error("too many output notes in the batch (max: {MAX_OUTPUT_NOTES_PER_BATCH}, actual: {0})")]
+    TooManyOutputNotes(usize),
+    #[error("transaction {second_transaction_id} consumes the note with nullifier {note_nullifier} that is also consumed by another transaction {first_transaction_id} in the batch")]
+    DuplicateInputNote {
+        note_nullifier: Nullifier,
+        first_transaction_id: TransactionId,
+        second_transaction_id: TransactionId,
+    },
+    #[error("transaction {second_transaction_id} creates the note with id {note_id} that is also created by another transaction {first_transaction_id} in the batch")]
+    DuplicateOutputNote {
+        note_id: NoteId,
+        first_transaction_id: TransactionId,
+        second_transaction_id: TransactionId,
+    },
+    #[error("note hashes mismatch for note {id}: (input: {input_hash}, output: {output_hash})")]
+    NoteHashesMismatch {
+        id: NoteId,
+        input_hash: Digest,
+        output_hash: Digest,
+    },
+    #[error("transaction {transaction} was executed against account {actual_account_id} but the account update is for account {expected_account_id}")]
+    AccountUpdateIdMismatch {
+        transaction: TransactionId,
+        expected_account_id: AccountId,
+        actual_account_id: AccountId,
+    },
+    #[error("initial state commitment {actual} of transaction {transaction} does not match the current state commitment {expected} of account {account_id} in the batch")]
+    AccountUpdateInitialStateMismatch {
+        account_id: AccountId,
+        transaction: TransactionId,
+        expected: Digest,
+        actual: Digest,
+    },
+    #[error("failed to merge transaction updates of account {account_id}")]
+    AccountUpdateMergeFailed {
+        account_id: AccountId,
+        source: AccountDeltaError,
+    },
+}
+
 // BLOCK VALIDATION ERROR
 // ================================================================================================
 
@@ -449,3 +531,106 @@ pub enum BlockError {
     )]
     TooManyTransactionBatches(usize),
 }
+
+// ACCOUNT TREE ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum AccountTreeError {
+    #[error("account {0} appears more than once in the account updates")]
+    DuplicateAccountId(AccountId),
+    #[error("account {account_id} has the same ID prefix as account {existing_account_id}")]
+    DuplicateIdPrefix {
+        account_id: AccountId,
+        existing_account_id: AccountId,
+    },
+    #[error("failed to apply mutations to the account tree")]
+    ApplyMutationsFailed(#[source] MerkleError),
+}
+
+// NULLIFIER TREE ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum NullifierTreeError {
+    #[error("nullifier {nullifier} was already spent in block {block_num}")]
+    NullifierAlreadySpent {
+        nullifier: Nullifier,
+        block_num: BlockNumber,
+    },
+    #[error("nullifier {0} appears more than once in the nullifiers to spend")]
+    DuplicateNullifier(Nullifier),
+    #[error("nullifiers cannot be spent in the genesis block")]
+    SpentInGenesisBlock,
+}
+
+// BLOCK BUILDER ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum BlockBuilderError {
+    #[error("account tree root {actual} does not match the account root {expected} of the previous block")]
+    AccountRootMismatch { expected: Digest, actual: Digest },
+    #[error("nullifier tree root {actual} does not match the nullifier root {expected} of the previous block")]
+    NullifierRootMismatch { expected: Digest, actual: Digest },
+    #[error("chain MMR contains {actual} blocks but the previous block is block {prev_block_num}")]
+    ChainLengthMismatch {
+        prev_block_num: BlockNumber,
+        actual: usize,
+    },
+    #[error(
+        "chain MMR root {actual} does not match the chain root {expected} of the previous block"
+    )]
+    ChainRootMismatch { expected: Digest, actual: Digest },
+    #[error("block timestamp {timestamp} is not greater than the timestamp {previous} of the previous block")]
+    TimestampNotIncreasing { previous: u32, timestamp: u32 },
+    #[error("batch {0} appears more than once in the block")]
+    DuplicateBatch(BatchId),
+    #[error("batch {batch_id} expired at block {expiration_block_num} and cannot be included in block {block_num}")]
+    ExpiredBatch {
+        batch_id: BatchId,
+        expiration_block_num: BlockNumber,
+        block_num: BlockNumber,
+    },
+    #[error("batch {batch_id} updates account {account_id} from state {batch_initial_state} but the current state of the account is {current_state}")]
+    StaleAccountState {
+        account_id: AccountId,
+        batch_id: BatchId,
+        current_state: Digest,
+        batch_initial_state: Digest,
+    },
+    #[error("failed to merge updates of account {account_id} in the block")]
+    AccountUpdateMergeFailed {
+        account_id: AccountId,
+        source: AccountDeltaError,
+    },
+    #[error("failed to update the account tree with the account updates of the block")]
+    AccountTreeUpdateFailed(#[source] AccountTreeError),
+    #[error("note with nullifier {nullifier} was already consumed in block {block_num}")]
+    NullifierAlreadySpent {
+        nullifier: Nullifier,
+        block_num: BlockNumber,
+    },
+    #[error("note with nullifier {0} is consumed more than once in the block")]
+    DuplicateNullifier(Nullifier),
+    #[error("unauthenticated note {0} is neither created in the block nor has an inclusion proof")]
+    UnauthenticatedNoteNotFound(NoteId),
+    #[error(
+        "block {block_num} of the inclusion proof of note {note_id} is not a block of the chain"
+    )]
+    NoteBlockNotFound { note_id: NoteId, block_num: BlockNumber },
+    #[error("inclusion proof of unauthenticated note {0} is invalid")]
+    InvalidNoteInclusionProof(NoteId),
+    #[error("note hashes mismatch for note {id}: (input: {input_hash}, output: {output_hash})")]
+    NoteHashesMismatch {
+        id: NoteId,
+        input_hash: Digest,
+        output_hash: Digest,
+    },
+    #[error("built block is invalid")]
+    InvalidBlock(#[source] BlockError),
+    #[error(
+        "proposed block builds on block {actual} but the latest block of the chain is {expected}"
+    )]
+    ProposedBlockPrevHashMismatch { expected: Digest, actual: Digest },
+}
@@ -13,7 +13,7 @@ use crate::{
         },
         storage::FAUCET_STORAGE_DATA_SLOT,
     },
-    Felt, Word, ZERO,
+    Felt, ZERO,
 };
 
 // MOCK ACCOUNT
@@ -73,7 +73,7 @@ impl Account {
             true => vec![],
             false => {
                 let asset = NonFungibleAsset::mock(&constants::NON_FUNGIBLE_ASSET_DATA_2);
-                vec![(Word::from(asset).into(), asset.into())]
+                vec![(asset.vault_key().into(), asset.into())]
             },
         };
 
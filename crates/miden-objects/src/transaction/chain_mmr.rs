@@ -111,6 +111,12 @@ impl ChainMmr {
     // ITERATORS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns an iterator over the headers of the blocks for which this chain MMR contains
+    /// authentication paths.
+    pub fn block_headers(&self) -> impl Iterator<Item = &BlockHeader> {
+        self.blocks.values()
+    }
+
     /// Returns an iterator over the inner nodes of authentication paths contained in this chain
     /// MMR.
     pub fn inner_nodes(&self) -> impl Iterator<Item = InnerNodeInfo> + '_ {
@@ -3,8 +3,12 @@ use core::fmt::Debug;
 
 use super::{BlockHeader, ChainMmr, Digest, Felt, Hasher, Word};
 use crate::{
-    account::{Account, AccountId, AccountIdAnchor},
+    account::{
+        Account, AccountCode, AccountHeader, AccountId, AccountIdAnchor, AccountStorageHeader,
+        StorageMap, StorageSlot,
+    },
     block::BlockNumber,
+    crypto::merkle::{MerkleError, MerklePath},
     note::{Note, NoteId, NoteInclusionProof, NoteLocation, Nullifier},
     utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
     TransactionInputError, MAX_INPUT_NOTES_PER_TX,
@@ -152,6 +156,177 @@ impl Deserializable for TransactionInputs {
     }
 }
 
+// FOREIGN ACCOUNT INPUTS
+// ================================================================================================
+
+/// Contains the data required to load a foreign account into the transaction kernel during a
+/// foreign procedure invocation.
+///
+/// This includes the account header, the storage header and the storage maps of the account, its
+/// code, and the authentication path of the account's state hash in the account tree of the
+/// transaction's reference block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForeignAccountInputs {
+    account_header: AccountHeader,
+    storage_header: AccountStorageHeader,
+    account_code: AccountCode,
+    storage_maps: Vec<StorageMap>,
+    account_path: MerklePath,
+}
+
+impl ForeignAccountInputs {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns new [ForeignAccountInputs] instantiated with the specified parameters.
+    ///
+    /// `account_path` is expected to be the authentication path from the account tree root of the
+    /// transaction's reference block to the leaf containing the hash of the account.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The commitment of the provided account code does not match the code commitment in the
+    ///   account header.
+    /// - The commitment of the provided storage header does not match the storage commitment in
+    ///   the account header.
+    pub fn new(
+        account_header: AccountHeader,
+        storage_header: AccountStorageHeader,
+        account_code: AccountCode,
+        storage_maps: Vec<StorageMap>,
+        account_path: MerklePath,
+    ) -> Result<Self, TransactionInputError> {
+        if account_code.commitment() != account_header.code_commitment() {
+            return Err(TransactionInputError::InconsistentForeignAccountCode {
+                account_id: account_header.id(),
+                expected: account_header.code_commitment(),
+                actual: account_code.commitment(),
+            });
+        }
+
+        let storage_commitment = Hasher::hash_elements(&storage_header.as_elements());
+        if storage_commitment != account_header.storage_commitment() {
+            return Err(TransactionInputError::InconsistentForeignAccountStorage {
+                account_id: account_header.id(),
+                expected: account_header.storage_commitment(),
+                actual: storage_commitment,
+            });
+        }
+
+        Ok(Self {
+            account_header,
+            storage_header,
+            account_code,
+            storage_maps,
+            account_path,
+        })
+    }
+
+    /// Returns new [ForeignAccountInputs] instantiated from the provided account and the
+    /// authentication path of this account in the account tree.
+    pub fn from_account(account: &Account, account_path: MerklePath) -> Self {
+        let storage_maps = account
+            .storage()
+            .slots()
+            .iter()
+            .filter_map(|slot| match slot {
+                StorageSlot::Map(map) => Some(map.clone()),
+                StorageSlot::Value(_) => None,
+            })
+            .collect();
+
+        Self {
+            account_header: account.into(),
+            storage_header: account.storage().get_header(),
+            account_code: account.code().clone(),
+            storage_maps,
+            account_path,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the foreign account.
+    pub fn id(&self) -> AccountId {
+        self.account_header.id()
+    }
+
+    /// Returns the header of the foreign account.
+    pub fn account_header(&self) -> &AccountHeader {
+        &self.account_header
+    }
+
+    /// Returns the storage header of the foreign account.
+    pub fn storage_header(&self) -> &AccountStorageHeader {
+        &self.storage_header
+    }
+
+    /// Returns the code of the foreign account.
+    pub fn account_code(&self) -> &AccountCode {
+        &self.account_code
+    }
+
+    /// Returns the storage maps of the foreign account.
+    pub fn storage_maps(&self) -> &[StorageMap] {
+        &self.storage_maps
+    }
+
+    /// Returns the authentication path of the foreign account in the account tree.
+    pub fn account_path(&self) -> &MerklePath {
+        &self.account_path
+    }
+
+    /// Computes the account tree root implied by the account's state hash and its authentication
+    /// path.
+    ///
+    /// # Errors
+    /// Returns an error if the depth of the authentication path does not match the depth of the
+    /// account tree.
+    pub fn compute_account_root(&self) -> Result<Digest, MerkleError> {
+        self.account_path
+            .compute_root(self.id().prefix().as_u64(), self.account_header.hash())
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Consumes these foreign account inputs and returns their underlying components.
+    pub fn into_parts(
+        self,
+    ) -> (AccountHeader, AccountStorageHeader, AccountCode, Vec<StorageMap>, MerklePath) {
+        (
+            self.account_header,
+            self.storage_header,
+            self.account_code,
+            self.storage_maps,
+            self.account_path,
+        )
+    }
+}
+
+impl Serializable for ForeignAccountInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account_header.write_into(target);
+        self.storage_header.write_into(target);
+        self.account_code.write_into(target);
+        self.storage_maps.write_into(target);
+        self.account_path.write_into(target);
+    }
+}
+
+impl Deserializable for ForeignAccountInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account_header = AccountHeader::read_from(source)?;
+        let storage_header = AccountStorageHeader::read_from(source)?;
+        let account_code = AccountCode::read_from(source)?;
+        let storage_maps = Vec::<StorageMap>::read_from(source)?;
+        let account_path = MerklePath::read_from(source)?;
+        Self::new(account_header, storage_header, account_code, storage_maps, account_path)
+            .map_err(|err| DeserializationError::InvalidValue(format!("{}", err)))
+    }
+}
+
 // TO INPUT NOTE COMMITMENT
 // ================================================================================================
 
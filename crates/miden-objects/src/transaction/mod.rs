@@ -13,15 +13,19 @@ mod outputs;
 mod proven_tx;
 mod transaction_id;
 mod tx_args;
+mod tx_header;
 mod tx_witness;
 
 pub use chain_mmr::ChainMmr;
 pub use executed_tx::{ExecutedTransaction, TransactionMeasurements};
-pub use inputs::{InputNote, InputNotes, ToInputNoteCommitments, TransactionInputs};
+pub use inputs::{
+    ForeignAccountInputs, InputNote, InputNotes, ToInputNoteCommitments, TransactionInputs,
+};
 pub use outputs::{OutputNote, OutputNotes, TransactionOutputs};
 pub use proven_tx::{
     InputNoteCommitment, ProvenTransaction, ProvenTransactionBuilder, TxAccountUpdate,
 };
 pub use transaction_id::TransactionId;
 pub use tx_args::{TransactionArgs, TransactionScript};
+pub use tx_header::TransactionHeader;
 pub use tx_witness::TransactionWitness;
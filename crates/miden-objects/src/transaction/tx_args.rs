@@ -12,6 +12,7 @@ use vm_processor::{AdviceInputs, DeserializationError};
 
 use super::{Digest, Felt, Word};
 use crate::{
+    account::AccountCode,
     note::{NoteDetails, NoteId},
     TransactionScriptError,
 };
@@ -27,11 +28,15 @@ use crate::{
 ///   different from note inputs, as the user executing the transaction can specify arbitrary note
 ///   args.
 /// - Advice inputs: Provides data needed by the runtime, like the details of public output notes.
+/// - New account code: the code the native account may switch to via the `account::set_code`
+///   kernel procedure. The account code is not needed to execute the transaction, but it is needed
+///   to record the code update in the account delta.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct TransactionArgs {
     tx_script: Option<TransactionScript>,
     note_args: BTreeMap<NoteId, Word>,
     advice_inputs: AdviceInputs,
+    new_account_code: Option<AccountCode>,
 }
 
 impl TransactionArgs {
@@ -59,6 +64,7 @@ impl TransactionArgs {
             tx_script,
             note_args: note_args.unwrap_or_default(),
             advice_inputs,
+            new_account_code: None,
         }
     }
 
@@ -79,6 +85,17 @@ impl TransactionArgs {
         self
     }
 
+    /// Returns the provided [TransactionArgs] with the new code of the native account set to the
+    /// passed-in `account_code`.
+    ///
+    /// This must be provided if the transaction updates the code of the native account via the
+    /// `account::set_code` kernel procedure, and its commitment must match the commitment of the
+    /// code which the account is updated to.
+    pub fn with_new_account_code(mut self, account_code: AccountCode) -> Self {
+        self.new_account_code = Some(account_code);
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -97,6 +114,11 @@ impl TransactionArgs {
         &self.advice_inputs
     }
 
+    /// Returns a reference to the new code of the native account, if provided.
+    pub fn new_account_code(&self) -> Option<&AccountCode> {
+        self.new_account_code.as_ref()
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -157,6 +179,7 @@ impl Serializable for TransactionArgs {
         self.tx_script.write_into(target);
         self.note_args.write_into(target);
         self.advice_inputs.write_into(target);
+        self.new_account_code.write_into(target);
     }
 }
 
@@ -165,8 +188,14 @@ impl Deserializable for TransactionArgs {
         let tx_script = Option::<TransactionScript>::read_from(source)?;
         let note_args = BTreeMap::<NoteId, Word>::read_from(source)?;
         let advice_inputs = AdviceInputs::read_from(source)?;
+        let new_account_code = Option::<AccountCode>::read_from(source)?;
 
-        Ok(Self { tx_script, note_args, advice_inputs })
+        Ok(Self {
+            tx_script,
+            note_args,
+            advice_inputs,
+            new_account_code,
+        })
     }
 }
 
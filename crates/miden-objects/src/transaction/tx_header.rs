@@ -0,0 +1,128 @@
+use super::{AccountId, Digest, ProvenTransaction, TransactionId};
+use crate::utils::serde::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+};
+
+// TRANSACTION HEADER
+// ================================================================================================
+
+/// The public commitments of a proven transaction from which its [TransactionId] is computed.
+///
+/// A transaction header allows the effects of a transaction on the state of the chain to be
+/// authenticated against the ID of the transaction without access to the full transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransactionHeader {
+    id: TransactionId,
+    account_id: AccountId,
+    initial_state_commitment: Digest,
+    final_state_commitment: Digest,
+    input_notes_commitment: Digest,
+    output_notes_commitment: Digest,
+}
+
+impl TransactionHeader {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [TransactionHeader] instantiated from the provided commitments of a
+    /// transaction executed against the specified account.
+    pub fn new(
+        account_id: AccountId,
+        initial_state_commitment: Digest,
+        final_state_commitment: Digest,
+        input_notes_commitment: Digest,
+        output_notes_commitment: Digest,
+    ) -> Self {
+        let id = TransactionId::new(
+            initial_state_commitment,
+            final_state_commitment,
+            input_notes_commitment,
+            output_notes_commitment,
+        );
+
+        Self {
+            id,
+            account_id,
+            initial_state_commitment,
+            final_state_commitment,
+            input_notes_commitment,
+            output_notes_commitment,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the transaction.
+    pub fn id(&self) -> TransactionId {
+        self.id
+    }
+
+    /// Returns the ID of the account against which the transaction was executed.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns a commitment to the state of the account before the transaction was executed.
+    pub fn initial_state_commitment(&self) -> Digest {
+        self.initial_state_commitment
+    }
+
+    /// Returns a commitment to the state of the account after the transaction was executed.
+    pub fn final_state_commitment(&self) -> Digest {
+        self.final_state_commitment
+    }
+
+    /// Returns a commitment to the notes consumed by the transaction.
+    pub fn input_notes_commitment(&self) -> Digest {
+        self.input_notes_commitment
+    }
+
+    /// Returns a commitment to the notes created by the transaction.
+    pub fn output_notes_commitment(&self) -> Digest {
+        self.output_notes_commitment
+    }
+}
+
+impl From<&ProvenTransaction> for TransactionHeader {
+    fn from(tx: &ProvenTransaction) -> Self {
+        Self::new(
+            tx.account_id(),
+            tx.account_update().init_state_hash(),
+            tx.account_update().final_state_hash(),
+            tx.input_notes().commitment(),
+            tx.output_notes().commitment(),
+        )
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for TransactionHeader {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account_id.write_into(target);
+        self.initial_state_commitment.write_into(target);
+        self.final_state_commitment.write_into(target);
+        self.input_notes_commitment.write_into(target);
+        self.output_notes_commitment.write_into(target);
+    }
+}
+
+impl Deserializable for TransactionHeader {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account_id = AccountId::read_from(source)?;
+        let initial_state_commitment = Digest::read_from(source)?;
+        let final_state_commitment = Digest::read_from(source)?;
+        let input_notes_commitment = Digest::read_from(source)?;
+        let output_notes_commitment = Digest::read_from(source)?;
+
+        Ok(Self::new(
+            account_id,
+            initial_state_commitment,
+            final_state_commitment,
+            input_notes_commitment,
+            output_notes_commitment,
+        ))
+    }
+}
@@ -1,4 +1,15 @@
 mod tx_authenticator;
 pub use tx_authenticator::{BasicAuthenticator, TransactionAuthenticator};
 
+#[cfg(feature = "std")]
+mod remote;
+#[cfg(feature = "std")]
+pub use remote::{RemoteAuthenticator, SignerServer};
+
+mod signing_request;
+pub use signing_request::SigningRequest;
+
+mod partially_signed_tx;
+pub use partially_signed_tx::PartiallySignedTransaction;
+
 pub mod signatures;
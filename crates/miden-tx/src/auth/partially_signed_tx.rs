@@ -0,0 +1,224 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use miden_objects::{
+    account::AccountId,
+    block::BlockNumber,
+    note::NoteId,
+    transaction::TransactionArgs,
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    Digest, Felt, Hasher, Word,
+};
+
+use winter_maybe_async::{maybe_async, maybe_await};
+
+use super::{SigningRequest, TransactionAuthenticator};
+use crate::{errors::AuthenticationError, SimulatedTransaction};
+
+// PARTIALLY SIGNED TRANSACTION
+// ================================================================================================
+
+/// A transaction which has not been executed yet, together with the signatures over its message
+/// collected so far.
+///
+/// This enables transactions against accounts which require signatures from several signers
+/// (e.g., accounts using the `RpoFalcon512Multisig` authentication component of miden-lib) when
+/// the signers do not share a single [TransactionAuthenticator]:
+/// 1. The proposer creates the transaction via
+///    [TransactionExecutor::propose_transaction()](crate::TransactionExecutor::propose_transaction),
+///    which simulates it without requesting any signatures.
+/// 2. The transaction is serialized and passed between the signers. Each signer reviews the
+///    effects of the transaction via [Self::signing_request()] and adds its signature via
+///    [Self::sign()] or [Self::add_signature()].
+/// 3. Once enough signatures are collected, the transaction is executed via
+///    [TransactionExecutor::execute_transaction()](crate::TransactionExecutor::execute_transaction)
+///    using the arguments returned by [Self::into_tx_args()].
+///
+/// Note that the signatures are not verified when they are added. Invalid signatures cause the
+/// execution of the transaction to fail.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartiallySignedTransaction {
+    block_ref: BlockNumber,
+    input_notes: Vec<NoteId>,
+    tx_args: TransactionArgs,
+    simulated_tx: SimulatedTransaction,
+    message: Digest,
+    signing_request: SigningRequest,
+    /// A map of public keys |-> signatures over the message.
+    signatures: BTreeMap<Digest, Vec<Felt>>,
+}
+
+impl PartiallySignedTransaction {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [PartiallySignedTransaction] without any signatures.
+    ///
+    /// - `block_ref`, `input_notes` and `tx_args` are the arguments with which the transaction is
+    ///   executed.
+    /// - `simulated_tx` is the result of simulating the transaction with these arguments.
+    /// - `message` is the message which needs to be signed by the signers.
+    /// - `signing_request` describes the transaction up to the point the signature was requested,
+    ///   and is presented to the authenticators of the signers.
+    pub fn new(
+        block_ref: BlockNumber,
+        input_notes: Vec<NoteId>,
+        tx_args: TransactionArgs,
+        simulated_tx: SimulatedTransaction,
+        message: Digest,
+        signing_request: SigningRequest,
+    ) -> Self {
+        Self {
+            block_ref,
+            input_notes,
+            tx_args,
+            simulated_tx,
+            message,
+            signing_request,
+            signatures: BTreeMap::new(),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the account against which the transaction is executed.
+    pub fn account_id(&self) -> AccountId {
+        self.simulated_tx.account_id()
+    }
+
+    /// Returns the number of the reference block of the transaction.
+    pub fn block_ref(&self) -> BlockNumber {
+        self.block_ref
+    }
+
+    /// Returns the IDs of the notes consumed by the transaction.
+    pub fn input_notes(&self) -> &[NoteId] {
+        &self.input_notes
+    }
+
+    /// Returns the arguments of the transaction, excluding the collected signatures.
+    pub fn tx_args(&self) -> &TransactionArgs {
+        &self.tx_args
+    }
+
+    /// Returns the result of simulating the transaction, which describes the changes the
+    /// transaction makes to the account and the notes it creates.
+    pub fn simulated_transaction(&self) -> &SimulatedTransaction {
+        &self.simulated_tx
+    }
+
+    /// Returns the message which needs to be signed by the signers.
+    pub fn message(&self) -> Digest {
+        self.message
+    }
+
+    /// Returns the data of the transaction which is presented to the authenticators of the
+    /// signers, and from which the message can be verified to be derived.
+    pub fn signing_request(&self) -> &SigningRequest {
+        &self.signing_request
+    }
+
+    /// Returns an iterator over the public keys of the signers whose signatures were collected.
+    pub fn signers(&self) -> impl Iterator<Item = &Digest> {
+        self.signatures.keys()
+    }
+
+    /// Returns the number of collected signatures.
+    pub fn num_signatures(&self) -> usize {
+        self.signatures.len()
+    }
+
+    /// Returns true if a signature by the signer with the specified public key was collected.
+    pub fn is_signed_by(&self, pub_key: &Digest) -> bool {
+        self.signatures.contains_key(pub_key)
+    }
+
+    // SIGNING
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds the provided signature over the message by the signer with the specified public key.
+    ///
+    /// If a signature by this signer was already collected, it is replaced.
+    pub fn add_signature(&mut self, pub_key: Digest, signature: Vec<Felt>) {
+        self.signatures.insert(pub_key, signature);
+    }
+
+    /// Requests a signature over the message by the signer with the specified public key from the
+    /// provided authenticator, and adds it to the collected signatures.
+    ///
+    /// # Errors
+    /// Returns an error if the authenticator fails to provide the signature.
+    #[maybe_async]
+    pub fn sign(
+        &mut self,
+        pub_key: Word,
+        authenticator: &dyn TransactionAuthenticator,
+    ) -> Result<(), AuthenticationError> {
+        let signature = maybe_await!(authenticator.get_signature(
+            pub_key,
+            self.message.into(),
+            &self.signing_request,
+        ))?;
+        self.add_signature(pub_key.into(), signature);
+
+        Ok(())
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Consumes `self` and returns the arguments of the transaction, with the collected signatures
+    /// added to their advice map under `hash(PUB_KEY, MESSAGE)`.
+    pub fn into_tx_args(self) -> TransactionArgs {
+        let message = self.message;
+        let mut tx_args = self.tx_args;
+        tx_args.extend_advice_map(
+            self.signatures
+                .into_iter()
+                .map(|(pub_key, signature)| (Hasher::merge(&[pub_key, message]), signature)),
+        );
+
+        tx_args
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for PartiallySignedTransaction {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.block_ref.write_into(target);
+        self.input_notes.write_into(target);
+        self.tx_args.write_into(target);
+        self.simulated_tx.write_into(target);
+        self.message.write_into(target);
+        self.signing_request.write_into(target);
+        target.write_usize(self.signatures.len());
+        for (pub_key, signature) in self.signatures.iter() {
+            pub_key.write_into(target);
+            signature.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for PartiallySignedTransaction {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let block_ref = BlockNumber::read_from(source)?;
+        let input_notes = Vec::<NoteId>::read_from(source)?;
+        let tx_args = TransactionArgs::read_from(source)?;
+        let simulated_tx = SimulatedTransaction::read_from(source)?;
+        let message = Digest::read_from(source)?;
+        let signing_request = SigningRequest::read_from(source)?;
+
+        let mut partial_tx =
+            Self::new(block_ref, input_notes, tx_args, simulated_tx, message, signing_request);
+        let num_signatures = source.read_usize()?;
+        for _ in 0..num_signatures {
+            let pub_key = Digest::read_from(source)?;
+            let signature = Vec::<Felt>::read_from(source)?;
+            partial_tx.add_signature(pub_key, signature);
+        }
+
+        Ok(partial_tx)
+    }
+}
@@ -0,0 +1,437 @@
+#[cfg(feature = "async")]
+use alloc::boxed::Box;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+#[cfg(feature = "async")]
+use std::{
+    future::Future,
+    panic::{self, AssertUnwindSafe},
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+use std::{
+    io::{self, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::Duration,
+};
+
+use miden_objects::{
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    Felt, Word,
+};
+use rand::Rng;
+use winter_maybe_async::*;
+
+use super::{BasicAuthenticator, SigningRequest, TransactionAuthenticator};
+use crate::errors::AuthenticationError;
+
+// CONSTANTS
+// ================================================================================================
+
+/// The maximum size of a message exchanged between a [RemoteAuthenticator] and a [SignerServer].
+const MAX_MESSAGE_SIZE: usize = 1 << 24;
+
+/// The default timeout for connecting to, reading from and writing to the other side of a
+/// connection between a [RemoteAuthenticator] and a [SignerServer].
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The maximum number of connections a [SignerServer] handles at the same time.
+const MAX_CONCURRENT_CONNECTIONS: usize = 64;
+
+// REMOTE AUTHENTICATOR
+// ================================================================================================
+
+/// A [TransactionAuthenticator] which forwards signature requests to a [SignerServer] running in
+/// a separate process, such that the secret keys never have to be loaded into the process which
+/// executes transactions.
+///
+/// A new TCP connection to the signer is established for every request, and a request fails if
+/// connecting to the signer, sending the request or receiving the response takes longer than the
+/// timeout of the authenticator (30 seconds by default). When the `async` feature is enabled, the
+/// request is performed on a separate thread, such that it does not block the async runtime of the
+/// caller.
+///
+/// Note that the transaction executor requests the signatures of a transaction in rounds: the
+/// transaction is executed until it requests signatures which are not present in its advice inputs,
+/// the signatures are requested from the authenticator, and the transaction is executed once more.
+/// Thus, every round of signature requests costs a full re-execution of the transaction, in
+/// addition to the round trips to the signer.
+#[derive(Debug, Clone)]
+pub struct RemoteAuthenticator {
+    address: SocketAddr,
+    timeout: Duration,
+}
+
+impl RemoteAuthenticator {
+    /// Returns a new [RemoteAuthenticator] which requests signatures from the signer listening at
+    /// the specified address.
+    pub fn new(address: SocketAddr) -> Self {
+        Self { address, timeout: DEFAULT_TIMEOUT }
+    }
+
+    /// Sets the timeout for connecting to the signer, sending a request and receiving the
+    /// response.
+    ///
+    /// # Panics
+    /// Panics if the timeout is zero.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        assert!(!timeout.is_zero(), "timeout of remote authenticator must not be zero");
+        self.timeout = timeout;
+        self
+    }
+
+    /// Returns the address of the signer.
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Returns the timeout for connecting to the signer, sending a request and receiving the
+    /// response.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Sends the provided request to the signer and returns the signature the signer responded
+    /// with.
+    fn request_signature(
+        &self,
+        request: &SignatureRequest,
+    ) -> Result<Vec<Felt>, AuthenticationError> {
+        let mut stream = TcpStream::connect_timeout(&self.address, self.timeout)
+            .and_then(|stream| set_timeouts(&stream, self.timeout).map(|_| stream))
+            .map_err(|err| {
+                AuthenticationError::other_with_source("failed to connect to remote signer", err)
+            })?;
+
+        write_message(&mut stream, &request.to_bytes()).map_err(|err| {
+            AuthenticationError::other_with_source("failed to send request to remote signer", err)
+        })?;
+        let response = read_message(&mut stream).map_err(|err| {
+            AuthenticationError::other_with_source("failed to read response of remote signer", err)
+        })?;
+
+        match SignatureResponse::read_from_bytes(&response).map_err(|err| {
+            AuthenticationError::other_with_source("failed to parse response of remote signer", err)
+        })? {
+            SignatureResponse::Signature(signature) => Ok(signature),
+            SignatureResponse::Rejected(reason) => {
+                Err(AuthenticationError::RejectedSignature(reason))
+            },
+        }
+    }
+}
+
+#[maybe_async_trait]
+impl TransactionAuthenticator for RemoteAuthenticator {
+    /// Requests a signature over the message by the specified public key from the remote signer.
+    ///
+    /// # Errors
+    /// Returns an error if the signer could not be reached, or if it rejected the request (e.g.,
+    /// because it does not manage the secret key corresponding to the public key).
+    #[maybe_async]
+    fn get_signature(
+        &self,
+        pub_key: Word,
+        message: Word,
+        signing_request: &SigningRequest,
+    ) -> Result<Vec<Felt>, AuthenticationError> {
+        let request = SignatureRequest {
+            pub_key,
+            message,
+            signing_request: signing_request.clone(),
+        };
+
+        #[cfg(feature = "async")]
+        {
+            let authenticator = self.clone();
+            SignatureTask::spawn(move || authenticator.request_signature(&request)).await
+        }
+        #[cfg(not(feature = "async"))]
+        self.request_signature(&request)
+    }
+}
+
+// SIGNATURE TASK
+// ================================================================================================
+
+/// A future which resolves to the result of a signature request performed on a separate thread.
+#[cfg(feature = "async")]
+struct SignatureTask {
+    state: Arc<Mutex<SignatureTaskState>>,
+}
+
+#[cfg(feature = "async")]
+#[derive(Default)]
+struct SignatureTaskState {
+    result: Option<Result<Vec<Felt>, AuthenticationError>>,
+    waker: Option<Waker>,
+}
+
+#[cfg(feature = "async")]
+impl SignatureTask {
+    /// Performs the provided request on a new thread, and returns a future which resolves to its
+    /// result.
+    fn spawn(
+        request: impl FnOnce() -> Result<Vec<Felt>, AuthenticationError> + Send + 'static,
+    ) -> Self {
+        let state = Arc::new(Mutex::new(SignatureTaskState::default()));
+
+        let task_state = state.clone();
+        thread::spawn(move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(request)).unwrap_or_else(|_| {
+                Err(AuthenticationError::other("request to remote signer panicked"))
+            });
+
+            let mut state = task_state.lock().expect("signature task state should not be poisoned");
+            state.result = Some(result);
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        });
+
+        Self { state }
+    }
+}
+
+#[cfg(feature = "async")]
+impl Future for SignatureTask {
+    type Output = Result<Vec<Felt>, AuthenticationError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().expect("signature task state should not be poisoned");
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            },
+        }
+    }
+}
+
+// SIGNER SERVER
+// ================================================================================================
+
+/// Serves signature requests of [RemoteAuthenticator]s using the keys managed by a
+/// [BasicAuthenticator].
+///
+/// The server does not authenticate its clients: anyone who can connect to it can request
+/// signatures over any message derived from a transaction. Thus, it should only listen at
+/// addresses which cannot be reached by untrusted parties, such as loopback addresses.
+pub struct SignerServer<R> {
+    listener: TcpListener,
+    authenticator: BasicAuthenticator<R>,
+    /// If true, requests for messages which were not derived from their signing request are
+    /// rejected.
+    verify_messages: bool,
+    /// The timeout for reading a request from and writing a response to a connection.
+    timeout: Duration,
+}
+
+impl<R: Rng> SignerServer<R> {
+    /// Returns a new [SignerServer] listening at the specified address, which signs messages using
+    /// the keys managed by the provided authenticator.
+    ///
+    /// By default, the server rejects requests for messages which were not derived from the
+    /// signing request sent along with them (see [Self::without_message_verification()]). Note
+    /// that this does not verify the account delta of the signing request, which is not part of
+    /// the message. The server also closes connections on which reading a request or writing a response takes longer than 30
+    /// seconds.
+    ///
+    /// # Errors
+    /// Returns an error if the server cannot listen at the specified address.
+    pub fn bind(
+        address: impl ToSocketAddrs,
+        authenticator: BasicAuthenticator<R>,
+    ) -> io::Result<Self> {
+        let listener = TcpListener::bind(address)?;
+        Ok(Self {
+            listener,
+            authenticator,
+            verify_messages: true,
+            timeout: DEFAULT_TIMEOUT,
+        })
+    }
+
+    /// Makes the server sign messages without verifying that they were derived from the signing
+    /// request sent along with them (see [SigningRequest::verify_message()]).
+    ///
+    /// This is required if the accounts of the keys managed by the server use authentication
+    /// components which compute the message differently from the authentication components of
+    /// miden-lib.
+    pub fn without_message_verification(mut self) -> Self {
+        self.verify_messages = false;
+        self
+    }
+
+    /// Sets the timeout for reading a request from and writing a response to a connection.
+    ///
+    /// # Panics
+    /// Panics if the timeout is zero.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        assert!(!timeout.is_zero(), "timeout of signer server must not be zero");
+        self.timeout = timeout;
+        self
+    }
+
+    /// Returns the address the server is listening at.
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Reads a single request from the provided stream and responds to it.
+    fn handle_request(&self, stream: &mut TcpStream) -> io::Result<()> {
+        set_timeouts(stream, self.timeout)?;
+        let request = read_message(stream)?;
+        let response = match SignatureRequest::read_from_bytes(&request) {
+            Ok(request) => match self.sign(&request) {
+                Ok(signature) => SignatureResponse::Signature(signature),
+                Err(err) => SignatureResponse::Rejected(err.to_string()),
+            },
+            Err(err) => SignatureResponse::Rejected(format!("malformed request: {err}")),
+        };
+
+        write_message(stream, &response.to_bytes())
+    }
+
+    /// Signs the message of the provided request, after verifying it against the signing request
+    /// if message verification is enabled.
+    fn sign(&self, request: &SignatureRequest) -> Result<Vec<Felt>, AuthenticationError> {
+        if self.verify_messages {
+            request.signing_request.verify_message(request.message)?;
+        }
+
+        self.authenticator.sign(request.pub_key, request.message)
+    }
+}
+
+impl<R: Rng + Send + Sync> SignerServer<R> {
+    /// Serves incoming requests until accepting a connection fails.
+    ///
+    /// Each connection is handled on a separate thread, such that slow or idle clients do not
+    /// delay the requests of other clients. Connections accepted while the maximum number of
+    /// connections (64) is being handled are closed immediately.
+    ///
+    /// Failures to handle a single request (e.g., because the connection was closed by the
+    /// client or timed out) do not stop the server.
+    pub fn serve(&self) -> io::Result<()> {
+        let num_connections = AtomicUsize::new(0);
+
+        thread::scope(|scope| loop {
+            let (mut stream, _) = self.listener.accept()?;
+            if num_connections.fetch_add(1, Ordering::AcqRel) >= MAX_CONCURRENT_CONNECTIONS {
+                num_connections.fetch_sub(1, Ordering::AcqRel);
+                continue;
+            }
+
+            let num_connections = &num_connections;
+            scope.spawn(move || {
+                let _ = self.handle_request(&mut stream);
+                num_connections.fetch_sub(1, Ordering::AcqRel);
+            });
+        })
+    }
+}
+
+// MESSAGES
+// ================================================================================================
+
+/// A request for a signature sent by a [RemoteAuthenticator] to a [SignerServer].
+struct SignatureRequest {
+    pub_key: Word,
+    message: Word,
+    /// The data of the transaction, which allows the signer to review the transaction before
+    /// signing.
+    signing_request: SigningRequest,
+}
+
+impl Serializable for SignatureRequest {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.pub_key.write_into(target);
+        self.message.write_into(target);
+        self.signing_request.write_into(target);
+    }
+}
+
+impl Deserializable for SignatureRequest {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let pub_key = Word::read_from(source)?;
+        let message = Word::read_from(source)?;
+        let signing_request = SigningRequest::read_from(source)?;
+
+        Ok(Self { pub_key, message, signing_request })
+    }
+}
+
+/// The response of a [SignerServer] to a [SignatureRequest].
+enum SignatureResponse {
+    Signature(Vec<Felt>),
+    Rejected(String),
+}
+
+impl Serializable for SignatureResponse {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::Signature(signature) => {
+                target.write_u8(0);
+                signature.write_into(target);
+            },
+            Self::Rejected(reason) => {
+                target.write_u8(1);
+                reason.write_into(target);
+            },
+        }
+    }
+}
+
+impl Deserializable for SignatureResponse {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(Self::Signature(Vec::<Felt>::read_from(source)?)),
+            1 => Ok(Self::Rejected(String::read_from(source)?)),
+            tag => Err(DeserializationError::InvalidValue(format!(
+                "unknown signature response tag {tag}"
+            ))),
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Sets the read and write timeouts of the provided stream.
+fn set_timeouts(stream: &TcpStream, timeout: Duration) -> io::Result<()> {
+    stream.set_read_timeout(Some(timeout))?;
+    stream.set_write_timeout(Some(timeout))
+}
+
+/// Writes the provided message to the stream, prefixed with its length.
+fn write_message(stream: &mut TcpStream, message: &[u8]) -> io::Result<()> {
+    let len = u32::try_from(message.len())
+        .ok()
+        .filter(|len| *len as usize <= MAX_MESSAGE_SIZE)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "message is too large"))?;
+
+    stream.write_all(&len.to_le_bytes())?;
+    stream.write_all(message)?;
+    stream.flush()
+}
+
+/// Reads a length-prefixed message from the stream.
+fn read_message(stream: &mut TcpStream) -> io::Result<Vec<u8>> {
+    let mut len = [0; 4];
+    stream.read_exact(&mut len)?;
+    let len = u32::from_le_bytes(len) as usize;
+    if len > MAX_MESSAGE_SIZE {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "message is too large"));
+    }
+
+    let mut message = vec![0; len];
+    stream.read_exact(&mut message)?;
+    Ok(message)
+}
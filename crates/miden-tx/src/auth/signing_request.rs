@@ -0,0 +1,161 @@
+use miden_objects::{
+    account::{AccountDelta, AccountId},
+    note::NoteId,
+    transaction::{InputNote, InputNotes, OutputNotes},
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    Digest, Felt, Hasher, Word, ZERO,
+};
+
+use crate::errors::AuthenticationError;
+
+// SIGNING REQUEST
+// ================================================================================================
+
+/// The data of a transaction which is presented to a
+/// [TransactionAuthenticator](super::TransactionAuthenticator) together with a message to sign.
+///
+/// This allows signers to show users what they are approving, i.e., the changes the transaction
+/// makes to the account, the notes it consumes, and the notes it creates (including their assets
+/// and recipients, if known).
+///
+/// The authentication components of miden-lib (`RpoFalcon512` and `RpoFalcon512Multisig`) sign
+/// the message `h(OUTPUT_NOTES_COMMITMENT, h(INPUT_NOTES_COMMITMENT, h(ACCOUNT_ID, NONCE)))`, where
+/// the nonce is the nonce of the account at the beginning of the transaction. The authenticator
+/// can verify that a message was derived from the data of the request via
+/// [Self::verify_message()].
+///
+/// Note that the account delta is not part of the message, and so it is not authenticated by it.
+/// A successful [Self::verify_message()] does not imply that the account delta of the request is
+/// the one of the transaction, and so it must not be treated as verified data, e.g., when deciding
+/// whether to sign the message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SigningRequest {
+    account_id: AccountId,
+    nonce: Felt,
+    account_delta: AccountDelta,
+    input_notes: InputNotes<InputNote>,
+    output_notes: OutputNotes,
+}
+
+impl SigningRequest {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [SigningRequest] instantiated from the provided data.
+    ///
+    /// - `nonce` is the nonce of the account at the beginning of the transaction.
+    /// - `account_delta` describes the changes made to the account up to the point the signature
+    ///   was requested.
+    pub fn new(
+        account_id: AccountId,
+        nonce: Felt,
+        account_delta: AccountDelta,
+        input_notes: InputNotes<InputNote>,
+        output_notes: OutputNotes,
+    ) -> Self {
+        Self {
+            account_id,
+            nonce,
+            account_delta,
+            input_notes,
+            output_notes,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the account against which the transaction is executed.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the nonce of the account at the beginning of the transaction.
+    pub fn nonce(&self) -> Felt {
+        self.nonce
+    }
+
+    /// Returns the changes made to the account up to the point the signature was requested.
+    ///
+    /// The account delta is not authenticated by the message of this request, and so it is only
+    /// informative unless the authenticator executed the transaction itself.
+    pub fn account_delta(&self) -> &AccountDelta {
+        &self.account_delta
+    }
+
+    /// Returns the notes consumed by the transaction.
+    pub fn input_notes(&self) -> &InputNotes<InputNote> {
+        &self.input_notes
+    }
+
+    /// Returns an iterator over the IDs of the notes consumed by the transaction.
+    pub fn input_note_ids(&self) -> impl Iterator<Item = NoteId> + '_ {
+        self.input_notes.iter().map(|note| note.id())
+    }
+
+    /// Returns the notes created by the transaction up to the point the signature was requested.
+    ///
+    /// The assets and the recipients of private notes are not known and so they are not included.
+    pub fn output_notes(&self) -> &OutputNotes {
+        &self.output_notes
+    }
+
+    // MESSAGE VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the message signed by the authentication components of miden-lib for the
+    /// transaction described by this request.
+    ///
+    /// The message commits to the account ID, the nonce, and the input and output notes of this
+    /// request, but not to the account delta.
+    pub fn compute_message(&self) -> Digest {
+        let account_id: Word =
+            [self.account_id.suffix(), self.account_id.prefix().as_felt(), ZERO, ZERO];
+        let nonce: Word = [self.nonce, ZERO, ZERO, ZERO];
+
+        let account_commitment = Hasher::merge(&[nonce.into(), account_id.into()]);
+        let input_commitment = Hasher::merge(&[self.input_notes.commitment(), account_commitment]);
+        Hasher::merge(&[self.output_notes.commitment(), input_commitment])
+    }
+
+    /// Verifies that the provided message was derived from the data of this request, as computed
+    /// by [Self::compute_message()].
+    ///
+    /// The account delta of this request is not verified, as it is not part of the message.
+    ///
+    /// # Errors
+    /// Returns an error if the message does not match the data of this request.
+    pub fn verify_message(&self, message: Word) -> Result<(), AuthenticationError> {
+        let expected = self.compute_message();
+        if expected != Digest::from(message) {
+            return Err(AuthenticationError::MessageMismatch { expected, actual: message.into() });
+        }
+
+        Ok(())
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for SigningRequest {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account_id.write_into(target);
+        self.nonce.write_into(target);
+        self.account_delta.write_into(target);
+        self.input_notes.write_into(target);
+        self.output_notes.write_into(target);
+    }
+}
+
+impl Deserializable for SigningRequest {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account_id = AccountId::read_from(source)?;
+        let nonce = Felt::read_from(source)?;
+        let account_delta = AccountDelta::read_from(source)?;
+        let input_notes = InputNotes::<InputNote>::read_from(source)?;
+        let output_notes = OutputNotes::read_from(source)?;
+
+        Ok(Self::new(account_id, nonce, account_delta, input_notes, output_notes))
+    }
+}
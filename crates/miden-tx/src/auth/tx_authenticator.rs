@@ -1,11 +1,14 @@
+#[cfg(feature = "async")]
+use alloc::boxed::Box;
 use alloc::{collections::BTreeMap, string::ToString, sync::Arc, vec::Vec};
 
 use miden_lib::utils::sync::RwLock;
-use miden_objects::account::{AccountDelta, AuthSecretKey};
+use miden_objects::account::AuthSecretKey;
 use rand::Rng;
 use vm_processor::{Digest, Felt, Word};
+use winter_maybe_async::*;
 
-use super::signatures::get_falcon_signature;
+use super::{signatures::get_falcon_signature, SigningRequest};
 use crate::errors::AuthenticationError;
 
 // TRANSACTION AUTHENTICATOR
@@ -18,23 +21,34 @@ use crate::errors::AuthenticationError;
 /// private key pairs, and can be requested to generate signatures against any of the managed keys.
 ///
 /// The public keys are defined by [Digest]'s which are the hashes of the actual public keys.
+///
+/// When the `async` feature is enabled, [Self::get_signature()] is async, which allows the keys to
+/// be managed by an external signer (e.g., a hardware wallet or an HSM). See
+/// `RemoteAuthenticator` for an implementation which requests signatures from a signer running in
+/// a separate process.
+#[maybe_async_trait]
 pub trait TransactionAuthenticator {
     /// Retrieves a signature for a specific message as a list of [Felt].
     ///
-    /// The request is initiated by the VM as a consequence of the SigToStack advice
-    /// injector.
+    /// The request is initiated when the transaction requests a signature which is not present in
+    /// its advice inputs: by the transaction host during execution, or, when the `async` feature
+    /// is enabled, by the transaction executor, which then re-executes the transaction with the
+    /// obtained signature.
     ///
     /// - `pub_key`: The public key used for signature generation.
     /// - `message`: The message to sign, usually a commitment to the transaction data.
-    /// - `account_delta`: An informational parameter describing the changes made to the account up
-    ///   to the point of calling `get_signature()`. This allows the authenticator to review any
-    ///   alterations to the account prior to signing. It should not be directly used in the
-    ///   signature computation.
+    /// - `signing_request`: An informational parameter describing the transaction up to the point
+    ///   of calling `get_signature()`. This allows the authenticator to review the changes made to
+    ///   the account and the notes consumed and created by the transaction prior to signing, and
+    ///   to verify that the message was derived from them via
+    ///   [SigningRequest::verify_message()]. It should not be directly used in the signature
+    ///   computation.
+    #[maybe_async]
     fn get_signature(
         &self,
         pub_key: Word,
         message: Word,
-        account_delta: &AccountDelta,
+        signing_request: &SigningRequest,
     ) -> Result<Vec<Felt>, AuthenticationError>;
 }
 
@@ -69,9 +83,7 @@ impl<R: Rng> BasicAuthenticator<R> {
             rng: Arc::new(RwLock::new(rng)),
         }
     }
-}
 
-impl<R: Rng> TransactionAuthenticator for BasicAuthenticator<R> {
     /// Gets a signature over a message, given a public key.
     /// The key should be included in the `keys` map and should be a variant of [AuthSecretKey].
     ///
@@ -81,13 +93,7 @@ impl<R: Rng> TransactionAuthenticator for BasicAuthenticator<R> {
     /// # Errors
     /// If the public key is not contained in the `keys` map,
     /// [`AuthenticationError::UnknownPublicKey`] is returned.
-    fn get_signature(
-        &self,
-        pub_key: Word,
-        message: Word,
-        account_delta: &AccountDelta,
-    ) -> Result<Vec<Felt>, AuthenticationError> {
-        let _ = account_delta;
+    pub fn sign(&self, pub_key: Word, message: Word) -> Result<Vec<Felt>, AuthenticationError> {
         let mut rng = self.rng.write();
 
         match self.keys.get(&pub_key.into()) {
@@ -104,15 +110,34 @@ impl<R: Rng> TransactionAuthenticator for BasicAuthenticator<R> {
     }
 }
 
+#[maybe_async_trait]
+impl<R: Rng + Send + Sync> TransactionAuthenticator for BasicAuthenticator<R> {
+    /// Gets a signature over a message, given a public key.
+    ///
+    /// See [BasicAuthenticator::sign()] for details.
+    #[maybe_async]
+    fn get_signature(
+        &self,
+        pub_key: Word,
+        message: Word,
+        signing_request: &SigningRequest,
+    ) -> Result<Vec<Felt>, AuthenticationError> {
+        let _ = signing_request;
+        self.sign(pub_key, message)
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
+#[maybe_async_trait]
 impl TransactionAuthenticator for () {
+    #[maybe_async]
     fn get_signature(
         &self,
         _pub_key: Word,
         _message: Word,
-        _account_delta: &AccountDelta,
+        _signing_request: &SigningRequest,
     ) -> Result<Vec<Felt>, AuthenticationError> {
         Err(AuthenticationError::RejectedSignature(
             "default authenticator cannot provide signatures".to_string(),
@@ -0,0 +1,59 @@
+use alloc::{sync::Arc, vec::Vec};
+
+use miden_lib::transaction::TransactionTrace;
+use miden_objects::Digest;
+use vm_processor::{ExecutionError, Host, MastForest, ProcessState, RowIndex};
+
+// DEBUG HOST
+// ================================================================================================
+
+/// A [Host] which forwards all requests to the wrapped host, and records the cycles at which the
+/// transaction kernel emitted [TransactionTrace]s.
+pub struct DebugHost<H> {
+    host: H,
+    trace_events: Vec<(RowIndex, TransactionTrace)>,
+}
+
+impl<H: Host> DebugHost<H> {
+    /// Returns a new [DebugHost] wrapping the provided host.
+    pub fn new(host: H) -> Self {
+        Self { host, trace_events: Vec::new() }
+    }
+
+    /// Consumes `self` and returns the wrapped host, together with the recorded trace events and
+    /// the cycles at which they were emitted.
+    pub fn into_parts(self) -> (H, Vec<(RowIndex, TransactionTrace)>) {
+        (self.host, self.trace_events)
+    }
+}
+
+impl<H: Host> Host for DebugHost<H> {
+    type AdviceProvider = H::AdviceProvider;
+
+    fn advice_provider(&self) -> &Self::AdviceProvider {
+        self.host.advice_provider()
+    }
+
+    fn advice_provider_mut(&mut self) -> &mut Self::AdviceProvider {
+        self.host.advice_provider_mut()
+    }
+
+    fn get_mast_forest(&self, node_digest: &Digest) -> Option<Arc<MastForest>> {
+        self.host.get_mast_forest(node_digest)
+    }
+
+    fn on_event(&mut self, process: ProcessState, event_id: u32) -> Result<(), ExecutionError> {
+        self.host.on_event(process, event_id)
+    }
+
+    fn on_trace(&mut self, process: ProcessState, trace_id: u32) -> Result<(), ExecutionError> {
+        if let Ok(trace) = TransactionTrace::try_from(trace_id) {
+            self.trace_events.push((process.clk(), trace));
+        }
+        self.host.on_trace(process, trace_id)
+    }
+
+    fn on_assert_failed(&mut self, process: ProcessState, err_code: u32) -> ExecutionError {
+        self.host.on_assert_failed(process, err_code)
+    }
+}
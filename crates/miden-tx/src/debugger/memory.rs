@@ -0,0 +1,219 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use miden_lib::transaction::memory::{
+    ACCT_CODE_COMMITMENT_OFFSET, ACCT_ID_AND_NONCE_OFFSET, ACCT_ID_PREFIX_IDX, ACCT_ID_SUFFIX_IDX,
+    ACCT_NONCE_IDX, ACCT_STORAGE_COMMITMENT_OFFSET, ACCT_STORAGE_SLOTS_SECTION_OFFSET,
+    ACCT_VAULT_ROOT_OFFSET, CURRENT_ACCOUNT_DATA_PTR, CURRENT_INPUT_NOTE_PTR,
+    INPUT_NOTE_ARGS_OFFSET, INPUT_NOTE_ASSETS_HASH_OFFSET, INPUT_NOTE_ASSETS_OFFSET,
+    INPUT_NOTE_DATA_SECTION_OFFSET, INPUT_NOTE_ID_OFFSET, INPUT_NOTE_INPUTS_HASH_OFFSET,
+    INPUT_NOTE_METADATA_OFFSET, INPUT_NOTE_NUM_ASSETS_OFFSET, INPUT_NOTE_SCRIPT_ROOT_OFFSET,
+    INPUT_NOTE_SERIAL_NUM_OFFSET, INPUT_VAULT_ROOT_PTR, NOTE_MEM_SIZE,
+    NUM_ACCT_STORAGE_SLOTS_OFFSET, NUM_INPUT_NOTES_PTR, NUM_OUTPUT_NOTES_PTR,
+    OUTPUT_NOTE_ASSETS_OFFSET, OUTPUT_NOTE_ASSET_HASH_OFFSET, OUTPUT_NOTE_ID_OFFSET,
+    OUTPUT_NOTE_METADATA_OFFSET, OUTPUT_NOTE_NUM_ASSETS_OFFSET, OUTPUT_NOTE_RECIPIENT_OFFSET,
+    OUTPUT_NOTE_SECTION_OFFSET, OUTPUT_VAULT_ROOT_PTR,
+};
+use miden_objects::{
+    account::{AccountId, StorageSlot},
+    note::NoteId,
+    Digest, Felt, Word, ZERO,
+};
+
+// KERNEL MEMORY
+// ================================================================================================
+
+/// A snapshot of the memory of the transaction kernel (i.e., of the root context), decoded
+/// according to the layout defined in [miden_lib::transaction::memory].
+///
+/// Note that the assets of account vaults and the entries of storage maps are not kept in kernel
+/// memory; only their commitments are.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KernelMemory {
+    memory: BTreeMap<u64, Felt>,
+}
+
+impl KernelMemory {
+    /// Returns a new [KernelMemory] instantiated from the provided (address, value) pairs.
+    pub fn new(memory: &[(u64, Felt)]) -> Self {
+        Self { memory: memory.iter().copied().collect() }
+    }
+
+    // RAW ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the element at the specified address. Memory which was never written is read as
+    /// zero.
+    pub fn get_value(&self, addr: u32) -> Felt {
+        self.memory.get(&(addr as u64)).copied().unwrap_or(ZERO)
+    }
+
+    /// Returns the word starting at the specified address.
+    pub fn get_word(&self, addr: u32) -> Word {
+        [
+            self.get_value(addr),
+            self.get_value(addr + 1),
+            self.get_value(addr + 2),
+            self.get_value(addr + 3),
+        ]
+    }
+
+    // TRANSACTION VAULT
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of the vault containing the assets of the native account and of the input
+    /// notes at the beginning of the transaction.
+    pub fn input_vault_root(&self) -> Digest {
+        self.get_word(INPUT_VAULT_ROOT_PTR).into()
+    }
+
+    /// Returns the root of the vault containing the assets of the native account and of the output
+    /// notes at the end of the transaction. This is only set by the epilogue.
+    pub fn output_vault_root(&self) -> Digest {
+        self.get_word(OUTPUT_VAULT_ROOT_PTR).into()
+    }
+
+    // CURRENT ACCOUNT
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the account which is currently being accessed (i.e., the native account
+    /// or a foreign account), or None if the account data has not been loaded yet.
+    pub fn current_account_id(&self) -> Option<AccountId> {
+        let id_and_nonce = self.get_word(self.current_account_ptr() + ACCT_ID_AND_NONCE_OFFSET);
+        AccountId::try_from([id_and_nonce[ACCT_ID_PREFIX_IDX], id_and_nonce[ACCT_ID_SUFFIX_IDX]])
+            .ok()
+    }
+
+    /// Returns the nonce of the account which is currently being accessed.
+    pub fn current_account_nonce(&self) -> Felt {
+        self.get_word(self.current_account_ptr() + ACCT_ID_AND_NONCE_OFFSET)[ACCT_NONCE_IDX]
+    }
+
+    /// Returns the vault root of the account which is currently being accessed.
+    pub fn current_account_vault_root(&self) -> Digest {
+        self.get_word(self.current_account_ptr() + ACCT_VAULT_ROOT_OFFSET).into()
+    }
+
+    /// Returns the storage commitment of the account which is currently being accessed.
+    pub fn current_account_storage_commitment(&self) -> Digest {
+        self.get_word(self.current_account_ptr() + ACCT_STORAGE_COMMITMENT_OFFSET)
+            .into()
+    }
+
+    /// Returns the code commitment of the account which is currently being accessed.
+    pub fn current_account_code_commitment(&self) -> Digest {
+        self.get_word(self.current_account_ptr() + ACCT_CODE_COMMITMENT_OFFSET).into()
+    }
+
+    /// Returns the values of the storage slots of the account which is currently being accessed.
+    ///
+    /// For storage map slots, the value is the root of the storage map.
+    pub fn current_account_storage_slots(&self) -> Vec<Word> {
+        let account_ptr = self.current_account_ptr();
+        let num_slots = self.get_value(account_ptr + NUM_ACCT_STORAGE_SLOTS_OFFSET).as_int() as u32;
+        let slots_ptr = account_ptr + ACCT_STORAGE_SLOTS_SECTION_OFFSET;
+
+        (0..num_slots)
+            .map(|slot_idx| {
+                self.get_word(
+                    slots_ptr + slot_idx * StorageSlot::NUM_ELEMENTS_PER_STORAGE_SLOT as u32,
+                )
+            })
+            .collect()
+    }
+
+    // NOTES
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the data of the input note referenced by the current input note pointer of the
+    /// kernel.
+    ///
+    /// During note processing, this is the note which is being executed or, between the execution
+    /// of two notes, the note which is executed next. Returns None if the pointer is not set or
+    /// does not point to one of the input notes of the transaction.
+    pub fn current_input_note(&self) -> Option<InputNoteData> {
+        let note_ptr = self.get_value(CURRENT_INPUT_NOTE_PTR).as_int() as u32;
+        let num_notes = self.get_value(NUM_INPUT_NOTES_PTR).as_int() as u32;
+        let notes_end = INPUT_NOTE_DATA_SECTION_OFFSET + num_notes * NOTE_MEM_SIZE;
+        if note_ptr < INPUT_NOTE_DATA_SECTION_OFFSET || note_ptr >= notes_end {
+            return None;
+        }
+
+        let num_assets = self.get_value(note_ptr + INPUT_NOTE_NUM_ASSETS_OFFSET).as_int() as u32;
+        let assets = (0..num_assets)
+            .map(|asset_idx| self.get_word(note_ptr + INPUT_NOTE_ASSETS_OFFSET + asset_idx * 4))
+            .collect();
+
+        Some(InputNoteData {
+            id: self.get_word(note_ptr + INPUT_NOTE_ID_OFFSET).into(),
+            serial_num: self.get_word(note_ptr + INPUT_NOTE_SERIAL_NUM_OFFSET),
+            script_root: self.get_word(note_ptr + INPUT_NOTE_SCRIPT_ROOT_OFFSET).into(),
+            inputs_hash: self.get_word(note_ptr + INPUT_NOTE_INPUTS_HASH_OFFSET).into(),
+            assets_hash: self.get_word(note_ptr + INPUT_NOTE_ASSETS_HASH_OFFSET).into(),
+            metadata: self.get_word(note_ptr + INPUT_NOTE_METADATA_OFFSET),
+            args: self.get_word(note_ptr + INPUT_NOTE_ARGS_OFFSET),
+            assets,
+        })
+    }
+
+    /// Returns the data of the notes created by the transaction so far.
+    ///
+    /// The note IDs and asset commitments of the output notes are only computed by the epilogue.
+    pub fn output_notes(&self) -> Vec<OutputNoteData> {
+        let num_notes = self.get_value(NUM_OUTPUT_NOTES_PTR).as_int() as u32;
+
+        (0..num_notes)
+            .map(|note_idx| {
+                let note_ptr = OUTPUT_NOTE_SECTION_OFFSET + note_idx * NOTE_MEM_SIZE;
+                let num_assets =
+                    self.get_value(note_ptr + OUTPUT_NOTE_NUM_ASSETS_OFFSET).as_int() as u32;
+                let assets = (0..num_assets)
+                    .map(|asset_idx| {
+                        self.get_word(note_ptr + OUTPUT_NOTE_ASSETS_OFFSET + asset_idx * 4)
+                    })
+                    .collect();
+
+                OutputNoteData {
+                    id: self.get_word(note_ptr + OUTPUT_NOTE_ID_OFFSET).into(),
+                    metadata: self.get_word(note_ptr + OUTPUT_NOTE_METADATA_OFFSET),
+                    recipient: self.get_word(note_ptr + OUTPUT_NOTE_RECIPIENT_OFFSET).into(),
+                    assets_hash: self.get_word(note_ptr + OUTPUT_NOTE_ASSET_HASH_OFFSET).into(),
+                    assets,
+                }
+            })
+            .collect()
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the pointer to the data of the account which is currently being accessed.
+    fn current_account_ptr(&self) -> u32 {
+        self.get_value(CURRENT_ACCOUNT_DATA_PTR).as_int() as u32
+    }
+}
+
+// NOTE DATA
+// ================================================================================================
+
+/// The data of an input note as stored in kernel memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputNoteData {
+    pub id: NoteId,
+    pub serial_num: Word,
+    pub script_root: Digest,
+    pub inputs_hash: Digest,
+    pub assets_hash: Digest,
+    pub metadata: Word,
+    pub args: Word,
+    pub assets: Vec<Word>,
+}
+
+/// The data of an output note as stored in kernel memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputNoteData {
+    pub id: NoteId,
+    pub metadata: Word,
+    pub recipient: Digest,
+    pub assets_hash: Digest,
+    pub assets: Vec<Word>,
+}
@@ -0,0 +1,259 @@
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use miden_lib::transaction::{TransactionKernel, TransactionTrace};
+use miden_objects::{assembly::Library, Digest, Felt};
+use vm_processor::{ContextId, ExecutionError, RowIndex, VmState, VmStateIterator};
+
+mod host;
+pub use host::DebugHost;
+
+mod memory;
+pub use memory::{InputNoteData, KernelMemory, OutputNoteData};
+
+mod profile;
+pub use profile::{ProcedureProfile, TransactionProfile};
+
+// BREAKPOINT
+// ================================================================================================
+
+/// A condition upon which a [TransactionDebugger] pauses execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Breakpoint {
+    /// Pauses at the cycle at which the transaction kernel emits the specified trace.
+    Trace(TransactionTrace),
+    /// Pauses when execution enters the procedure with the specified MAST root.
+    ///
+    /// Procedures are identified via the assembly operation info of the VM state, and thus this
+    /// breakpoint can only be hit for code assembled in debug mode. Moreover, the procedure must
+    /// be exported from one of the libraries registered with the debugger. Since the debugger
+    /// cannot distinguish between calling a procedure and returning to it, the breakpoint is also
+    /// hit when execution returns to the procedure from a nested procedure call.
+    Procedure(Digest),
+}
+
+// DEBUGGER STOP
+// ================================================================================================
+
+/// Describes why [TransactionDebugger::resume()] returned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DebuggerStop {
+    /// Execution was paused at the specified breakpoint.
+    Breakpoint(Breakpoint),
+    /// The transaction was executed to completion.
+    Finished,
+    /// The transaction failed. The error is available via [TransactionDebugger::error()].
+    Failed,
+}
+
+// TRANSACTION DEBUGGER
+// ================================================================================================
+
+/// Steps through the execution of a transaction cycle by cycle.
+///
+/// The debugger is created via
+/// [TransactionExecutor::debug_transaction()](crate::TransactionExecutor::debug_transaction),
+/// which executes the transaction up front and then allows replaying it. At each cycle, the state
+/// of the VM is available via [Self::state()], and the memory of the transaction kernel can be
+/// inspected in a decoded form via [Self::kernel_memory()].
+///
+/// Execution is paused either after a single step via [Self::step()], or upon hitting one of the
+/// registered [Breakpoint]s via [Self::resume()].
+pub struct TransactionDebugger {
+    states: VmStateIterator,
+    /// The traces emitted by the transaction kernel, keyed by the cycle at which they were
+    /// emitted.
+    trace_events: BTreeMap<RowIndex, Vec<TransactionTrace>>,
+    /// A map of procedure MAST roots |-> fully qualified procedure names.
+    procedure_names: BTreeMap<Digest, String>,
+    breakpoints: Vec<Breakpoint>,
+    /// The state of the VM at the current cycle.
+    state: Option<VmState>,
+    /// The name of the procedure which is currently being executed, if known.
+    procedure: Option<String>,
+    /// True if the current procedure was entered at the current cycle.
+    procedure_changed: bool,
+    /// The memory of the root context as of the last cycle executed in the root context.
+    kernel_memory: Vec<(u64, Felt)>,
+    error: Option<ExecutionError>,
+}
+
+impl TransactionDebugger {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [TransactionDebugger] instantiated from the provided VM states and the trace
+    /// events recorded when the VM states were produced.
+    ///
+    /// The procedures exported from the transaction kernel are registered with the debugger.
+    pub fn new(
+        states: VmStateIterator,
+        trace_events: impl IntoIterator<Item = (RowIndex, TransactionTrace)>,
+    ) -> Self {
+        let mut events: BTreeMap<RowIndex, Vec<TransactionTrace>> = BTreeMap::new();
+        for (clk, trace) in trace_events {
+            events.entry(clk).or_default().push(trace);
+        }
+
+        Self {
+            states,
+            trace_events: events,
+            procedure_names: BTreeMap::new(),
+            breakpoints: Vec::new(),
+            state: None,
+            procedure: None,
+            procedure_changed: false,
+            kernel_memory: Vec::new(),
+            error: None,
+        }
+        .with_library(TransactionKernel::kernel().as_ref())
+    }
+
+    /// Registers the procedures exported from the provided library with the debugger, such that
+    /// they can be used in [Breakpoint::Procedure].
+    pub fn with_library(mut self, library: &Library) -> Self {
+        for proc_name in library.exports() {
+            let node_id = library.get_export_node_id(proc_name);
+            let root = library.mast_forest()[node_id].digest();
+            self.procedure_names.insert(root, proc_name.to_string());
+        }
+        self
+    }
+
+    // BREAKPOINTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds the provided breakpoint to the debugger. Adding the same breakpoint twice has no
+    /// effect.
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) {
+        if !self.breakpoints.contains(&breakpoint) {
+            self.breakpoints.push(breakpoint);
+        }
+    }
+
+    /// Removes the provided breakpoint from the debugger, and returns true if it was present.
+    pub fn remove_breakpoint(&mut self, breakpoint: &Breakpoint) -> bool {
+        let num_breakpoints = self.breakpoints.len();
+        self.breakpoints.retain(|bp| bp != breakpoint);
+        self.breakpoints.len() != num_breakpoints
+    }
+
+    /// Returns the breakpoints registered with the debugger.
+    pub fn breakpoints(&self) -> &[Breakpoint] {
+        &self.breakpoints
+    }
+
+    // EXECUTION
+    // --------------------------------------------------------------------------------------------
+
+    /// Advances execution by a single cycle, and returns the state of the VM at that cycle.
+    ///
+    /// Returns None if the transaction has finished or failed; in the latter case, the error is
+    /// available via [Self::error()].
+    pub fn step(&mut self) -> Option<&VmState> {
+        let state = match self.states.next() {
+            Some(Ok(state)) => state,
+            Some(Err(err)) => {
+                self.error = Some(err);
+                return None;
+            },
+            None => return None,
+        };
+
+        // kernel memory can only change while executing in the root context, and thus the memory
+        // of the last state in the root context remains valid while executing in other contexts
+        if let Some(prev_state) = self.state.take() {
+            if prev_state.ctx == ContextId::root() {
+                self.kernel_memory = prev_state.memory;
+            }
+        }
+
+        let procedure = state.asmop.as_ref().map(|asmop| asmop.context_name());
+        self.procedure_changed = procedure.is_some() && procedure != self.procedure.as_deref();
+        if self.procedure_changed {
+            self.procedure = procedure.map(String::from);
+        }
+
+        self.state = Some(state);
+        self.state.as_ref()
+    }
+
+    /// Advances execution until one of the registered breakpoints is hit or the transaction
+    /// terminates, and returns the reason why execution stopped.
+    ///
+    /// If several breakpoints are hit at the same cycle, the one which was added first is
+    /// returned.
+    pub fn resume(&mut self) -> DebuggerStop {
+        loop {
+            if self.step().is_none() {
+                return match self.error {
+                    Some(_) => DebuggerStop::Failed,
+                    None => DebuggerStop::Finished,
+                };
+            }
+
+            if let Some(breakpoint) = self.breakpoints.iter().find(|bp| self.is_hit(bp)) {
+                return DebuggerStop::Breakpoint(breakpoint.clone());
+            }
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the state of the VM at the current cycle, or None if execution has not started yet.
+    pub fn state(&self) -> Option<&VmState> {
+        self.state.as_ref()
+    }
+
+    /// Returns the fully qualified name of the procedure which is currently being executed, or
+    /// None if it is unknown (e.g., because the procedure was not assembled in debug mode).
+    pub fn procedure(&self) -> Option<&str> {
+        self.procedure.as_deref()
+    }
+
+    /// Returns the memory of the transaction kernel at the current cycle.
+    pub fn kernel_memory(&self) -> KernelMemory {
+        match &self.state {
+            Some(state) if state.ctx == ContextId::root() => KernelMemory::new(&state.memory),
+            _ => KernelMemory::new(&self.kernel_memory),
+        }
+    }
+
+    /// Returns an iterator over the traces emitted by the transaction kernel during the whole
+    /// execution of the transaction, together with the cycles at which they were emitted.
+    pub fn trace_events(&self) -> impl Iterator<Item = (RowIndex, &TransactionTrace)> {
+        self.trace_events
+            .iter()
+            .flat_map(|(clk, traces)| traces.iter().map(move |trace| (*clk, trace)))
+    }
+
+    /// Returns the error with which the transaction failed, or None if the transaction has not
+    /// failed (yet).
+    pub fn error(&self) -> Option<&ExecutionError> {
+        self.error.as_ref()
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if the provided breakpoint is hit at the current cycle.
+    fn is_hit(&self, breakpoint: &Breakpoint) -> bool {
+        let Some(state) = &self.state else {
+            return false;
+        };
+
+        match breakpoint {
+            Breakpoint::Trace(trace) => {
+                self.trace_events.get(&state.clk).is_some_and(|traces| traces.contains(trace))
+            },
+            Breakpoint::Procedure(root) => {
+                self.procedure_changed
+                    && self.procedure_names.get(root).map(String::as_str) == self.procedure()
+            },
+        }
+    }
+}
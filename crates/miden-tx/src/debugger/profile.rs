@@ -0,0 +1,452 @@
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use core::fmt::Write;
+
+use miden_lib::transaction::{TransactionKernel, TransactionTrace};
+use miden_objects::{
+    assembly::{
+        mast::{MastForest, MastNode, MastNodeId},
+        Library,
+    },
+    transaction::TransactionMeasurements,
+    Digest, Felt, ONE, ZERO,
+};
+use vm_processor::{
+    ExecutionError, MastForestStore, Operation, Program, RowIndex, VmState, VmStateIterator,
+};
+
+// TRANSACTION PROFILE
+// ================================================================================================
+
+/// Attributes the cycles of an executed transaction to the procedures which were running when the
+/// cycles were spent.
+///
+/// The profile is built by [TransactionExecutor::profile_transaction()](
+/// crate::TransactionExecutor::profile_transaction). Cycles are attributed to call stacks, where
+/// each stack consists of the stage of the transaction (e.g., the prologue or the execution of a
+/// note) followed by the procedures invoked via `call`, `syscall`, `dynexec` or `dyncall`. Thus,
+/// account procedures, kernel API procedures, note scripts and transaction scripts each get their
+/// own frame, while procedures invoked via `exec` are attributed to their caller.
+///
+/// Procedures are identified by their MAST roots. The names of the procedures exported from the
+/// transaction kernel are known to the profile, and the names of other procedures (e.g., of the
+/// procedures of account components) can be provided via [Self::with_library()].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransactionProfile {
+    measurements: TransactionMeasurements,
+    /// A map of call stacks |-> number of cycles spent in the innermost frame of the stack.
+    stacks: BTreeMap<Vec<ProfileFrame>, usize>,
+    /// A map of procedure MAST roots |-> number of times the procedure was invoked.
+    num_calls: BTreeMap<Digest, usize>,
+    /// A map of procedure MAST roots |-> fully qualified procedure names.
+    procedure_names: BTreeMap<Digest, String>,
+}
+
+impl TransactionProfile {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [TransactionProfile] built from the VM states produced by executing the
+    /// transaction kernel program, and the trace events recorded during that execution.
+    ///
+    /// `mast_store` must provide the code of all external procedures executed by the transaction.
+    ///
+    /// # Errors
+    /// Returns an error if the execution of the transaction failed.
+    pub fn new(
+        states: VmStateIterator,
+        trace_events: impl IntoIterator<Item = (RowIndex, TransactionTrace)>,
+        mast_store: &impl MastForestStore,
+        measurements: TransactionMeasurements,
+    ) -> Result<Self, ExecutionError> {
+        let mut trace_events: BTreeMap<RowIndex, Vec<TransactionTrace>> =
+            trace_events.into_iter().fold(BTreeMap::new(), |mut events, (clk, trace)| {
+                events.entry(clk).or_default().push(trace);
+                events
+            });
+
+        let mut tracker = CallStackTracker::new(TransactionKernel::main(), mast_store);
+        let mut prev_state: Option<VmState> = None;
+        for state in states {
+            let state = state?;
+            if let Some(prev_state) = &prev_state {
+                tracker.execute_op(prev_state, &state);
+            }
+            for trace in trace_events.remove(&state.clk).unwrap_or_default() {
+                tracker.on_trace(trace);
+            }
+            prev_state = Some(state);
+        }
+        let (stacks, num_calls) = tracker.finish();
+
+        let profile = Self {
+            measurements,
+            stacks,
+            num_calls,
+            procedure_names: BTreeMap::new(),
+        };
+
+        Ok(profile.with_library(TransactionKernel::kernel().as_ref()))
+    }
+
+    /// Registers the names of the procedures exported from the provided library with the profile.
+    pub fn with_library(mut self, library: &Library) -> Self {
+        for proc_name in library.exports() {
+            let node_id = library.get_export_node_id(proc_name);
+            let root = library.mast_forest()[node_id].digest();
+            self.procedure_names.insert(root, proc_name.to_string());
+        }
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of cycles spent in each stage of the transaction.
+    pub fn measurements(&self) -> &TransactionMeasurements {
+        &self.measurements
+    }
+
+    /// Returns the total number of cycles spent executing the transaction.
+    pub fn total_cycles(&self) -> usize {
+        self.stacks.values().sum()
+    }
+
+    /// Returns the profile of the procedure with the specified MAST root, or None if the procedure
+    /// was not invoked by the transaction.
+    pub fn procedure(&self, root: &Digest) -> Option<ProcedureProfile> {
+        let num_calls = *self.num_calls.get(root)?;
+        let frame = ProfileFrame::Procedure(*root);
+
+        let mut self_cycles = 0;
+        let mut total_cycles = 0;
+        for (stack, cycles) in self.stacks.iter() {
+            if stack.last() == Some(&frame) {
+                self_cycles += cycles;
+            }
+            if stack.contains(&frame) {
+                total_cycles += cycles;
+            }
+        }
+
+        Some(ProcedureProfile {
+            root: *root,
+            name: self.procedure_names.get(root).cloned(),
+            num_calls,
+            self_cycles,
+            total_cycles,
+        })
+    }
+
+    /// Returns an iterator over the profiles of all procedures invoked by the transaction.
+    pub fn procedures(&self) -> impl Iterator<Item = ProcedureProfile> + '_ {
+        self.num_calls.keys().filter_map(|root| self.procedure(root))
+    }
+
+    /// Returns the profile in the collapsed stack format, which can be turned into a flamegraph by
+    /// tools such as `inferno-flamegraph` or `flamegraph.pl`.
+    ///
+    /// Each line consists of the frames of a call stack separated by `;`, followed by a space and
+    /// the number of cycles spent in the innermost frame of the stack. Procedures are named by
+    /// their fully qualified names if known, and by the hex encoding of their MAST roots
+    /// otherwise.
+    pub fn to_collapsed_stacks(&self) -> String {
+        let mut output = String::new();
+        for (stack, cycles) in self.stacks.iter() {
+            output.push_str("transaction");
+            for frame in stack {
+                output.push(';');
+                match frame {
+                    ProfileFrame::Stage(stage) => output.push_str(stage.name()),
+                    ProfileFrame::Procedure(root) => match self.procedure_names.get(root) {
+                        Some(name) => output.push_str(name),
+                        None => output.push_str(&root.to_hex()),
+                    },
+                }
+            }
+            writeln!(output, " {cycles}").expect("writing to a string should not fail");
+        }
+        output
+    }
+}
+
+// PROCEDURE PROFILE
+// ================================================================================================
+
+/// The cycles spent in a single procedure over the course of a transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcedureProfile {
+    /// The MAST root of the procedure.
+    pub root: Digest,
+    /// The fully qualified name of the procedure, if known.
+    pub name: Option<String>,
+    /// The number of times the procedure was invoked.
+    pub num_calls: usize,
+    /// The number of cycles spent in the procedure itself, excluding the procedures it invoked.
+    pub self_cycles: usize,
+    /// The number of cycles spent in the procedure, including the procedures it invoked.
+    pub total_cycles: usize,
+}
+
+// PROFILE FRAME
+// ================================================================================================
+
+/// A single frame of a call stack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum ProfileFrame {
+    Stage(TransactionStage),
+    Procedure(Digest),
+}
+
+/// A stage of the transaction, as delimited by the traces emitted by the transaction kernel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum TransactionStage {
+    Prologue,
+    NotesProcessing,
+    NoteExecution,
+    TxScriptProcessing,
+    Epilogue,
+}
+
+impl TransactionStage {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Prologue => "prologue",
+            Self::NotesProcessing => "notes_processing",
+            Self::NoteExecution => "note_execution",
+            Self::TxScriptProcessing => "tx_script_processing",
+            Self::Epilogue => "epilogue",
+        }
+    }
+}
+
+// CALL STACK TRACKER
+// ================================================================================================
+
+/// Reconstructs the call stack of the VM by following the control flow operations executed by the
+/// VM through the MAST of the executed program.
+struct CallStackTracker<'a, S: MastForestStore> {
+    program: Program,
+    mast_store: &'a S,
+    /// The MAST nodes which are currently being executed, from the outermost to the innermost.
+    blocks: Vec<Block>,
+    /// The current call stack.
+    frames: Vec<ProfileFrame>,
+    /// The number of cycles spent in the current call stack since it was last changed.
+    frame_cycles: usize,
+    stacks: BTreeMap<Vec<ProfileFrame>, usize>,
+    num_calls: BTreeMap<Digest, usize>,
+}
+
+/// A MAST node which is being executed.
+struct Block {
+    forest: Arc<MastForest>,
+    node_id: MastNodeId,
+    /// The children of the node which are still to be entered, in reverse order.
+    pending_children: Vec<Child>,
+    /// True if entering the node pushed a procedure frame onto the call stack.
+    is_frame: bool,
+}
+
+/// A reference to the child of a MAST node.
+enum Child {
+    /// A node in the same MAST forest as the parent.
+    Node(MastNodeId),
+    /// A procedure invoked dynamically, identified by its MAST root.
+    Dynamic(Digest),
+}
+
+impl<'a, S: MastForestStore> CallStackTracker<'a, S> {
+    fn new(program: Program, mast_store: &'a S) -> Self {
+        Self {
+            program,
+            mast_store,
+            blocks: Vec::new(),
+            frames: Vec::new(),
+            frame_cycles: 0,
+            stacks: BTreeMap::new(),
+            num_calls: BTreeMap::new(),
+        }
+    }
+
+    /// Accounts for the operation which transitioned the VM from `prev_state` to `state`.
+    fn execute_op(&mut self, prev_state: &VmState, state: &VmState) {
+        // the cycle is attributed to the call stack as it was before the operation was executed,
+        // and thus the operations starting and ending a procedure are attributed to its caller
+        self.frame_cycles += 1;
+
+        match state.op {
+            Some(
+                Operation::Join
+                | Operation::Split
+                | Operation::Loop
+                | Operation::Call
+                | Operation::SysCall
+                | Operation::Dyn
+                | Operation::Dyncall
+                | Operation::Span,
+            ) => self.enter_block(prev_state),
+            Some(Operation::Repeat) => {
+                let block = self.blocks.last_mut().expect("REPEAT should be executed in a loop");
+                let MastNode::Loop(loop_node) = &block.forest[block.node_id] else {
+                    panic!("REPEAT should be executed in a loop");
+                };
+                block.pending_children.push(Child::Node(loop_node.body()));
+            },
+            Some(Operation::End) => {
+                let block = self.blocks.pop().expect("END should close an open block");
+                if block.is_frame {
+                    self.pop_frame();
+                }
+            },
+            _ => (),
+        }
+    }
+
+    /// Updates the stage of the transaction according to the provided trace.
+    fn on_trace(&mut self, trace: TransactionTrace) {
+        let stage = match trace {
+            TransactionTrace::PrologueStart | TransactionTrace::PrologueEnd => {
+                TransactionStage::Prologue
+            },
+            TransactionTrace::NotesProcessingStart | TransactionTrace::NotesProcessingEnd => {
+                TransactionStage::NotesProcessing
+            },
+            TransactionTrace::NoteExecutionStart | TransactionTrace::NoteExecutionEnd => {
+                TransactionStage::NoteExecution
+            },
+            TransactionTrace::TxScriptProcessingStart | TransactionTrace::TxScriptProcessingEnd => {
+                TransactionStage::TxScriptProcessing
+            },
+            TransactionTrace::EpilogueStart | TransactionTrace::EpilogueEnd => {
+                TransactionStage::Epilogue
+            },
+        };
+
+        match trace {
+            TransactionTrace::PrologueStart
+            | TransactionTrace::NotesProcessingStart
+            | TransactionTrace::NoteExecutionStart
+            | TransactionTrace::TxScriptProcessingStart
+            | TransactionTrace::EpilogueStart => self.push_frame(ProfileFrame::Stage(stage)),
+            _ => {
+                if self.frames.last() == Some(&ProfileFrame::Stage(stage)) {
+                    self.pop_frame();
+                }
+            },
+        }
+    }
+
+    /// Consumes the tracker and returns the cycles spent in each call stack, together with the
+    /// number of times each procedure was invoked.
+    fn finish(mut self) -> (BTreeMap<Vec<ProfileFrame>, usize>, BTreeMap<Digest, usize>) {
+        self.flush_cycles();
+        (self.stacks, self.num_calls)
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Enters the next MAST node, which is either the entrypoint of the program or the next child
+    /// of the innermost block.
+    fn enter_block(&mut self, prev_state: &VmState) {
+        let (forest, node_id, is_frame) = match self.blocks.last_mut() {
+            None => (self.program.mast_forest().clone(), self.program.entrypoint(), false),
+            Some(parent) => {
+                let child =
+                    parent.pending_children.pop().expect("entered block should have a parent");
+                let is_frame =
+                    matches!(parent.forest[parent.node_id], MastNode::Call(_) | MastNode::Dyn(_));
+                let (forest, node_id) = resolve_child(self.mast_store, &parent.forest, child);
+                (forest, node_id, is_frame)
+            },
+        };
+
+        // the condition of a SPLIT or a LOOP, and the memory address of the callee of a dynamic
+        // call, are located at the top of the stack before the block is entered
+        let stack_top = prev_state.stack.first().copied().unwrap_or_default();
+        let pending_children = match &forest[node_id] {
+            MastNode::Join(node) => vec![Child::Node(node.second()), Child::Node(node.first())],
+            MastNode::Split(node) if stack_top == ONE => vec![Child::Node(node.on_true())],
+            MastNode::Split(node) => vec![Child::Node(node.on_false())],
+            MastNode::Loop(node) if stack_top == ONE => vec![Child::Node(node.body())],
+            MastNode::Call(node) => vec![Child::Node(node.callee())],
+            MastNode::Dyn(_) => vec![Child::Dynamic(read_word(prev_state, stack_top).into())],
+            _ => Vec::new(),
+        };
+
+        if is_frame {
+            let root = forest[node_id].digest();
+            *self.num_calls.entry(root).or_default() += 1;
+            self.push_frame(ProfileFrame::Procedure(root));
+        }
+
+        self.blocks.push(Block {
+            forest,
+            node_id,
+            pending_children,
+            is_frame,
+        });
+    }
+
+    fn push_frame(&mut self, frame: ProfileFrame) {
+        self.flush_cycles();
+        self.frames.push(frame);
+    }
+
+    fn pop_frame(&mut self) {
+        self.flush_cycles();
+        self.frames.pop();
+    }
+
+    /// Attributes the cycles spent since the call stack was last changed to the current stack.
+    fn flush_cycles(&mut self) {
+        if self.frame_cycles > 0 {
+            *self.stacks.entry(self.frames.clone()).or_default() += self.frame_cycles;
+            self.frame_cycles = 0;
+        }
+    }
+}
+
+/// Returns the MAST forest and the ID of the node the provided child refers to, resolving
+/// references to external procedures via the MAST store.
+fn resolve_child(
+    mast_store: &impl MastForestStore,
+    forest: &Arc<MastForest>,
+    child: Child,
+) -> (Arc<MastForest>, MastNodeId) {
+    let digest = match child {
+        Child::Node(node_id) => match &forest[node_id] {
+            MastNode::External(node) => node.digest(),
+            _ => return (forest.clone(), node_id),
+        },
+        Child::Dynamic(digest) => match forest.find_procedure_root(digest) {
+            Some(node_id) => return (forest.clone(), node_id),
+            None => digest,
+        },
+    };
+
+    let forest = mast_store
+        .get(&digest)
+        .expect("executed procedure should be present in the MAST store");
+    let node_id = forest
+        .find_procedure_root(digest)
+        .expect("executed procedure should be a procedure root");
+    (forest, node_id)
+}
+
+/// Returns the word stored at the specified address in the memory of the provided state.
+fn read_word(state: &VmState, addr: Felt) -> [Felt; 4] {
+    let addr = addr.as_int();
+    let mut word = [ZERO; 4];
+    for (mem_addr, value) in state.memory.iter() {
+        if (addr..addr + 4).contains(mem_addr) {
+            word[(*mem_addr - addr) as usize] = *value;
+        }
+    }
+    word
+}
@@ -2,8 +2,14 @@ use alloc::{boxed::Box, string::String};
 use core::error::Error;
 
 use miden_objects::{
-    account::AccountId, block::BlockNumber, note::NoteId, AccountError, Felt,
-    ProvenTransactionError, TransactionInputError, TransactionOutputError,
+    account::AccountId,
+    batch::BatchId,
+    block::BlockNumber,
+    crypto::merkle::MerkleError,
+    note::{NoteId, Nullifier},
+    transaction::TransactionId,
+    AccountError, Digest, Felt, ProposedBatchError, ProvenTransactionError, TransactionInputError,
+    TransactionOutputError, MAX_NUM_FOREIGN_ACCOUNTS,
 };
 use miden_verifier::VerificationError;
 use thiserror::Error;
@@ -18,6 +24,32 @@ pub enum TransactionExecutorError {
     TransactionProgramExecutionFailed(#[source] ExecutionError),
     #[error("failed to fetch transaction inputs from the data store")]
     FetchTransactionInputsFailed(#[source] DataStoreError),
+    #[error("failed to construct transaction inputs")]
+    InvalidTransactionInputs(#[source] TransactionInputError),
+    #[error("failed to fetch inputs of foreign account {0} from the data store")]
+    FetchForeignAccountInputsFailed(AccountId, #[source] DataStoreError),
+    #[error("data store returned inputs of account {actual} when inputs of foreign account {expected} were requested")]
+    ForeignAccountIdMismatch { expected: AccountId, actual: AccountId },
+    #[error("authentication path of foreign account {0} is invalid")]
+    ForeignAccountPathInvalid(AccountId, #[source] MerkleError),
+    #[error("foreign account {account_id} commits to account tree root {actual} but the reference block has account root {expected}")]
+    ForeignAccountRootMismatch {
+        account_id: AccountId,
+        expected: Digest,
+        actual: Digest,
+    },
+    #[error(
+        "transaction requires more than the maximum of {MAX_NUM_FOREIGN_ACCOUNTS} foreign accounts"
+    )]
+    TooManyForeignAccounts,
+    #[error("failed to fetch Merkle path to storage map entry or asset of account {0} from the data store")]
+    FetchAccountWitnessFailed(AccountId, #[source] DataStoreError),
+    #[error("Merkle path to key {key} of account {account_id} returned by the data store does not open to root {root}")]
+    AccountWitnessRootMismatch {
+        account_id: AccountId,
+        root: Digest,
+        key: Digest,
+    },
     #[error("input account ID {input_id} does not match output account ID {output_id}")]
     InconsistentAccountId {
         input_id: AccountId,
@@ -32,6 +64,8 @@ pub enum TransactionExecutorError {
     TransactionOutputConstructionFailed(#[source] TransactionOutputError),
     #[error("failed to create transaction host")]
     TransactionHostCreationFailed(#[source] TransactionHostError),
+    #[error("transaction against account {0} does not require any further signatures")]
+    NoSignatureRequested(AccountId),
 }
 
 // TRANSACTION PROVER ERROR
@@ -91,6 +125,66 @@ pub enum TransactionVerifierError {
         "transaction proof security level is {actual} but must be at least {expected_minimum}"
     )]
     InsufficientProofSecurityLevel { actual: u32, expected_minimum: u32 },
+    #[error(
+        "chain MMR root {actual} does not match the chain root {expected} of the latest block"
+    )]
+    ChainRootMismatch { expected: Digest, actual: Digest },
+    #[error("reference block {0} of the transaction is not part of the chain")]
+    ReferenceBlockNotFound(Digest),
+    #[error("transaction expired at block {expiration_block_num} and cannot be included in block {block_num}")]
+    TransactionExpired {
+        expiration_block_num: BlockNumber,
+        block_num: BlockNumber,
+    },
+    #[error("note with nullifier {nullifier} was already consumed in block {block_num}")]
+    NullifierAlreadySpent {
+        nullifier: Nullifier,
+        block_num: BlockNumber,
+    },
+    #[error("unauthenticated note {0} is not part of the chain")]
+    UnauthenticatedNoteNotFound(NoteId),
+    #[error(
+        "block {block_num} containing unauthenticated note {note_id} is not part of the chain MMR"
+    )]
+    NoteBlockNotFound { note_id: NoteId, block_num: BlockNumber },
+    #[error(
+        "inclusion proof of unauthenticated note {0} does not match the note root of its block"
+    )]
+    InvalidNoteInclusionProof(NoteId),
+    #[error("transaction updates the account from state {actual} but the current state of the account is {expected}")]
+    AccountStateMismatch { expected: Digest, actual: Digest },
+}
+
+// BATCH PROVER ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum BatchProverError {
+    #[error("failed to verify transaction {transaction_id} in the batch")]
+    TransactionVerificationFailed {
+        transaction_id: TransactionId,
+        source: TransactionVerifierError,
+    },
+}
+
+// BATCH VERIFIER ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum BatchVerifierError {
+    #[error("transactions do not form a valid batch")]
+    InvalidBatch(#[source] ProposedBatchError),
+    #[error(
+        "batch built from the transactions has id {actual} but the proven batch has id {expected}"
+    )]
+    BatchIdMismatch { expected: BatchId, actual: BatchId },
+    #[error("contents of batch {0} do not match the batch built from its transactions")]
+    BatchContentMismatch(BatchId),
+    #[error("failed to verify transaction {transaction_id} in the batch")]
+    TransactionVerificationFailed {
+        transaction_id: TransactionId,
+        source: TransactionVerifierError,
+    },
 }
 
 // TRANSACTION HOST ERROR
@@ -104,6 +198,53 @@ pub enum TransactionHostError {
     AccountProcedureInfoCreationFailed(#[source] AccountError),
 }
 
+// EVENT HANDLER ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum EventHandlerError {
+    #[error("event ID range {start}..={end} is empty")]
+    EmptyEventIdRange { start: u32, end: u32 },
+    #[error(
+        "event ID range {start}..={end} contains event IDs reserved for the transaction kernel"
+    )]
+    ReservedEventIds { start: u32, end: u32 },
+    #[error(
+        "event ID range {start}..={end} overlaps with the range of a registered event handler"
+    )]
+    OverlappingEventIds { start: u32, end: u32 },
+    /// Custom error variant for implementors of the
+    /// [`EventHandler`](crate::host::EventHandler) trait.
+    #[error("{error_msg}")]
+    Other {
+        error_msg: Box<str>,
+        // thiserror will return this when calling Error::source on EventHandlerError.
+        source: Option<Box<dyn Error + Send + Sync + 'static>>,
+    },
+}
+
+impl EventHandlerError {
+    /// Creates a custom error using the [`EventHandlerError::Other`] variant from an error
+    /// message.
+    pub fn other(message: impl Into<String>) -> Self {
+        let message: String = message.into();
+        Self::Other { error_msg: message.into(), source: None }
+    }
+
+    /// Creates a custom error using the [`EventHandlerError::Other`] variant from an error message
+    /// and a source error.
+    pub fn other_with_source(
+        message: impl Into<String>,
+        source: impl Error + Send + Sync + 'static,
+    ) -> Self {
+        let message: String = message.into();
+        Self::Other {
+            error_msg: message.into(),
+            source: Some(Box::new(source)),
+        }
+    }
+}
+
 // DATA STORE ERROR
 // ================================================================================================
 
@@ -159,6 +300,8 @@ pub enum AuthenticationError {
     RejectedSignature(String),
     #[error("unknown public key: {0}")]
     UnknownPublicKey(String),
+    #[error("message {actual} was not derived from the signing request, expected {expected}")]
+    MessageMismatch { expected: Digest, actual: Digest },
     /// Custom error variant for implementors of the
     /// [`TransactionAuthenticatior`](crate::auth::TransactionAuthenticator) trait.
     #[error("{error_msg}")]
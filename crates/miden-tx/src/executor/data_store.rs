@@ -1,8 +1,14 @@
 #[cfg(feature = "async")]
 use alloc::boxed::Box;
+use alloc::format;
 
 use miden_objects::{
-    account::AccountId, block::BlockNumber, note::NoteId, transaction::TransactionInputs,
+    account::AccountId,
+    block::BlockNumber,
+    crypto::merkle::SmtProof,
+    note::NoteId,
+    transaction::{ForeignAccountInputs, TransactionInputs},
+    Digest,
 };
 use winter_maybe_async::*;
 
@@ -37,4 +43,85 @@ pub trait DataStore {
         block_ref: BlockNumber,
         notes: &[NoteId],
     ) -> Result<TransactionInputs, DataStoreError>;
+
+    /// Returns the data required to load the foreign account with the specified ID during the
+    /// execution of a transaction which references the block with the specified number.
+    ///
+    /// The returned inputs must contain the authentication path of the account's state hash in
+    /// the account tree of the block with number `block_ref`.
+    ///
+    /// The default implementation returns an error, and so data stores which do not implement this
+    /// method only support transactions whose foreign accounts are provided via the advice inputs
+    /// of the transaction arguments.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The account with the specified ID could not be found in the data store.
+    /// - The block with the specified number could not be found in the data store.
+    /// - The data store encountered some internal error
+    #[maybe_async]
+    fn get_foreign_account_inputs(
+        &self,
+        foreign_account_id: AccountId,
+        block_ref: BlockNumber,
+    ) -> Result<ForeignAccountInputs, DataStoreError> {
+        let _ = block_ref;
+        Err(DataStoreError::other(format!(
+            "data store does not provide inputs of foreign account {foreign_account_id}"
+        )))
+    }
+
+    /// Returns the opening of the entry with the specified key in the storage map with the
+    /// specified root. The storage map belongs to the account with the specified ID.
+    ///
+    /// For the native account of a transaction, `map_root` is the root of the storage map in the
+    /// initial state of the account. The returned proof must open to this root.
+    ///
+    /// This is only used by executors with lazy loading enabled. The default implementation returns
+    /// an error.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The account with the specified ID could not be found in the data store.
+    /// - The account does not contain a storage map with the specified root.
+    /// - The data store encountered some internal error
+    #[maybe_async]
+    fn get_storage_map_witness(
+        &self,
+        account_id: AccountId,
+        map_root: Digest,
+        map_key: Digest,
+    ) -> Result<SmtProof, DataStoreError> {
+        let _ = map_key;
+        Err(DataStoreError::other(format!(
+            "data store does not provide entries of storage map {map_root} of account {account_id}"
+        )))
+    }
+
+    /// Returns the opening of the asset with the specified vault key in the asset vault with the
+    /// specified root. The asset vault belongs to the account with the specified ID.
+    ///
+    /// For the native account of a transaction, `vault_root` is the root of the asset vault in the
+    /// initial state of the account. The returned proof must open to this root.
+    ///
+    /// This is only used by executors with lazy loading enabled. The default implementation returns
+    /// an error.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The account with the specified ID could not be found in the data store.
+    /// - The asset vault of the account does not have the specified root.
+    /// - The data store encountered some internal error
+    #[maybe_async]
+    fn get_vault_asset_witness(
+        &self,
+        account_id: AccountId,
+        vault_root: Digest,
+        vault_key: Digest,
+    ) -> Result<SmtProof, DataStoreError> {
+        let _ = vault_key;
+        Err(DataStoreError::other(format!(
+            "data store does not provide assets of asset vault {vault_root} of account {account_id}"
+        )))
+    }
 }
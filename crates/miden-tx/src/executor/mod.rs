@@ -1,20 +1,37 @@
 use alloc::{collections::BTreeSet, sync::Arc, vec::Vec};
 
 use miden_lib::transaction::TransactionKernel;
+#[cfg(feature = "async")]
+use miden_objects::Hasher;
 use miden_objects::{
-    account::{AccountCode, AccountId},
+    account::{AccountCode, AccountDelta, AccountId},
     assembly::Library,
-    block::BlockNumber,
+    block::{BlockHeader, BlockNumber},
+    crypto::merkle::SmtProof,
     note::NoteId,
-    transaction::{ExecutedTransaction, TransactionArgs, TransactionInputs},
-    vm::StackOutputs,
-    MAX_TX_EXECUTION_CYCLES, MIN_TX_EXECUTION_CYCLES, ZERO,
+    transaction::{
+        ExecutedTransaction, ForeignAccountInputs, OutputNote, OutputNotes, TransactionArgs,
+        TransactionInputs,
+    },
+    vm::{AdviceInputs, StackOutputs},
+    Digest, MAX_NUM_FOREIGN_ACCOUNTS, MAX_TX_EXECUTION_CYCLES, MIN_TX_EXECUTION_CYCLES, ZERO,
+};
+use vm_processor::{
+    ExecutionError, ExecutionOptions, MemAdviceProvider, Process, RecAdviceProvider,
+    VmStateIterator,
 };
-use vm_processor::{ExecutionOptions, RecAdviceProvider};
 use winter_maybe_async::{maybe_async, maybe_await};
 
-use super::{TransactionExecutorError, TransactionHost};
-use crate::auth::TransactionAuthenticator;
+#[cfg(not(feature = "async"))]
+use super::host::TransactionDataResolver;
+use super::{
+    host::{EventHandlerRegistry, TransactionDataRequest},
+    TransactionExecutorError, TransactionHost,
+};
+use crate::{
+    auth::{PartiallySignedTransaction, SigningRequest, TransactionAuthenticator},
+    debugger::{DebugHost, TransactionDebugger, TransactionProfile},
+};
 
 mod data_store;
 pub use data_store::DataStore;
@@ -22,6 +39,33 @@ pub use data_store::DataStore;
 mod mast_store;
 pub use mast_store::TransactionMastStore;
 
+mod notes_checker;
+pub use notes_checker::{NoteConsumptionChecker, NoteConsumptionReport, NoteExecutionFailure};
+
+mod simulation;
+use simulation::with_placeholder_signatures;
+pub use simulation::SimulatedTransaction;
+
+/// The result of executing the transaction kernel program, together with the host used for the
+/// execution, the foreign accounts fetched from the [DataStore], and the advice inputs which were
+/// fetched by the host during the execution and are therefore not part of the advice witness
+/// recorded by the host.
+type TxProgramExecution = (
+    Result<StackOutputs, ExecutionError>,
+    TransactionHost<RecAdviceProvider>,
+    Vec<ForeignAccountInputs>,
+    AdviceInputs,
+);
+
+/// The result of executing the transaction kernel program without requesting signatures, together
+/// with the host used for the execution, and the public key and the message of the signature which
+/// was requested but not present in the advice inputs, if any.
+type PlaceholderSignedExecution = (
+    Result<StackOutputs, ExecutionError>,
+    TransactionHost<RecAdviceProvider>,
+    Option<(Digest, Digest)>,
+);
+
 // TRANSACTION EXECUTOR
 // ================================================================================================
 
@@ -30,7 +74,9 @@ pub use mast_store::TransactionMastStore;
 /// Transaction execution consists of the following steps:
 /// - Fetch the data required to execute a transaction from the [DataStore].
 /// - Load the code associated with the transaction into the [TransactionMastStore].
-/// - Execute the transaction program and create an [ExecutedTransaction].
+/// - Execute the transaction program and create an [ExecutedTransaction]. Foreign accounts
+///   accessed by the transaction are fetched from the [DataStore] as needed. In lazy loading mode,
+///   the same applies to the storage map entries and assets of the native account.
 ///
 /// The transaction executor uses dynamic dispatch with trait objects for the [DataStore] and
 /// [TransactionAuthenticator], allowing it to be used with different backend implementations.
@@ -42,6 +88,12 @@ pub struct TransactionExecutor {
     /// [Self::load_account_code()] method.
     account_codes: BTreeSet<AccountCode>,
     exec_options: ExecutionOptions,
+    /// If true, the storage maps and the asset vault of the native account are not loaded into
+    /// the advice provider up front, and the entries accessed by the transaction are fetched from
+    /// the [DataStore] instead.
+    lazy_loading: bool,
+    /// Handlers for events emitted by user-defined code during transaction execution.
+    event_handlers: EventHandlerRegistry,
 }
 
 impl TransactionExecutor {
@@ -68,6 +120,8 @@ impl TransactionExecutor {
             )
             .expect("Must not fail while max cycles is more than min trace length"),
             account_codes: BTreeSet::new(),
+            lazy_loading: false,
+            event_handlers: EventHandlerRegistry::default(),
         }
     }
 
@@ -91,6 +145,36 @@ impl TransactionExecutor {
         self
     }
 
+    /// Enables lazy loading of the native account data for the created instance of
+    /// [TransactionExecutor].
+    ///
+    /// When lazy loading is enabled, the storage maps and the asset vault of the native account, as
+    /// well as the storage maps of foreign accounts, are not loaded into the advice provider
+    /// before they are needed. Instead, the Merkle paths to the storage map entries and assets
+    /// accessed by the transaction are fetched from the [DataStore] via
+    /// [DataStore::get_storage_map_witness()] and [DataStore::get_vault_asset_witness()] as they
+    /// are requested by the transaction kernel. Thus, only the accessed leaves become part of the
+    /// transaction witness.
+    ///
+    /// The Merkle paths are fetched by the transaction host while the transaction is executing.
+    /// With the `async` feature enabled, the host cannot wait for the [DataStore], and so every
+    /// fetch requires the transaction to be re-executed instead.
+    pub fn with_lazy_loading(mut self) -> Self {
+        self.lazy_loading = true;
+        self
+    }
+
+    /// Sets the handlers for events emitted by user-defined code (e.g., note scripts or account
+    /// components) during transaction execution.
+    ///
+    /// Without a handler for its ID, any event which is not emitted by the transaction kernel
+    /// causes transaction execution to fail. The same handlers must be provided to the prover
+    /// when the transaction is proven.
+    pub fn with_event_handlers(mut self, event_handlers: EventHandlerRegistry) -> Self {
+        self.event_handlers = event_handlers;
+        self
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -122,9 +206,19 @@ impl TransactionExecutor {
     /// and compile the transaction into an executable program. Then, it executes the transaction
     /// program and creates an [ExecutedTransaction] object.
     ///
+    /// If the transaction invokes procedures of foreign accounts whose data was not provided via
+    /// the advice inputs of the [TransactionArgs], the data of these accounts is fetched from the
+    /// [DataStore] when it is first accessed and verified against the account root of the
+    /// reference block. At most [MAX_NUM_FOREIGN_ACCOUNTS] foreign accounts are fetched this way.
+    /// Similarly, missing Merkle paths to storage map entries and assets are fetched from the
+    /// [DataStore] and verified against the roots they were requested for.
+    ///
     /// # Errors:
     /// Returns an error if:
     /// - If required data can not be fetched from the [DataStore].
+    /// - If the data of a foreign account fetched from the [DataStore] is not consistent with the
+    ///   reference block of the transaction.
+    /// - If a Merkle path fetched from the [DataStore] does not open to the requested root.
     #[maybe_async]
     pub fn execute_transaction(
         &self,
@@ -137,30 +231,18 @@ impl TransactionExecutor {
             maybe_await!(self.data_store.get_transaction_inputs(account_id, block_ref, notes))
                 .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
 
-        let (stack_inputs, advice_inputs) =
-            TransactionKernel::prepare_inputs(&tx_inputs, &tx_args, None);
-        let advice_recorder: RecAdviceProvider = advice_inputs.into();
-
         // load note script MAST into the MAST store
         self.mast_store.load_transaction_code(&tx_inputs, &tx_args);
 
-        let mut host = TransactionHost::new(
-            tx_inputs.account().into(),
-            advice_recorder,
-            self.mast_store.clone(),
-            self.authenticator.clone(),
-            self.account_codes.iter().map(|code| code.commitment()).collect(),
-        )
-        .map_err(TransactionExecutorError::TransactionHostCreationFailed)?;
-
-        // execute the transaction kernel
-        let result = vm_processor::execute(
-            &TransactionKernel::main(),
-            stack_inputs,
-            &mut host,
-            self.exec_options,
-        )
-        .map_err(TransactionExecutorError::TransactionProgramExecutionFailed)?;
+        let (result, host, foreign_accounts, fetched_advice_inputs) = maybe_await!(self
+            .execute_tx_program(
+                &tx_inputs,
+                &tx_args,
+                self.authenticator.clone(),
+                self.exec_options,
+            ))?;
+        let stack_outputs =
+            result.map_err(TransactionExecutorError::TransactionProgramExecutionFailed)?;
 
         // Attempt to retrieve used account codes based on the advice map
         let account_codes = self
@@ -172,33 +254,702 @@ impl TransactionExecutor {
                     .mapped_values(&code.commitment())
                     .and(Some(code.clone()))
             })
+            .chain(foreign_accounts.into_iter().map(|account| account.account_code().clone()))
             .collect();
 
         build_executed_transaction(
             tx_args,
             tx_inputs,
-            result.stack_outputs().clone(),
+            stack_outputs,
             host,
+            fetched_advice_inputs,
             account_codes,
         )
     }
+
+    /// Executes the transaction specified by the provided arguments without requesting any
+    /// signatures, and returns a [SimulatedTransaction].
+    ///
+    /// Execution proceeds as in [Self::execute_transaction()], except that the authenticator of
+    /// this executor is never used. If the transaction requests a signature which is not present
+    /// in the advice inputs of the [TransactionArgs], the public key and the message to be signed
+    /// are recorded in the returned [SimulatedTransaction], and the transaction is executed once
+    /// more with placeholder signatures: the public keys stored in the account are replaced by the
+    /// public keys of placeholder key pairs, which sign the requested messages. Thus, the
+    /// signatures are verified and the epilogue is executed as they would be with the actual
+    /// signatures, and the measurements of the simulated transaction cover all its stages.
+    ///
+    /// For new accounts, whose IDs commit to their initial storage, the public keys cannot be
+    /// replaced. Their simulation stops at the signature request instead, and the returned
+    /// [SimulatedTransaction] describes the state of the transaction at that moment.
+    ///
+    /// This allows previewing the effects of a transaction before asking the user to sign it. Once
+    /// a signature for the message is obtained, it can be added to the advice map of the
+    /// [TransactionArgs] under `hash(PUB_KEY, MESSAGE)` to execute the transaction.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [DataStore].
+    /// - If the transaction program fails for any reason other than a missing signature, including
+    ///   a failure of the transaction executed with placeholder signatures.
+    #[maybe_async]
+    pub fn simulate_transaction(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+    ) -> Result<SimulatedTransaction, TransactionExecutorError> {
+        let tx_inputs =
+            maybe_await!(self.data_store.get_transaction_inputs(account_id, block_ref, notes))
+                .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
+
+        maybe_await!(self.simulate_tx_program(&tx_inputs, &tx_args))
+    }
+
+    /// Simulates the transaction specified by the provided arguments, and returns a
+    /// [PartiallySignedTransaction] which can be passed between several signers to collect their
+    /// signatures over the message of the transaction.
+    ///
+    /// The transaction is simulated as in [Self::simulate_transaction()]. Signatures which are
+    /// already present in the advice inputs of the [TransactionArgs] remain part of the returned
+    /// transaction's arguments.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - The simulation of the transaction fails.
+    /// - The transaction does not request a signature which is not present in the advice inputs
+    ///   of the [TransactionArgs].
+    #[maybe_async]
+    pub fn propose_transaction(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+    ) -> Result<PartiallySignedTransaction, TransactionExecutorError> {
+        let tx_inputs =
+            maybe_await!(self.data_store.get_transaction_inputs(account_id, block_ref, notes))
+                .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
+
+        let simulated_tx = maybe_await!(self.simulate_tx_program(&tx_inputs, &tx_args))?;
+
+        let (_, message) = simulated_tx
+            .pending_signature()
+            .ok_or(TransactionExecutorError::NoSignatureRequested(account_id))?;
+        let signing_request = build_signing_request(
+            &tx_inputs,
+            simulated_tx.account_delta().clone(),
+            simulated_tx.output_notes().to_vec(),
+        )?;
+
+        Ok(PartiallySignedTransaction::new(
+            block_ref,
+            notes.to_vec(),
+            tx_args,
+            simulated_tx,
+            message,
+            signing_request,
+        ))
+    }
+
+    /// Executes the transaction specified by the provided arguments and returns a
+    /// [TransactionDebugger] which allows stepping through the execution cycle by cycle.
+    ///
+    /// The transaction is first executed as in [Self::execute_transaction()], fetching any data
+    /// requested during execution from the [DataStore]. Then, it is executed once more in debug
+    /// mode against the recorded advice witness, and the states of the VM produced during this
+    /// execution are replayed by the debugger.
+    ///
+    /// A failing transaction does not cause this method to fail. Instead, replaying the
+    /// transaction via the debugger ends at the cycle at which the transaction failed.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [DataStore].
+    /// - If the transaction host cannot be created for the fetched data.
+    #[maybe_async]
+    pub fn debug_transaction(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+    ) -> Result<TransactionDebugger, TransactionExecutorError> {
+        let (states, host) =
+            maybe_await!(self.replay_transaction(account_id, block_ref, notes, tx_args))?;
+        let (_, trace_events) = host.into_parts();
+
+        Ok(TransactionDebugger::new(states, trace_events))
+    }
+
+    /// Executes the transaction specified by the provided arguments and returns a
+    /// [TransactionProfile] which attributes the cycles spent by the transaction to the invoked
+    /// account procedures, kernel procedures, note scripts and transaction script.
+    ///
+    /// The transaction is executed the same way as in [Self::debug_transaction()].
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [DataStore].
+    /// - If the transaction host cannot be created for the fetched data.
+    /// - If the transaction program fails.
+    #[maybe_async]
+    pub fn profile_transaction(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+    ) -> Result<TransactionProfile, TransactionExecutorError> {
+        let (states, host) =
+            maybe_await!(self.replay_transaction(account_id, block_ref, notes, tx_args))?;
+        let (host, trace_events) = host.into_parts();
+        let (.., tx_progress) = host.into_parts();
+
+        TransactionProfile::new(states, trace_events, self.mast_store.as_ref(), tx_progress.into())
+            .map_err(TransactionExecutorError::TransactionProgramExecutionFailed)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Executes the transaction kernel program for the provided transaction inputs and arguments
+    /// without requesting any signatures, and returns a [SimulatedTransaction].
+    ///
+    /// # Errors
+    /// Returns an error if the requested data could not be fetched or verified, or if the
+    /// transaction program fails for any reason other than a missing signature.
+    #[maybe_async]
+    fn simulate_tx_program(
+        &self,
+        tx_inputs: &TransactionInputs,
+        tx_args: &TransactionArgs,
+    ) -> Result<SimulatedTransaction, TransactionExecutorError> {
+        // load note script MAST into the MAST store
+        self.mast_store.load_transaction_code(tx_inputs, tx_args);
+
+        // tracing is always enabled so that cycle counts of the transaction stages are recorded
+        let (result, host, pending_signature) = maybe_await!(self
+            .execute_tx_program_with_placeholder_signatures(
+                tx_inputs,
+                tx_args,
+                self.exec_options.with_tracing(),
+            ))?;
+
+        // a transaction which could not be executed with placeholder signatures stops at the
+        // signature request
+        if let Err(err) = result {
+            if !matches!(host.data_request(), Some(TransactionDataRequest::Signature { .. })) {
+                return Err(TransactionExecutorError::TransactionProgramExecutionFailed(err));
+            }
+        }
+
+        let (_, account_delta, output_notes, tx_progress) = host.into_parts();
+
+        Ok(SimulatedTransaction::new(
+            tx_inputs.account().id(),
+            account_delta,
+            output_notes,
+            tx_progress.into(),
+            pending_signature,
+        ))
+    }
+
+    /// Executes the transaction kernel program for the provided transaction inputs and arguments
+    /// without requesting any signatures.
+    ///
+    /// If the transaction stops at a request for a signature which is not present in the advice
+    /// inputs, it is executed once more with placeholder signatures (see
+    /// [Self::simulate_transaction()]). The result of the last execution is returned together
+    /// with the host used for it, and the public key and the message of the missing signature, if
+    /// any.
+    ///
+    /// # Errors
+    /// Returns an error if the requested data could not be fetched or verified.
+    #[maybe_async]
+    fn execute_tx_program_with_placeholder_signatures(
+        &self,
+        tx_inputs: &TransactionInputs,
+        tx_args: &TransactionArgs,
+        exec_options: ExecutionOptions,
+    ) -> Result<PlaceholderSignedExecution, TransactionExecutorError> {
+        let (result, host, ..) =
+            maybe_await!(self.execute_tx_program(tx_inputs, tx_args, None, exec_options))?;
+
+        let pending_signature = match (&result, host.data_request()) {
+            (Err(_), Some(TransactionDataRequest::Signature { pub_key, message })) => {
+                (*pub_key, *message)
+            },
+            _ => return Ok((result, host, None)),
+        };
+
+        match with_placeholder_signatures(tx_inputs, tx_args, host.signature_requests()) {
+            Some((tx_inputs, tx_args)) => {
+                let (result, host, ..) = maybe_await!(self.execute_tx_program(
+                    &tx_inputs,
+                    &tx_args,
+                    None,
+                    exec_options
+                ))?;
+                Ok((result, host, Some(pending_signature)))
+            },
+            None => Ok((result, host, Some(pending_signature))),
+        }
+    }
+
+    /// Executes the transaction kernel program for the provided transaction inputs and arguments.
+    ///
+    /// Data requested by the transaction kernel which can be fetched from the [DataStore] (see
+    /// [TransactionDataRequest]) is fetched by the host from within its event handlers, so that the
+    /// execution continues without interruption. With the `async` feature enabled, the handlers of
+    /// the host cannot wait for the [DataStore], and so the execution is aborted instead, the data
+    /// is fetched and the program is re-executed.
+    ///
+    /// Signatures which are requested by the transaction but not present in the advice inputs are
+    /// requested from the provided authenticator, in the same way: by the host from within its
+    /// event handlers, or, with the `async` feature enabled, after the execution was aborted, in
+    /// which case the program is re-executed if at least one of them was obtained. Otherwise, the
+    /// result of the last execution is returned together with the host used for it, the foreign
+    /// accounts fetched from the [DataStore], and the advice inputs fetched by the host during the
+    /// last execution.
+    ///
+    /// # Errors
+    /// Returns an error if the requested data could not be fetched or verified.
+    #[maybe_async]
+    fn execute_tx_program(
+        &self,
+        tx_inputs: &TransactionInputs,
+        tx_args: &TransactionArgs,
+        authenticator: Option<Arc<dyn TransactionAuthenticator>>,
+        exec_options: ExecutionOptions,
+    ) -> Result<TxProgramExecution, TransactionExecutorError> {
+        // data fetched from the data store during execution
+        let mut data_requests: BTreeSet<TransactionDataRequest> = BTreeSet::new();
+        let mut foreign_accounts: Vec<ForeignAccountInputs> = Vec::new();
+        let mut witness_advice_inputs = AdviceInputs::default();
+        // signatures which were requested from the authenticator, as (pub_key, message) pairs
+        #[cfg(feature = "async")]
+        let mut requested_signatures: BTreeSet<(Digest, Digest)> = BTreeSet::new();
+
+        loop {
+            let mut fetched_advice_inputs = witness_advice_inputs.clone();
+            for foreign_account in foreign_accounts.iter() {
+                extend_advice_inputs_for_foreign_account(
+                    &mut fetched_advice_inputs,
+                    foreign_account,
+                    self.lazy_loading,
+                )?;
+            }
+
+            let (stack_inputs, advice_inputs) = if self.lazy_loading {
+                TransactionKernel::prepare_lazy_inputs(
+                    tx_inputs,
+                    tx_args,
+                    Some(fetched_advice_inputs),
+                )
+            } else {
+                TransactionKernel::prepare_inputs(tx_inputs, tx_args, Some(fetched_advice_inputs))
+            };
+            let advice_recorder: RecAdviceProvider = advice_inputs.into();
+
+            let account_code_commitments = self
+                .account_codes
+                .iter()
+                .map(|code| code.commitment())
+                .chain(foreign_accounts.iter().map(|account| account.account_code().commitment()))
+                .collect();
+
+            let mut host = TransactionHost::new(
+                tx_inputs.account().into(),
+                advice_recorder,
+                self.mast_store.clone(),
+                account_code_commitments,
+            )
+            .map_err(TransactionExecutorError::TransactionHostCreationFailed)?
+            .with_event_handlers(self.event_handlers.clone());
+            if let Some(code) = tx_args.new_account_code() {
+                host = host.with_new_account_code(code.clone());
+            }
+            #[cfg(not(feature = "async"))]
+            {
+                let mut data_resolver = TransactionDataResolver::new(
+                    self.data_store.clone(),
+                    tx_inputs,
+                    foreign_accounts.len(),
+                    self.lazy_loading,
+                );
+                if let Some(authenticator) = authenticator.as_ref() {
+                    data_resolver = data_resolver.with_authenticator(authenticator.clone());
+                }
+                host = host.with_data_resolver(data_resolver);
+            }
+
+            // execute the transaction kernel
+            let result = vm_processor::execute(
+                &TransactionKernel::main(),
+                stack_inputs,
+                &mut host,
+                exec_options,
+            );
+
+            // the data fetched by the host is kept for the subsequent executions, if any
+            #[cfg(not(feature = "async"))]
+            let fetched_advice_inputs = {
+                let data_resolver = host.take_data_resolver().expect("data resolver was set");
+                let (fetched_foreign_accounts, fetched_advice_inputs, error) =
+                    data_resolver.into_parts();
+                if let Some(err) = error {
+                    return Err(err);
+                }
+
+                foreign_accounts.extend(fetched_foreign_accounts);
+                witness_advice_inputs.extend(fetched_advice_inputs.clone());
+                fetched_advice_inputs
+            };
+            #[cfg(feature = "async")]
+            let fetched_advice_inputs = AdviceInputs::default();
+
+            let request = match (&result, host.data_request()) {
+                // if the requested data has already been fetched, re-executing would not help
+                (Err(_), Some(request)) if !data_requests.contains(request) => request.clone(),
+                _ => {
+                    let result = result.map(|trace| trace.stack_outputs().clone());
+                    return Ok((result, host, foreign_accounts, fetched_advice_inputs));
+                },
+            };
+
+            match &request {
+                TransactionDataRequest::ForeignAccount(foreign_account_id) => {
+                    let foreign_account = maybe_await!(self.fetch_foreign_account_inputs(
+                        *foreign_account_id,
+                        tx_inputs.block_header(),
+                        foreign_accounts.len(),
+                    ))?;
+                    foreign_accounts.push(foreign_account);
+                },
+                TransactionDataRequest::StorageMapWitness { account_id, map_root, map_key } => {
+                    let proof = maybe_await!(self.data_store.get_storage_map_witness(
+                        *account_id,
+                        *map_root,
+                        *map_key
+                    ))
+                    .map_err(|err| {
+                        TransactionExecutorError::FetchAccountWitnessFailed(*account_id, err)
+                    })?;
+                    add_smt_proof_to_advice_inputs(
+                        &mut witness_advice_inputs,
+                        *account_id,
+                        *map_root,
+                        *map_key,
+                        proof,
+                    )?;
+                },
+                TransactionDataRequest::VaultAssetWitness { account_id, vault_root, vault_key } => {
+                    let proof = maybe_await!(self.data_store.get_vault_asset_witness(
+                        *account_id,
+                        *vault_root,
+                        *vault_key
+                    ))
+                    .map_err(|err| {
+                        TransactionExecutorError::FetchAccountWitnessFailed(*account_id, err)
+                    })?;
+                    add_smt_proof_to_advice_inputs(
+                        &mut witness_advice_inputs,
+                        *account_id,
+                        *vault_root,
+                        *vault_key,
+                        proof,
+                    )?;
+                },
+                // without the `async` feature, the host already requested the missing signatures
+                // from the authenticator, and so re-executing would not help
+                #[cfg(not(feature = "async"))]
+                TransactionDataRequest::Signature { .. } => {
+                    let result = result.map(|trace| trace.stack_outputs().clone());
+                    return Ok((result, host, foreign_accounts, fetched_advice_inputs));
+                },
+                // signatures cannot be fetched from the data store, and so they are requested from
+                // the authenticator instead
+                #[cfg(feature = "async")]
+                TransactionDataRequest::Signature { .. } => {
+                    let new_requests: Vec<(Digest, Digest)> = host
+                        .signature_requests()
+                        .iter()
+                        .filter(|request| !requested_signatures.contains(request))
+                        .copied()
+                        .collect();
+
+                    let mut num_obtained = 0;
+                    if let Some(authenticator) = authenticator.as_ref() {
+                        let signing_request = build_signing_request(
+                            tx_inputs,
+                            host.account_delta(),
+                            host.output_notes(),
+                        )?;
+                        for (pub_key, message) in new_requests {
+                            requested_signatures.insert((pub_key, message));
+                            let signature = maybe_await!(authenticator.get_signature(
+                                pub_key.into(),
+                                message.into(),
+                                &signing_request
+                            ));
+
+                            // signatures which cannot be obtained are not necessarily required by
+                            // the transaction (e.g., for multisig accounts), and so a failure is
+                            // surfaced by the re-executed transaction program if needed
+                            if let Ok(signature) = signature {
+                                witness_advice_inputs
+                                    .extend_map([(Hasher::merge(&[pub_key, message]), signature)]);
+                                num_obtained += 1;
+                            }
+                        }
+                    }
+
+                    if num_obtained == 0 {
+                        let result = result.map(|trace| trace.stack_outputs().clone());
+                        return Ok((result, host, foreign_accounts, fetched_advice_inputs));
+                    }
+                },
+            }
+            data_requests.insert(request);
+        }
+    }
+
+    /// Executes the transaction specified by the provided arguments, and then executes it once more
+    /// in debug mode against the recorded advice witness, the same way the prover does.
+    ///
+    /// Both executions load account data lazily if lazy loading is enabled for this executor, and
+    /// use its execution options, with debug mode and tracing always enabled for the second one.
+    ///
+    /// Returns the states of the VM produced by the second execution, together with the host used
+    /// for it.
+    ///
+    /// # Errors
+    /// Returns an error if the required data could not be fetched, or if the transaction host
+    /// cannot be created for it.
+    #[maybe_async]
+    fn replay_transaction(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+    ) -> Result<
+        (VmStateIterator, DebugHost<TransactionHost<MemAdviceProvider>>),
+        TransactionExecutorError,
+    > {
+        let tx_inputs =
+            maybe_await!(self.data_store.get_transaction_inputs(account_id, block_ref, notes))
+                .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
+
+        // load note script MAST into the MAST store
+        self.mast_store.load_transaction_code(&tx_inputs, &tx_args);
+
+        let (_, host, foreign_accounts, fetched_advice_inputs) = maybe_await!(self
+            .execute_tx_program(
+                &tx_inputs,
+                &tx_args,
+                self.authenticator.clone(),
+                self.exec_options,
+            ))?;
+
+        let (advice_recorder, ..) = host.into_parts();
+        let (mut advice_witness, ..) = advice_recorder.finalize();
+        advice_witness.extend(fetched_advice_inputs);
+
+        // the witness contains all data fetched during execution, and so the account data which is
+        // loaded lazily is found in the advice provider
+        let (stack_inputs, advice_inputs) = if self.lazy_loading {
+            TransactionKernel::prepare_lazy_inputs(&tx_inputs, &tx_args, Some(advice_witness))
+        } else {
+            TransactionKernel::prepare_inputs(&tx_inputs, &tx_args, Some(advice_witness))
+        };
+        let advice_provider: MemAdviceProvider = advice_inputs.into();
+
+        let account_code_commitments = self
+            .account_codes
+            .iter()
+            .map(|code| code.commitment())
+            .chain(foreign_accounts.iter().map(|account| account.account_code().commitment()))
+            .collect();
+
+        let mut host = TransactionHost::new(
+            tx_inputs.account().into(),
+            advice_provider,
+            self.mast_store.clone(),
+            account_code_commitments,
+        )
+        .map_err(TransactionExecutorError::TransactionHostCreationFailed)?
+        .with_event_handlers(self.event_handlers.clone());
+        if let Some(code) = tx_args.new_account_code() {
+            host = host.with_new_account_code(code.clone());
+        }
+
+        // the states of the VM are only recorded in debug mode, which also requires tracing, and so
+        // both are enabled on top of the execution options of this executor
+        let program = TransactionKernel::main();
+        let mut process = Process::new(
+            program.kernel().clone(),
+            stack_inputs,
+            self.exec_options.with_tracing().with_debugging(),
+        );
+        let mut debug_host = DebugHost::new(host);
+        let result = process.execute(&program, &mut debug_host);
+
+        Ok((VmStateIterator::new(process, result), debug_host))
+    }
+
+    /// Fetches the inputs of the specified foreign account from the [DataStore], verifies them
+    /// against the account root of the provided reference block header, and loads the code of the
+    /// foreign account into the MAST store.
+    ///
+    /// `num_fetched` is the number of foreign accounts which have already been fetched for the
+    /// transaction being executed.
+    #[maybe_async]
+    fn fetch_foreign_account_inputs(
+        &self,
+        foreign_account_id: AccountId,
+        block_header: &BlockHeader,
+        num_fetched: usize,
+    ) -> Result<ForeignAccountInputs, TransactionExecutorError> {
+        if num_fetched >= MAX_NUM_FOREIGN_ACCOUNTS as usize {
+            return Err(TransactionExecutorError::TooManyForeignAccounts);
+        }
+
+        let foreign_account = maybe_await!(self
+            .data_store
+            .get_foreign_account_inputs(foreign_account_id, block_header.block_num()))
+        .map_err(|err| {
+            TransactionExecutorError::FetchForeignAccountInputsFailed(foreign_account_id, err)
+        })?;
+
+        verify_foreign_account_inputs(foreign_account_id, &foreign_account, block_header)?;
+
+        // load the code mast forest to the mast store
+        self.mast_store.load_account_code(foreign_account.account_code());
+
+        Ok(foreign_account)
+    }
 }
 
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Verifies that the provided inputs belong to the foreign account with the specified ID, and that
+/// they open to the account root of the provided reference block header.
+pub(crate) fn verify_foreign_account_inputs(
+    foreign_account_id: AccountId,
+    foreign_account: &ForeignAccountInputs,
+    block_header: &BlockHeader,
+) -> Result<(), TransactionExecutorError> {
+    if foreign_account.id() != foreign_account_id {
+        return Err(TransactionExecutorError::ForeignAccountIdMismatch {
+            expected: foreign_account_id,
+            actual: foreign_account.id(),
+        });
+    }
+
+    let account_root = foreign_account.compute_account_root().map_err(|err| {
+        TransactionExecutorError::ForeignAccountPathInvalid(foreign_account_id, err)
+    })?;
+    if account_root != block_header.account_root() {
+        return Err(TransactionExecutorError::ForeignAccountRootMismatch {
+            account_id: foreign_account_id,
+            expected: block_header.account_root(),
+            actual: account_root,
+        });
+    }
+
+    Ok(())
+}
+
+/// Extends the provided advice inputs with the data required to load the provided foreign account.
+///
+/// If `lazy_loading` is true, the storage maps of the account are not added, and so their entries
+/// are fetched from the [DataStore] as they are accessed.
+pub(crate) fn extend_advice_inputs_for_foreign_account(
+    advice_inputs: &mut AdviceInputs,
+    foreign_account: &ForeignAccountInputs,
+    lazy_loading: bool,
+) -> Result<(), TransactionExecutorError> {
+    let result = if lazy_loading {
+        TransactionKernel::extend_advice_inputs_for_account(
+            advice_inputs,
+            foreign_account.account_header(),
+            foreign_account.account_code(),
+            foreign_account.storage_header(),
+            foreign_account.account_path(),
+        )
+    } else {
+        TransactionKernel::extend_advice_inputs_for_foreign_account(advice_inputs, foreign_account)
+    };
+
+    result.map_err(|err| {
+        TransactionExecutorError::ForeignAccountPathInvalid(foreign_account.id(), err)
+    })
+}
+
+/// Verifies that the provided sparse Merkle tree proof opens the specified key to the specified
+/// root, and adds the Merkle path and the leaf of the proof to the provided advice inputs.
+pub(crate) fn add_smt_proof_to_advice_inputs(
+    advice_inputs: &mut AdviceInputs,
+    account_id: AccountId,
+    root: Digest,
+    key: Digest,
+    proof: SmtProof,
+) -> Result<(), TransactionExecutorError> {
+    if proof.get(&key).is_none() || proof.compute_root() != root {
+        return Err(TransactionExecutorError::AccountWitnessRootMismatch { account_id, root, key });
+    }
+
+    let (path, leaf) = proof.into_parts();
+    advice_inputs.extend_merkle_store(
+        path.inner_nodes(leaf.index().value(), leaf.hash())
+            .expect("sparse Merkle tree proof path should have the depth of the tree"),
+    );
+    if !leaf.is_empty() {
+        advice_inputs.extend_map([(leaf.hash(), leaf.to_elements())]);
+    }
+
+    Ok(())
+}
+
+/// Returns the [SigningRequest] presented to authenticators for a transaction with the provided
+/// inputs which made the provided changes to the account and created the provided notes.
+fn build_signing_request(
+    tx_inputs: &TransactionInputs,
+    account_delta: AccountDelta,
+    output_notes: Vec<OutputNote>,
+) -> Result<SigningRequest, TransactionExecutorError> {
+    let output_notes = OutputNotes::new(output_notes)
+        .map_err(TransactionExecutorError::TransactionOutputConstructionFailed)?;
+
+    Ok(SigningRequest::new(
+        tx_inputs.account().id(),
+        tx_inputs.account().nonce(),
+        account_delta,
+        tx_inputs.input_notes().clone(),
+        output_notes,
+    ))
+}
+
 /// Creates a new [ExecutedTransaction] from the provided data.
+///
+/// The advice inputs fetched by the host during the execution are added to the advice witness
+/// recorded by the host.
 fn build_executed_transaction(
     tx_args: TransactionArgs,
     tx_inputs: TransactionInputs,
     stack_outputs: StackOutputs,
     host: TransactionHost<RecAdviceProvider>,
+    fetched_advice_inputs: AdviceInputs,
     account_codes: Vec<AccountCode>,
 ) -> Result<ExecutedTransaction, TransactionExecutorError> {
-    let (advice_recorder, account_delta, output_notes, generated_signatures, tx_progress) =
-        host.into_parts();
+    let (advice_recorder, account_delta, output_notes, tx_progress) = host.into_parts();
 
     let (mut advice_witness, _, map, _store) = advice_recorder.finalize();
+    advice_witness.extend(fetched_advice_inputs);
 
     let tx_outputs =
         TransactionKernel::from_transaction_parts(&stack_outputs, &map.into(), output_notes)
@@ -231,9 +982,6 @@ fn build_executed_transaction(
         });
     }
 
-    // introduce generated signatures into the witness inputs
-    advice_witness.extend_map(generated_signatures);
-
     Ok(ExecutedTransaction::new(
         tx_inputs,
         tx_outputs,
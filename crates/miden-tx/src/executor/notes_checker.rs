@@ -0,0 +1,252 @@
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use miden_lib::errors::tx_kernel_errors::TX_KERNEL_ERRORS;
+use miden_objects::{
+    account::AccountId,
+    block::BlockNumber,
+    note::NoteId,
+    transaction::{InputNote, InputNotes, TransactionArgs, TransactionInputs},
+};
+use vm_processor::ExecutionError;
+use winter_maybe_async::{maybe_async, maybe_await};
+
+use super::{TransactionExecutor, TransactionExecutorError};
+use crate::host::TransactionDataRequest;
+
+// NOTE CONSUMPTION CHECKER
+// ================================================================================================
+
+/// Checks whether a set of notes can be consumed by an account.
+///
+/// The check is performed by executing transactions against the account via the wrapped
+/// [TransactionExecutor]: first, a transaction consuming each of the notes in isolation, and then a
+/// transaction consuming all of the notes together.
+///
+/// Transactions executed by the checker never request signatures. If a transaction requests a
+/// signature which is not present in the advice inputs of the [TransactionArgs], it is executed
+/// with placeholder signatures instead, the same way as in
+/// [TransactionExecutor::simulate_transaction()], so that the epilogue is checked as well. Thus,
+/// the transaction script provided via the [TransactionArgs] is expected to authenticate the
+/// transaction as it would when the notes are consumed; otherwise, the epilogue fails for any note
+/// that changes the account state.
+///
+/// Placeholder signatures cannot be used for new accounts. For these, a transaction which stops at
+/// a signature request is considered to have succeeded.
+pub struct NoteConsumptionChecker<'a>(&'a TransactionExecutor);
+
+impl<'a> NoteConsumptionChecker<'a> {
+    /// Creates a new [NoteConsumptionChecker] which executes transactions via the specified
+    /// [TransactionExecutor].
+    pub fn new(tx_executor: &'a TransactionExecutor) -> Self {
+        NoteConsumptionChecker(tx_executor)
+    }
+
+    /// Checks whether the provided input notes can be consumed by the specified account, and
+    /// returns a [NoteConsumptionReport] describing the outcome of consuming each note in
+    /// isolation as well as all notes together.
+    ///
+    /// The data required to execute the transactions is fetched from the
+    /// [DataStore](super::DataStore) of the executor, where only the IDs of authenticated notes
+    /// are passed as the notes to consume.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [DataStore](super::DataStore).
+    /// - If the transaction inputs for the provided notes are invalid (e.g., an authenticated note
+    ///   was not created in a block which is part of the fetched chain MMR).
+    #[maybe_async]
+    pub fn check_notes_consumability(
+        &self,
+        target_account_id: AccountId,
+        block_ref: BlockNumber,
+        input_notes: InputNotes<InputNote>,
+        tx_args: TransactionArgs,
+    ) -> Result<NoteConsumptionReport, TransactionExecutorError> {
+        let authenticated_note_ids: Vec<NoteId> = input_notes
+            .iter()
+            .filter(|note| note.proof().is_some())
+            .map(|note| note.id())
+            .collect();
+
+        let (account, account_seed, block_header, block_chain, _) = maybe_await!(self
+            .0
+            .data_store
+            .get_transaction_inputs(target_account_id, block_ref, &authenticated_note_ids))
+        .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?
+        .into_parts();
+
+        let tx_inputs =
+            TransactionInputs::new(account, account_seed, block_header, block_chain, input_notes)
+                .map_err(TransactionExecutorError::InvalidTransactionInputs)?;
+
+        // load note script MAST into the MAST store
+        self.0.mast_store.load_transaction_code(&tx_inputs, &tx_args);
+
+        // execute each note in isolation
+        let mut note_results = Vec::with_capacity(tx_inputs.input_notes().num_notes());
+        for note in tx_inputs.input_notes().iter() {
+            let note_inputs = InputNotes::new(vec![note.clone()])
+                .expect("a single input note should be valid input notes");
+            let (account, account_seed, block_header, block_chain, _) =
+                tx_inputs.clone().into_parts();
+            let note_tx_inputs = TransactionInputs::new(
+                account,
+                account_seed,
+                block_header,
+                block_chain,
+                note_inputs,
+            )
+            .map_err(TransactionExecutorError::InvalidTransactionInputs)?;
+
+            let failure = maybe_await!(self.execute_notes(&note_tx_inputs, &tx_args))?;
+            note_results.push((note.id(), failure));
+        }
+
+        // execute all notes together
+        let combined_failure = maybe_await!(self.execute_notes(&tx_inputs, &tx_args))?;
+
+        Ok(NoteConsumptionReport { note_results, combined_failure })
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Executes a transaction consuming the notes of the provided transaction inputs, and returns
+    /// the reason of the failure, or None if the transaction succeeded.
+    #[maybe_async]
+    fn execute_notes(
+        &self,
+        tx_inputs: &TransactionInputs,
+        tx_args: &TransactionArgs,
+    ) -> Result<Option<NoteExecutionFailure>, TransactionExecutorError> {
+        // tracing is enabled so that the note which was being executed on failure can be determined
+        let (result, host, _) =
+            maybe_await!(self.0.execute_tx_program_with_placeholder_signatures(
+                tx_inputs,
+                tx_args,
+                self.0.exec_options.with_tracing(),
+            ))?;
+
+        let err = match result {
+            Ok(_) => return Ok(None),
+            Err(err) => err,
+        };
+
+        // the transaction could not be executed with placeholder signatures, but would have
+        // proceeded if the requested signature was provided
+        if let Some(TransactionDataRequest::Signature { .. }) = host.data_request() {
+            return Ok(None);
+        }
+
+        // the note execution interval which has been started but not ended belongs to the note
+        // during whose execution the transaction failed
+        let note_id = host
+            .tx_progress()
+            .note_execution()
+            .last()
+            .filter(|(_, interval)| interval.end().is_none())
+            .map(|(note_id, _)| *note_id);
+
+        Ok(Some(NoteExecutionFailure::new(note_id, &err)))
+    }
+}
+
+// NOTE CONSUMPTION REPORT
+// ================================================================================================
+
+/// The outcome of checking whether a set of notes can be consumed by an account via
+/// [NoteConsumptionChecker::check_notes_consumability()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteConsumptionReport {
+    note_results: Vec<(NoteId, Option<NoteExecutionFailure>)>,
+    combined_failure: Option<NoteExecutionFailure>,
+}
+
+impl NoteConsumptionReport {
+    /// Returns an iterator over the IDs of the notes which can be consumed in isolation.
+    pub fn successful_notes(&self) -> impl Iterator<Item = NoteId> + '_ {
+        self.note_results
+            .iter()
+            .filter(|(_, failure)| failure.is_none())
+            .map(|(note_id, _)| *note_id)
+    }
+
+    /// Returns an iterator over the IDs of the notes which cannot be consumed in isolation,
+    /// together with the reason of the failure.
+    pub fn failed_notes(&self) -> impl Iterator<Item = (NoteId, &NoteExecutionFailure)> {
+        self.note_results
+            .iter()
+            .filter_map(|(note_id, failure)| failure.as_ref().map(|failure| (*note_id, failure)))
+    }
+
+    /// Returns the reason why the notes cannot be consumed together, or None if they can.
+    pub fn combined_failure(&self) -> Option<&NoteExecutionFailure> {
+        self.combined_failure.as_ref()
+    }
+
+    /// Returns true if all notes can be consumed together in a single transaction.
+    pub fn can_consume_all(&self) -> bool {
+        self.combined_failure.is_none()
+    }
+}
+
+// NOTE EXECUTION FAILURE
+// ================================================================================================
+
+/// Describes why a transaction consuming a set of notes failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteExecutionFailure {
+    note_id: Option<NoteId>,
+    err_code: Option<u32>,
+    err_msg: String,
+}
+
+impl NoteExecutionFailure {
+    /// Returns a new [NoteExecutionFailure] for a transaction which failed with the specified
+    /// error during the execution of the specified note.
+    fn new(note_id: Option<NoteId>, err: &ExecutionError) -> Self {
+        let err_code = match err {
+            ExecutionError::FailedAssertion { err_code, .. } => Some(*err_code),
+            _ => None,
+        };
+
+        Self {
+            note_id,
+            err_code,
+            err_msg: err.to_string(),
+        }
+    }
+
+    /// Returns the ID of the note during whose execution the transaction failed, or None if the
+    /// transaction failed outside of note execution (e.g., in the transaction script or in the
+    /// epilogue).
+    pub fn note_id(&self) -> Option<NoteId> {
+        self.note_id
+    }
+
+    /// Returns the error code of the failed assertion, or None if the transaction did not fail on
+    /// an assertion.
+    pub fn err_code(&self) -> Option<u32> {
+        self.err_code
+    }
+
+    /// Returns the message of the kernel error corresponding to the error code of the failed
+    /// assertion, or None if the error code is not one of the
+    /// [tx_kernel_errors](miden_lib::errors::tx_kernel_errors).
+    pub fn kernel_error_message(&self) -> Option<&'static str> {
+        let err_code = self.err_code?;
+        TX_KERNEL_ERRORS
+            .iter()
+            .find(|(code, _)| *code == err_code)
+            .map(|(_, message)| *message)
+    }
+
+    /// Returns the description of the execution error which caused the transaction to fail.
+    pub fn err_msg(&self) -> &str {
+        &self.err_msg
+    }
+}
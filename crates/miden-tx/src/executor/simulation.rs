@@ -0,0 +1,237 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use miden_objects::{
+    account::{Account, AccountDelta, AccountId, AccountStorage, StorageMap, StorageSlot},
+    crypto::{dsa::rpo_falcon512::SecretKey, rand::RpoRandomCoin},
+    transaction::{OutputNote, TransactionArgs, TransactionInputs, TransactionMeasurements},
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    Digest, Hasher, Word,
+};
+
+use crate::auth::signatures::get_falcon_signature;
+
+// SIMULATED TRANSACTION
+// ================================================================================================
+
+/// Describes the result of a transaction simulation performed via
+/// [TransactionExecutor::simulate_transaction()](super::TransactionExecutor::simulate_transaction).
+///
+/// A simulation never requests signatures. If the transaction requires a signature which was not
+/// provided via the transaction arguments, the public key and the message which need to be signed
+/// are available via [Self::pending_signature()], and the transaction is simulated to completion
+/// with placeholder signatures instead (see
+/// [TransactionExecutor::simulate_transaction()](super::TransactionExecutor::simulate_transaction)).
+///
+/// Placeholder signatures cannot be used for new accounts, whose IDs commit to their initial
+/// storage. For these, the simulation stops at the point the signature was requested, the account
+/// delta and output notes reflect the state of the transaction at that point, and measurements of
+/// the transaction stages which were not completed (e.g., the epilogue) are set to 0.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulatedTransaction {
+    account_id: AccountId,
+    account_delta: AccountDelta,
+    output_notes: Vec<OutputNote>,
+    measurements: TransactionMeasurements,
+    pending_signature: Option<(Digest, Digest)>,
+}
+
+impl SimulatedTransaction {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [SimulatedTransaction] instantiated from the provided data.
+    pub fn new(
+        account_id: AccountId,
+        account_delta: AccountDelta,
+        output_notes: Vec<OutputNote>,
+        measurements: TransactionMeasurements,
+        pending_signature: Option<(Digest, Digest)>,
+    ) -> Self {
+        Self {
+            account_id,
+            account_delta,
+            output_notes,
+            measurements,
+            pending_signature,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the account against which the transaction was simulated.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the changes made to the account by the simulated transaction.
+    pub fn account_delta(&self) -> &AccountDelta {
+        &self.account_delta
+    }
+
+    /// Returns the notes created by the simulated transaction.
+    pub fn output_notes(&self) -> &[OutputNote] {
+        &self.output_notes
+    }
+
+    /// Returns the number of cycles spent in each stage of the simulated transaction.
+    pub fn measurements(&self) -> &TransactionMeasurements {
+        &self.measurements
+    }
+
+    /// Returns the public key and the message of the signature which was requested by the
+    /// transaction, or None if the transaction was executed to completion.
+    pub fn pending_signature(&self) -> Option<(Digest, Digest)> {
+        self.pending_signature
+    }
+
+    /// Returns true if the transaction does not require any signature which was not provided via
+    /// the transaction arguments.
+    pub fn is_complete(&self) -> bool {
+        self.pending_signature.is_none()
+    }
+}
+
+// PLACEHOLDER SIGNATURES
+// ================================================================================================
+
+/// Returns the transaction inputs and arguments with which the transaction can be executed
+/// without the missing signatures requested by the provided `(PUB_KEY, MESSAGE)` pairs.
+///
+/// Every public key of the native account which signs one of the messages is replaced in the
+/// account storage (in value slots as well as in storage maps) by the public key of a placeholder
+/// key pair, and the signatures over the messages by the placeholder keys are added to the advice
+/// map of the transaction arguments. Since the messages signed by the authentication components do
+/// not commit to the public keys, the transaction is executed the same way as with the actual
+/// signatures, including the verification of the signatures and the epilogue.
+///
+/// Returns None if the account is new, as the ID of a new account commits to its initial storage,
+/// or if none of the public keys is stored in the account storage.
+pub(super) fn with_placeholder_signatures(
+    tx_inputs: &TransactionInputs,
+    tx_args: &TransactionArgs,
+    signature_requests: &[(Digest, Digest)],
+) -> Option<(TransactionInputs, TransactionArgs)> {
+    if tx_inputs.account().is_new() {
+        return None;
+    }
+
+    // the placeholder keys are derived from the public keys they replace, so that simulating the
+    // same transaction twice yields the same results
+    let placeholder_keys: BTreeMap<Digest, (SecretKey, Word)> = signature_requests
+        .iter()
+        .map(|(pub_key, _)| {
+            let mut rng = RpoRandomCoin::new((*pub_key).into());
+            let secret_key = SecretKey::with_rng(&mut rng);
+            let placeholder_pub_key = Word::from(secret_key.public_key());
+            (*pub_key, (secret_key, placeholder_pub_key))
+        })
+        .collect();
+    let placeholder_pub_key =
+        |value: &Word| placeholder_keys.get(&Digest::from(*value)).map(|(_, pub_key)| *pub_key);
+
+    let mut tx_args = tx_args.clone();
+    let mut num_replaced_keys = 0;
+    let slots = tx_inputs
+        .account()
+        .storage()
+        .slots()
+        .iter()
+        .map(|slot| match slot {
+            StorageSlot::Value(value) => match placeholder_pub_key(value) {
+                Some(pub_key) => {
+                    num_replaced_keys += 1;
+                    StorageSlot::Value(pub_key)
+                },
+                None => slot.clone(),
+            },
+            StorageSlot::Map(map) => {
+                let mut replaced_keys = false;
+                let entries = map.entries().map(|(key, value)| match placeholder_pub_key(value) {
+                    Some(pub_key) => {
+                        replaced_keys = true;
+                        (*key, pub_key)
+                    },
+                    None => (*key, *value),
+                });
+                let map = StorageMap::with_entries(entries);
+                if !replaced_keys {
+                    return slot.clone();
+                }
+
+                // the updated map cannot be fetched from the data store, and so it is provided via
+                // the advice inputs in case the executor loads account data lazily
+                num_replaced_keys += 1;
+                tx_args.extend_merkle_store(map.inner_nodes());
+                tx_args.extend_advice_map(
+                    map.leaves().map(|(_, leaf)| (leaf.hash(), leaf.to_elements())),
+                );
+                StorageSlot::Map(map)
+            },
+        })
+        .collect();
+    if num_replaced_keys == 0 {
+        return None;
+    }
+
+    for (pub_key, message) in signature_requests {
+        let (secret_key, placeholder_pub_key) = &placeholder_keys[pub_key];
+        let mut rng = RpoRandomCoin::new((*message).into());
+        let signature = get_falcon_signature(secret_key, (*message).into(), &mut rng).ok()?;
+        let signature_key = Hasher::merge(&[(*placeholder_pub_key).into(), *message]);
+        tx_args.extend_advice_map([(signature_key, signature)]);
+    }
+
+    let (account, account_seed, block_header, block_chain, input_notes) =
+        tx_inputs.clone().into_parts();
+    let (id, vault, _, code, nonce) = account.into_parts();
+    let storage = AccountStorage::new(slots).ok()?;
+    let account = Account::from_parts(id, vault, storage, code, nonce);
+    let tx_inputs =
+        TransactionInputs::new(account, account_seed, block_header, block_chain, input_notes)
+            .ok()?;
+
+    Some((tx_inputs, tx_args))
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for SimulatedTransaction {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account_id.write_into(target);
+        self.account_delta.write_into(target);
+        self.output_notes.write_into(target);
+        self.measurements.write_into(target);
+        match self.pending_signature {
+            Some((pub_key, message)) => {
+                target.write_bool(true);
+                pub_key.write_into(target);
+                message.write_into(target);
+            },
+            None => target.write_bool(false),
+        }
+    }
+}
+
+impl Deserializable for SimulatedTransaction {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account_id = AccountId::read_from(source)?;
+        let account_delta = AccountDelta::read_from(source)?;
+        let output_notes = Vec::<OutputNote>::read_from(source)?;
+        let measurements = TransactionMeasurements::read_from(source)?;
+        let pending_signature = if source.read_bool()? {
+            Some((Digest::read_from(source)?, Digest::read_from(source)?))
+        } else {
+            None
+        };
+
+        Ok(Self::new(
+            account_id,
+            account_delta,
+            output_notes,
+            measurements,
+            pending_signature,
+        ))
+    }
+}
@@ -1,5 +1,5 @@
 use miden_objects::{
-    account::{AccountDelta, AccountHeader, AccountStorageDelta, AccountVaultDelta},
+    account::{AccountCode, AccountDelta, AccountHeader, AccountStorageDelta, AccountVaultDelta},
     Felt, ZERO,
 };
 // ACCOUNT DELTA TRACKER
@@ -11,16 +11,17 @@ use miden_objects::{
 /// - Changes to the account storage, slots and maps.
 /// - Changes to the account vault.
 /// - Changes to the account nonce.
+/// - Changes to the account code.
 ///
 /// TODO: implement tracking of:
 /// - all account storage changes.
-/// - account code changes.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AccountDeltaTracker {
     storage: AccountStorageDelta,
     vault: AccountVaultDelta,
     init_nonce: Felt,
     nonce_delta: Felt,
+    code: Option<AccountCode>,
 }
 
 impl AccountDeltaTracker {
@@ -31,6 +32,7 @@ impl AccountDeltaTracker {
             vault: AccountVaultDelta::default(),
             init_nonce: account.nonce(),
             nonce_delta: ZERO,
+            code: None,
         }
     }
 
@@ -38,7 +40,12 @@ impl AccountDeltaTracker {
     pub fn into_delta(self) -> AccountDelta {
         let nonce_delta = (self.nonce_delta != ZERO).then_some(self.init_nonce + self.nonce_delta);
 
-        AccountDelta::new(self.storage, self.vault, nonce_delta).expect("invalid account delta")
+        let delta = AccountDelta::new(self.storage, self.vault, nonce_delta)
+            .expect("invalid account delta");
+        match self.code {
+            Some(code) => delta.with_code(code).expect("invalid account delta"),
+            None => delta,
+        }
     }
 
     /// Tracks nonce delta.
@@ -46,6 +53,13 @@ impl AccountDeltaTracker {
         self.nonce_delta += value;
     }
 
+    /// Tracks the new code of the account, replacing any previously set code.
+    ///
+    /// `None` indicates that the account code was set back to the initial code of the account.
+    pub fn set_code(&mut self, code: Option<AccountCode>) {
+        self.code = code;
+    }
+
     /// Get a mutable reference to the current vault delta
     pub fn vault_delta(&mut self) -> &mut AccountVaultDelta {
         &mut self.vault
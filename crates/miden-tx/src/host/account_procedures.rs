@@ -36,6 +36,22 @@ impl AccountProcedureIndexMap {
         Ok(Self(result))
     }
 
+    /// Adds the procedures of the provided account code to this map, replacing the procedures
+    /// previously added for the same code commitment.
+    pub fn insert_code(&mut self, code: &AccountCode) {
+        let account_procs_map = code
+            .procedures()
+            .iter()
+            .enumerate()
+            .map(|(proc_idx, procedure)| {
+                let proc_idx = u8::try_from(proc_idx).expect("Invalid procedure index.");
+                (*procedure.mast_root(), proc_idx)
+            })
+            .collect();
+
+        self.0.insert(code.commitment(), account_procs_map);
+    }
+
     /// Returns index of the procedure whose root is currently at the top of the operand stack in
     /// the provided process.
     ///
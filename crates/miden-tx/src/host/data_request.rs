@@ -0,0 +1,45 @@
+use miden_objects::{account::AccountId, Digest};
+
+// TRANSACTION DATA REQUEST
+// ================================================================================================
+
+/// Data which was requested by the transaction kernel during transaction execution, but which was
+/// not present in the advice provider of the [TransactionHost](super::TransactionHost).
+///
+/// When such a request is recorded by the host, transaction execution is aborted. The caller is
+/// expected to fetch the requested data, add it to the advice inputs, and re-execute the
+/// transaction.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TransactionDataRequest {
+    /// Inputs of the foreign account with the specified ID.
+    ForeignAccount(AccountId),
+
+    /// Merkle path to the entry with key `map_key` in the storage map with root `map_root`. The
+    /// storage map belongs to the account with ID `account_id`.
+    ///
+    /// For the native account, `map_root` is the root of the map at the beginning of the
+    /// transaction.
+    StorageMapWitness {
+        account_id: AccountId,
+        map_root: Digest,
+        map_key: Digest,
+    },
+
+    /// Merkle path to the asset with key `vault_key` in the asset vault with root `vault_root`.
+    /// The asset vault belongs to the account with ID `account_id`.
+    ///
+    /// For the native account, `vault_root` is the root of the vault at the beginning of the
+    /// transaction.
+    VaultAssetWitness {
+        account_id: AccountId,
+        vault_root: Digest,
+        vault_key: Digest,
+    },
+
+    /// Signature over `message` by the private key corresponding to `pub_key`.
+    ///
+    /// Signatures cannot be fetched from the [DataStore](crate::DataStore). Instead, they are
+    /// requested from the [TransactionAuthenticator](crate::auth::TransactionAuthenticator) of the
+    /// executor, if any.
+    Signature { pub_key: Digest, message: Digest },
+}
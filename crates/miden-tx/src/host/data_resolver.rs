@@ -0,0 +1,280 @@
+use alloc::{sync::Arc, vec::Vec};
+
+use miden_objects::{
+    account::{AccountDelta, AccountId},
+    block::BlockHeader,
+    crypto::merkle::SmtProof,
+    transaction::{
+        ForeignAccountInputs, InputNote, InputNotes, OutputNote, OutputNotes, TransactionInputs,
+    },
+    vm::AdviceInputs,
+    Digest, Felt, Hasher, MAX_NUM_FOREIGN_ACCOUNTS,
+};
+
+use crate::{
+    auth::{SigningRequest, TransactionAuthenticator},
+    executor::{
+        add_smt_proof_to_advice_inputs, extend_advice_inputs_for_foreign_account,
+        verify_foreign_account_inputs,
+    },
+    DataStore, TransactionExecutorError,
+};
+
+// TRANSACTION DATA RESOLVER
+// ================================================================================================
+
+/// Resolves data which the transaction kernel requests during transaction execution, but which is
+/// not present in the advice provider of the [TransactionHost](super::TransactionHost), by
+/// fetching it from a [DataStore], or by requesting signatures from a [TransactionAuthenticator].
+///
+/// The data is fetched and verified from within the event handlers of the host, and so the
+/// execution of the transaction continues without interruption. Since the handlers of the host are
+/// synchronous, the resolver is only available when the `async` feature is disabled.
+pub(crate) struct TransactionDataResolver {
+    data_store: Arc<dyn DataStore>,
+    /// The authenticator from which requested signatures are obtained, if any.
+    authenticator: Option<Arc<dyn TransactionAuthenticator>>,
+    /// The header of the reference block of the transaction.
+    block_header: BlockHeader,
+    /// The ID of the native account of the transaction.
+    account_id: AccountId,
+    /// The nonce of the native account at the beginning of the transaction.
+    nonce: Felt,
+    /// The notes consumed by the transaction.
+    input_notes: InputNotes<InputNote>,
+    /// If true, the storage maps of foreign accounts are not loaded together with the accounts,
+    /// and their entries are fetched as they are accessed instead.
+    lazy_loading: bool,
+    /// The number of foreign accounts which were loaded before the execution started.
+    num_loaded_foreign_accounts: usize,
+    /// The foreign accounts fetched during execution.
+    foreign_accounts: Vec<ForeignAccountInputs>,
+    /// The advice inputs added to the advice provider during execution.
+    advice_inputs: AdviceInputs,
+    /// The first error encountered while fetching or verifying data.
+    error: Option<TransactionExecutorError>,
+}
+
+impl TransactionDataResolver {
+    /// Returns a new [TransactionDataResolver] which fetches data for a transaction with the
+    /// provided inputs from the provided [DataStore].
+    ///
+    /// `num_loaded_foreign_accounts` is the number of foreign accounts whose data is already
+    /// present in the advice inputs of the transaction. If `lazy_loading` is true, the storage
+    /// maps of foreign accounts are not loaded together with the accounts.
+    pub fn new(
+        data_store: Arc<dyn DataStore>,
+        tx_inputs: &TransactionInputs,
+        num_loaded_foreign_accounts: usize,
+        lazy_loading: bool,
+    ) -> Self {
+        Self {
+            data_store,
+            authenticator: None,
+            block_header: *tx_inputs.block_header(),
+            account_id: tx_inputs.account().id(),
+            nonce: tx_inputs.account().nonce(),
+            input_notes: tx_inputs.input_notes().clone(),
+            lazy_loading,
+            num_loaded_foreign_accounts,
+            foreign_accounts: Vec::new(),
+            advice_inputs: AdviceInputs::default(),
+            error: None,
+        }
+    }
+
+    /// Sets the authenticator from which the signatures requested by the transaction are obtained.
+    ///
+    /// Without an authenticator, no signatures are resolved.
+    pub fn with_authenticator(mut self, authenticator: Arc<dyn TransactionAuthenticator>) -> Self {
+        self.authenticator = Some(authenticator);
+        self
+    }
+
+    /// Returns true if this resolver can obtain signatures, i.e., if it has an authenticator.
+    pub fn has_authenticator(&self) -> bool {
+        self.authenticator.is_some()
+    }
+
+    /// Consumes `self` and returns the foreign accounts fetched during execution, the advice
+    /// inputs added to the advice provider during execution, and the error encountered while
+    /// fetching or verifying data, if any.
+    pub fn into_parts(
+        self,
+    ) -> (Vec<ForeignAccountInputs>, AdviceInputs, Option<TransactionExecutorError>) {
+        (self.foreign_accounts, self.advice_inputs, self.error)
+    }
+
+    /// Fetches the inputs of the specified foreign account from the [DataStore], verifies them
+    /// against the account root of the reference block, and returns them together with the advice
+    /// inputs required to load the account.
+    ///
+    /// Returns `None` if the inputs could not be fetched or verified. The error is then recorded
+    /// and returned by [Self::into_parts()].
+    pub fn resolve_foreign_account(
+        &mut self,
+        foreign_account_id: AccountId,
+    ) -> Option<(&ForeignAccountInputs, AdviceInputs)> {
+        let (foreign_account, advice_inputs) = self
+            .fetch_foreign_account(foreign_account_id)
+            .map_err(|err| self.record_error(err))
+            .ok()?;
+
+        self.advice_inputs.extend(advice_inputs.clone());
+        self.foreign_accounts.push(foreign_account);
+        let foreign_account = self.foreign_accounts.last().expect("foreign account was just added");
+
+        Some((foreign_account, advice_inputs))
+    }
+
+    /// Fetches the opening of the specified key in the storage map with the specified root of the
+    /// specified account from the [DataStore], verifies it against the root, and returns the
+    /// advice inputs containing its Merkle path and leaf.
+    ///
+    /// Returns `None` if the opening could not be fetched or verified. The error is then recorded
+    /// and returned by [Self::into_parts()].
+    pub fn resolve_storage_map_witness(
+        &mut self,
+        account_id: AccountId,
+        map_root: Digest,
+        map_key: Digest,
+    ) -> Option<AdviceInputs> {
+        let result = self
+            .data_store
+            .get_storage_map_witness(account_id, map_root, map_key)
+            .map_err(|err| TransactionExecutorError::FetchAccountWitnessFailed(account_id, err))
+            .and_then(|proof| build_smt_proof_advice_inputs(account_id, map_root, map_key, proof));
+
+        self.record(result)
+    }
+
+    /// Fetches the opening of the asset with the specified vault key in the asset vault with the
+    /// specified root of the specified account from the [DataStore], verifies it against the root,
+    /// and returns the advice inputs containing its Merkle path and leaf.
+    ///
+    /// Returns `None` if the opening could not be fetched or verified. The error is then recorded
+    /// and returned by [Self::into_parts()].
+    pub fn resolve_vault_asset_witness(
+        &mut self,
+        account_id: AccountId,
+        vault_root: Digest,
+        vault_key: Digest,
+    ) -> Option<AdviceInputs> {
+        let result = self
+            .data_store
+            .get_vault_asset_witness(account_id, vault_root, vault_key)
+            .map_err(|err| TransactionExecutorError::FetchAccountWitnessFailed(account_id, err))
+            .and_then(|proof| {
+                build_smt_proof_advice_inputs(account_id, vault_root, vault_key, proof)
+            });
+
+        self.record(result)
+    }
+
+    /// Requests a signature over the specified message by the signer with the specified public key
+    /// from the authenticator of this resolver, and returns it.
+    ///
+    /// The authenticator is presented with a [SigningRequest] describing the transaction with the
+    /// provided account delta and output notes. The signature is also added to the advice map of
+    /// the recorded advice inputs under `hash(PUB_KEY, MESSAGE)`.
+    ///
+    /// Returns `None` if there is no authenticator or the authenticator did not provide the
+    /// signature. Signatures are not necessarily required by the transaction (e.g., for multisig
+    /// accounts), and so a failure to obtain one is surfaced by the transaction program if needed.
+    pub fn resolve_signature(
+        &mut self,
+        pub_key: Digest,
+        message: Digest,
+        account_delta: AccountDelta,
+        output_notes: Vec<OutputNote>,
+    ) -> Option<Vec<Felt>> {
+        let authenticator = self.authenticator.clone()?;
+        let output_notes = OutputNotes::new(output_notes)
+            .map_err(|err| {
+                self.record_error(TransactionExecutorError::TransactionOutputConstructionFailed(
+                    err,
+                ))
+            })
+            .ok()?;
+
+        let signing_request = SigningRequest::new(
+            self.account_id,
+            self.nonce,
+            account_delta,
+            self.input_notes.clone(),
+            output_notes,
+        );
+        let signature = authenticator
+            .get_signature(pub_key.into(), message.into(), &signing_request)
+            .ok()?;
+        self.advice_inputs
+            .extend_map([(Hasher::merge(&[pub_key, message]), signature.clone())]);
+
+        Some(signature)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Fetches and verifies the inputs of the specified foreign account, and builds the advice
+    /// inputs required to load it.
+    fn fetch_foreign_account(
+        &self,
+        foreign_account_id: AccountId,
+    ) -> Result<(ForeignAccountInputs, AdviceInputs), TransactionExecutorError> {
+        let num_foreign_accounts = self.num_loaded_foreign_accounts + self.foreign_accounts.len();
+        if num_foreign_accounts >= MAX_NUM_FOREIGN_ACCOUNTS as usize {
+            return Err(TransactionExecutorError::TooManyForeignAccounts);
+        }
+
+        let foreign_account = self
+            .data_store
+            .get_foreign_account_inputs(foreign_account_id, self.block_header.block_num())
+            .map_err(|err| {
+                TransactionExecutorError::FetchForeignAccountInputsFailed(foreign_account_id, err)
+            })?;
+        verify_foreign_account_inputs(foreign_account_id, &foreign_account, &self.block_header)?;
+
+        let mut advice_inputs = AdviceInputs::default();
+        extend_advice_inputs_for_foreign_account(
+            &mut advice_inputs,
+            &foreign_account,
+            self.lazy_loading,
+        )?;
+
+        Ok((foreign_account, advice_inputs))
+    }
+
+    /// Records the advice inputs of a successful result, or the error of a failed one.
+    fn record(
+        &mut self,
+        result: Result<AdviceInputs, TransactionExecutorError>,
+    ) -> Option<AdviceInputs> {
+        let advice_inputs = result.map_err(|err| self.record_error(err)).ok()?;
+        self.advice_inputs.extend(advice_inputs.clone());
+
+        Some(advice_inputs)
+    }
+
+    /// Records the provided error, unless an error was already recorded.
+    fn record_error(&mut self, err: TransactionExecutorError) {
+        self.error.get_or_insert(err);
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Verifies the provided sparse Merkle tree proof against the specified root and key, and returns
+/// the advice inputs containing its Merkle path and leaf.
+fn build_smt_proof_advice_inputs(
+    account_id: AccountId,
+    root: Digest,
+    key: Digest,
+    proof: SmtProof,
+) -> Result<AdviceInputs, TransactionExecutorError> {
+    let mut advice_inputs = AdviceInputs::default();
+    add_smt_proof_to_advice_inputs(&mut advice_inputs, account_id, root, key, proof)?;
+
+    Ok(advice_inputs)
+}
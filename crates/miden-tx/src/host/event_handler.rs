@@ -0,0 +1,189 @@
+use alloc::{collections::BTreeMap, sync::Arc, vec::Vec};
+use core::ops::RangeInclusive;
+
+use miden_lib::transaction::TransactionEvent;
+use miden_objects::{
+    account::{AccountDelta, AccountHeader},
+    Digest, Felt,
+};
+use vm_processor::ProcessState;
+
+use super::AccountDeltaTracker;
+use crate::errors::EventHandlerError;
+
+// EVENT HANDLER
+// ================================================================================================
+
+/// Handles events emitted via the `emit.<event_id>` instruction by user-defined code (e.g., note
+/// scripts, transaction scripts, or account components) during transaction execution.
+///
+/// Event handlers are registered for a range of event IDs in an [EventHandlerRegistry], which is
+/// then provided to the [TransactionHost](super::TransactionHost) via the transaction executor or
+/// prover. Since transactions are executed again when they are proven, handlers are expected to
+/// behave the same way on every invocation with the same inputs.
+pub trait EventHandler: Send + Sync {
+    /// Handles the event with the specified ID.
+    ///
+    /// The provided [EventContext] gives access to the state of the VM and to a read-only view of
+    /// the native account, and allows the handler to provide data to the transaction via the
+    /// advice provider.
+    ///
+    /// # Errors
+    /// Returning an error aborts the execution of the transaction.
+    fn on_event(
+        &self,
+        event_id: u32,
+        context: &mut EventContext<'_>,
+    ) -> Result<(), EventHandlerError>;
+}
+
+// EVENT HANDLER REGISTRY
+// ================================================================================================
+
+/// A set of [EventHandler]s for non-overlapping ranges of event IDs.
+///
+/// The event IDs reserved for the transaction kernel (i.e., the IDs whose upper 16 bits are set to
+/// [TransactionEvent::ID_PREFIX]) cannot be handled by user-defined handlers. System events of the
+/// VM are always handled by the host, even if they fall into a registered range.
+#[derive(Clone, Default)]
+pub struct EventHandlerRegistry {
+    /// A map of the first event ID of a range |-> (last event ID of the range, handler).
+    handlers: BTreeMap<u32, (u32, Arc<dyn EventHandler>)>,
+}
+
+impl EventHandlerRegistry {
+    /// Returns a new empty [EventHandlerRegistry].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the provided handler for the specified range of event IDs.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The range of event IDs is empty.
+    /// - The range contains event IDs reserved for the transaction kernel.
+    /// - The range overlaps with the range of a previously registered handler.
+    pub fn register(
+        &mut self,
+        event_ids: RangeInclusive<u32>,
+        handler: Arc<dyn EventHandler>,
+    ) -> Result<(), EventHandlerError> {
+        let (start, end) = event_ids.into_inner();
+        if start > end {
+            return Err(EventHandlerError::EmptyEventIdRange { start, end });
+        }
+
+        let first_kernel_event = TransactionEvent::ID_PREFIX << 16;
+        let last_kernel_event = first_kernel_event | 0xffff;
+        if start <= last_kernel_event && end >= first_kernel_event {
+            return Err(EventHandlerError::ReservedEventIds { start, end });
+        }
+
+        // the range with the greatest start not exceeding `end` is the only candidate for overlap
+        if let Some((_, (prev_end, _))) = self.handlers.range(..=end).next_back() {
+            if *prev_end >= start {
+                return Err(EventHandlerError::OverlappingEventIds { start, end });
+            }
+        }
+
+        self.handlers.insert(start, (end, handler));
+        Ok(())
+    }
+
+    /// Returns the handler registered for the specified event ID, or None if there is no such
+    /// handler.
+    pub fn get(&self, event_id: u32) -> Option<&Arc<dyn EventHandler>> {
+        self.handlers
+            .range(..=event_id)
+            .next_back()
+            .filter(|(_, (end, _))| event_id <= *end)
+            .map(|(_, (_, handler))| handler)
+    }
+
+    /// Returns true if no handlers have been registered.
+    pub fn is_empty(&self) -> bool {
+        self.handlers.is_empty()
+    }
+}
+
+// EVENT CONTEXT
+// ================================================================================================
+
+/// The context in which an [EventHandler] is invoked.
+///
+/// Values pushed to the advice stack and entries inserted into the advice map are applied to the
+/// advice provider of the host after the handler returns successfully.
+pub struct EventContext<'a> {
+    process: ProcessState<'a>,
+    initial_account: &'a AccountHeader,
+    account_delta: &'a AccountDeltaTracker,
+    advice_stack: Vec<Felt>,
+    advice_map: Vec<(Digest, Vec<Felt>)>,
+}
+
+impl<'a> EventContext<'a> {
+    /// Returns a new [EventContext] for the provided process and account state.
+    pub(super) fn new(
+        process: ProcessState<'a>,
+        initial_account: &'a AccountHeader,
+        account_delta: &'a AccountDeltaTracker,
+    ) -> Self {
+        Self {
+            process,
+            initial_account,
+            account_delta,
+            advice_stack: Vec::new(),
+            advice_map: Vec::new(),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the state of the VM at the moment the event was emitted.
+    pub fn process(&self) -> ProcessState<'a> {
+        self.process
+    }
+
+    /// Returns the header of the native account at the beginning of the transaction.
+    pub fn initial_account(&self) -> &AccountHeader {
+        self.initial_account
+    }
+
+    /// Returns the changes made to the native account up to the moment the event was emitted.
+    pub fn account_delta(&self) -> AccountDelta {
+        self.account_delta.clone().into_delta()
+    }
+
+    // ADVICE PROVIDER MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Pushes the provided value onto the advice stack.
+    ///
+    /// Values are pushed in the order of invocation, and thus the value pushed last ends up at the
+    /// top of the advice stack.
+    pub fn push_advice_stack(&mut self, value: Felt) {
+        self.advice_stack.push(value);
+    }
+
+    /// Pushes the provided values onto the advice stack one by one, such that the last value ends
+    /// up at the top of the advice stack.
+    pub fn extend_advice_stack(&mut self, values: impl IntoIterator<Item = Felt>) {
+        self.advice_stack.extend(values);
+    }
+
+    /// Inserts the provided values into the advice map under the specified key.
+    pub fn insert_advice_map(&mut self, key: Digest, values: Vec<Felt>) {
+        self.advice_map.push((key, values));
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Consumes `self` and returns the values to be pushed onto the advice stack and the entries
+    /// to be inserted into the advice map.
+    pub(super) fn into_advice(self) -> (Vec<Felt>, Vec<(Digest, Vec<Felt>)>) {
+        (self.advice_stack, self.advice_map)
+    }
+}
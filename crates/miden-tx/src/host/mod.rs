@@ -9,18 +9,25 @@ use alloc::{
 use miden_lib::{
     errors::tx_kernel_errors::TX_KERNEL_ERRORS,
     transaction::{
-        memory::{CURRENT_INPUT_NOTE_PTR, NATIVE_NUM_ACCT_STORAGE_SLOTS_PTR},
+        memory::{
+            ACCT_ID_AND_NONCE_OFFSET, ACCT_ID_PREFIX_IDX, ACCT_ID_SUFFIX_IDX,
+            CURRENT_ACCOUNT_DATA_PTR, CURRENT_INPUT_NOTE_PTR, NATIVE_ACCOUNT_DATA_PTR,
+            NATIVE_NUM_ACCT_STORAGE_SLOTS_PTR,
+        },
         TransactionEvent, TransactionEventError, TransactionKernelError, TransactionTrace,
     },
 };
 use miden_objects::{
-    account::{AccountDelta, AccountHeader},
+    account::{AccountCode, AccountDelta, AccountHeader, AccountId, StorageSlot},
     asset::Asset,
+    crypto::merkle::{LeafIndex, SMT_DEPTH},
     note::NoteId,
     transaction::{OutputNote, TransactionMeasurements},
     vm::{RowIndex, SystemEvent},
-    Digest, Hasher,
+    Digest, Hasher, ZERO,
 };
+#[cfg(not(feature = "async"))]
+use vm_processor::{AdviceInputs, MemAdviceProvider};
 use vm_processor::{
     AdviceProvider, AdviceSource, ContextId, ExecutionError, Felt, Host, MastForest,
     MastForestStore, ProcessState,
@@ -32,15 +39,24 @@ use account_delta_tracker::AccountDeltaTracker;
 mod account_procedures;
 pub use account_procedures::AccountProcedureIndexMap;
 
+mod data_request;
+pub use data_request::TransactionDataRequest;
+
+#[cfg(not(feature = "async"))]
+mod data_resolver;
+#[cfg(not(feature = "async"))]
+pub(crate) use data_resolver::TransactionDataResolver;
+
+mod event_handler;
+pub use event_handler::{EventContext, EventHandler, EventHandlerRegistry};
+
 mod note_builder;
 use note_builder::OutputNoteBuilder;
 
 mod tx_progress;
 pub use tx_progress::TransactionProgress;
 
-use crate::{
-    auth::TransactionAuthenticator, errors::TransactionHostError, executor::TransactionMastStore,
-};
+use crate::{errors::TransactionHostError, executor::TransactionMastStore};
 
 // TRANSACTION HOST
 // ================================================================================================
@@ -71,16 +87,12 @@ pub struct TransactionHost<A> {
     /// map.
     output_notes: BTreeMap<usize, OutputNoteBuilder>,
 
-    /// Serves signature generation requests from the transaction runtime for signatures which are
-    /// not present in the `generated_signatures` field.
-    authenticator: Option<Arc<dyn TransactionAuthenticator>>,
-
-    /// Contains previously generated signatures (as a message |-> signature map) required for
-    /// transaction execution.
+    /// Signatures which the transaction runtime requested, but which were not present in the
+    /// advice provider, as a list of (public key, message) pairs in the order of the requests.
     ///
-    /// If a required signature is not present in this map, the host will attempt to generate the
-    /// signature using the transaction authenticator.
-    generated_signatures: BTreeMap<Digest, Vec<Felt>>,
+    /// This field is updated by the [TransactionHost::on_event()] handler and the handler of the
+    /// `SigToStack` injector.
+    signature_requests: Vec<(Digest, Digest)>,
 
     /// Tracks the number of cycles for each of the transaction execution stages.
     ///
@@ -91,6 +103,26 @@ pub struct TransactionHost<A> {
     ///
     /// This map is initialized at construction time from the [`TX_KERNEL_ERRORS`] array.
     error_messages: BTreeMap<u32, &'static str>,
+
+    /// The header of the native account at the beginning of the transaction.
+    initial_account: AccountHeader,
+
+    /// Data which the transaction kernel requested, but which was not present in the advice
+    /// provider.
+    ///
+    /// This field is updated by the [TransactionHost::on_event()] handler.
+    data_request: Option<TransactionDataRequest>,
+
+    /// Handlers for events emitted by user-defined code.
+    event_handlers: EventHandlerRegistry,
+
+    /// The code the native account may set as its new code during the transaction.
+    new_account_code: Option<AccountCode>,
+
+    /// Resolves data requested by the transaction kernel which is not present in the advice
+    /// provider.
+    #[cfg(not(feature = "async"))]
+    data_resolver: Option<TransactionDataResolver>,
 }
 
 impl<A: AdviceProvider> TransactionHost<A> {
@@ -99,7 +131,6 @@ impl<A: AdviceProvider> TransactionHost<A> {
         account: AccountHeader,
         adv_provider: A,
         mast_store: Arc<TransactionMastStore>,
-        authenticator: Option<Arc<dyn TransactionAuthenticator>>,
         mut account_code_commitments: BTreeSet<Digest>,
     ) -> Result<Self, TransactionHostError> {
         // currently, the executor/prover do not keep track of the code commitment of the native
@@ -116,40 +147,99 @@ impl<A: AdviceProvider> TransactionHost<A> {
             account_delta: AccountDeltaTracker::new(&account),
             acct_procedure_index_map: proc_index_map,
             output_notes: BTreeMap::default(),
-            authenticator,
+            signature_requests: Vec::new(),
             tx_progress: TransactionProgress::default(),
-            generated_signatures: BTreeMap::new(),
             error_messages: kernel_assertion_errors,
+            initial_account: account,
+            data_request: None,
+            event_handlers: EventHandlerRegistry::default(),
+            new_account_code: None,
+            #[cfg(not(feature = "async"))]
+            data_resolver: None,
         })
     }
 
-    /// Consumes `self` and returns the advice provider, account delta, output notes, generated
-    /// signatures, and transaction progress.
-    pub fn into_parts(
-        self,
-    ) -> (
-        A,
-        AccountDelta,
-        Vec<OutputNote>,
-        BTreeMap<Digest, Vec<Felt>>,
-        TransactionProgress,
-    ) {
+    /// Sets the handlers for events emitted by user-defined code during transaction execution.
+    ///
+    /// Events with IDs which are neither handled by the host itself nor by one of the provided
+    /// handlers cause the execution to fail.
+    pub fn with_event_handlers(mut self, event_handlers: EventHandlerRegistry) -> Self {
+        self.event_handlers = event_handlers;
+        self
+    }
+
+    /// Sets the code which the native account may set as its new code during the transaction.
+    ///
+    /// If the account code is set to any other code than this one or the initial code of the
+    /// account, the execution fails.
+    pub fn with_new_account_code(mut self, code: AccountCode) -> Self {
+        self.new_account_code = Some(code);
+        self
+    }
+
+    /// Sets the resolver which fetches the data requested by the transaction kernel, but not
+    /// present in the advice provider, from within the event handlers of this host.
+    ///
+    /// Without a resolver, such a request is recorded and the execution is aborted (see
+    /// [Self::data_request()]).
+    #[cfg(not(feature = "async"))]
+    pub(crate) fn with_data_resolver(mut self, data_resolver: TransactionDataResolver) -> Self {
+        self.data_resolver = Some(data_resolver);
+        self
+    }
+
+    /// Removes the data resolver from this host and returns it, if any.
+    #[cfg(not(feature = "async"))]
+    pub(crate) fn take_data_resolver(&mut self) -> Option<TransactionDataResolver> {
+        self.data_resolver.take()
+    }
+
+    /// Consumes `self` and returns the advice provider, account delta, output notes, and
+    /// transaction progress.
+    pub fn into_parts(self) -> (A, AccountDelta, Vec<OutputNote>, TransactionProgress) {
         let output_notes = self.output_notes.into_values().map(|builder| builder.build()).collect();
 
         (
             self.adv_provider,
             self.account_delta.into_delta(),
             output_notes,
-            self.generated_signatures,
             self.tx_progress,
         )
     }
 
+    /// Returns the changes made to the native account so far.
+    pub fn account_delta(&self) -> AccountDelta {
+        self.account_delta.clone().into_delta()
+    }
+
+    /// Returns the notes created so far.
+    pub fn output_notes(&self) -> Vec<OutputNote> {
+        self.output_notes.values().map(|builder| builder.clone().build()).collect()
+    }
+
     /// Returns a reference to the `tx_progress` field of this transaction host.
     pub fn tx_progress(&self) -> &TransactionProgress {
         &self.tx_progress
     }
 
+    /// Returns the data which was requested by the transaction kernel but was not present in the
+    /// advice provider, if any.
+    ///
+    /// If this is set, the execution of the transaction has been aborted because of the missing
+    /// data.
+    pub fn data_request(&self) -> Option<&TransactionDataRequest> {
+        self.data_request.as_ref()
+    }
+
+    /// Returns the signatures which were requested by the transaction runtime but were not present
+    /// in the advice provider, as (public key, message) pairs in the order of the requests.
+    ///
+    /// A signature over a message by a public key is expected to be provided in the advice map
+    /// under `hash(PUB_KEY, MESSAGE)`.
+    pub fn signature_requests(&self) -> &[(Digest, Digest)] {
+        &self.signature_requests
+    }
+
     // EVENT HANDLERS
     // --------------------------------------------------------------------------------------------
 
@@ -220,6 +310,134 @@ impl<A: AdviceProvider> TransactionHost<A> {
         Ok(())
     }
 
+    /// Checks that the data of the foreign account which is about to be loaded by the transaction
+    /// kernel is present in the advice provider.
+    ///
+    /// If the data is missing, it is fetched via the data resolver of this host. Without a
+    /// resolver, or if fetching the data fails, the ID of the foreign account is recorded, so that
+    /// the caller can provide the data and re-execute the transaction.
+    ///
+    /// Expected stack state: [foreign_account_id_prefix, foreign_account_id_suffix, ...]
+    fn on_account_before_foreign_load(
+        &mut self,
+        process: ProcessState,
+    ) -> Result<(), TransactionKernelError> {
+        let account_id_prefix = process.get_stack_item(0);
+        let account_id_suffix = process.get_stack_item(1);
+
+        // Note: keep in sync with the start_foreign_context kernel procedure
+        let account_key = Digest::from([account_id_suffix, account_id_prefix, ZERO, ZERO]);
+        if self.adv_provider.get_mapped_values(&account_key).is_some() {
+            return Ok(());
+        }
+
+        let account_id = AccountId::try_from([account_id_prefix, account_id_suffix])
+            .map_err(TransactionKernelError::MalformedForeignAccountId)?;
+
+        #[cfg(not(feature = "async"))]
+        if self.load_foreign_account(account_id) {
+            return Ok(());
+        }
+
+        self.data_request = Some(TransactionDataRequest::ForeignAccount(account_id));
+
+        Err(TransactionKernelError::MissingForeignAccountInputs(account_id))
+    }
+
+    /// Checks that the Merkle path to the storage map entry which is about to be read or updated
+    /// by the transaction kernel is present in the advice provider.
+    ///
+    /// If the path is missing, the path in the initial state of the storage map is fetched via the
+    /// data resolver of this host. Without a resolver, or if fetching the path fails, a request for
+    /// the path is recorded, so that the caller can provide it and re-execute the transaction.
+    /// Since all nodes along the path in the initial map are then present, the path can also be
+    /// resolved in any state of the map derived from the initial one during execution.
+    ///
+    /// Expected stack state: [slot_index, KEY, ...], with MAP_ROOT starting at the stack item with
+    /// index `map_root_position`.
+    fn on_account_storage_before_map_access(
+        &mut self,
+        process: ProcessState,
+        map_root_position: usize,
+    ) -> Result<(), TransactionKernelError> {
+        let slot_index = process.get_stack_item(0).as_int() as usize;
+        let map_key = Digest::from([
+            process.get_stack_item(4),
+            process.get_stack_item(3),
+            process.get_stack_item(2),
+            process.get_stack_item(1),
+        ]);
+        let map_root = Digest::from([
+            process.get_stack_item(map_root_position + 3),
+            process.get_stack_item(map_root_position + 2),
+            process.get_stack_item(map_root_position + 1),
+            process.get_stack_item(map_root_position),
+        ]);
+
+        if self.has_smt_leaf_path(map_root, map_key) {
+            return Ok(());
+        }
+
+        // foreign accounts cannot be updated, so their map roots are always the initial ones
+        let (account_id, map_root) = match Self::get_current_foreign_account_id(process)? {
+            Some(account_id) => (account_id, map_root),
+            None => (self.initial_account.id(), self.get_initial_map_root(slot_index)?),
+        };
+
+        #[cfg(not(feature = "async"))]
+        if let Some(advice_inputs) = self.data_resolver.as_mut().and_then(|resolver| {
+            resolver.resolve_storage_map_witness(account_id, map_root, map_key)
+        }) {
+            extend_advice_provider(&mut self.adv_provider, advice_inputs);
+            return Ok(());
+        }
+
+        self.data_request =
+            Some(TransactionDataRequest::StorageMapWitness { account_id, map_root, map_key });
+
+        Err(TransactionKernelError::MissingStorageMapWitness { account_id, map_root, map_key })
+    }
+
+    /// Checks that the Merkle path to the asset which is about to be read or updated by the
+    /// transaction kernel is present in the advice provider.
+    ///
+    /// If the path is missing, the path in the initial state of the asset vault is fetched via the
+    /// data resolver of this host. Without a resolver, or if fetching the path fails, a request for
+    /// the path is recorded, so that the caller can provide it and re-execute the transaction.
+    ///
+    /// Expected stack state: [ASSET_KEY, VAULT_ROOT, ...]
+    fn on_account_vault_before_get_asset(
+        &mut self,
+        process: ProcessState,
+    ) -> Result<(), TransactionKernelError> {
+        let vault_key = Digest::from(process.get_stack_word(0));
+        let vault_root = Digest::from(process.get_stack_word(1));
+
+        if self.has_smt_leaf_path(vault_root, vault_key) {
+            return Ok(());
+        }
+
+        // vaults derived from the native account vault (e.g., the input and output vaults of the
+        // transaction) are only accessed while the native account is the current account
+        let (account_id, vault_root) = match Self::get_current_foreign_account_id(process)? {
+            Some(account_id) => (account_id, vault_root),
+            None => (self.initial_account.id(), self.initial_account.vault_root()),
+        };
+
+        #[cfg(not(feature = "async"))]
+        if let Some(advice_inputs) = self.data_resolver.as_mut().and_then(|resolver| {
+            resolver.resolve_vault_asset_witness(account_id, vault_root, vault_key)
+        }) {
+            extend_advice_provider(&mut self.adv_provider, advice_inputs);
+            return Ok(());
+        }
+
+        self.data_request =
+            Some(TransactionDataRequest::VaultAssetWitness { account_id, vault_root, vault_key });
+
+        Err(TransactionKernelError::MissingVaultAssetWitness { account_id, vault_root, vault_key })
+    }
+
     /// Extracts the nonce increment from the process state and adds it to the nonce delta tracker.
     ///
     /// Expected stack state: [nonce_delta, ...]
@@ -232,6 +450,31 @@ impl<A: AdviceProvider> TransactionHost<A> {
         Ok(())
     }
 
+    /// Extracts the commitment of the new account code from the process state and records the
+    /// corresponding code in the account delta.
+    ///
+    /// Expected stack state: [CODE_COMMITMENT, ...]
+    pub fn on_account_after_set_code(
+        &mut self,
+        process: ProcessState,
+    ) -> Result<(), TransactionKernelError> {
+        let code_commitment = Digest::from(process.get_stack_word(0));
+
+        if code_commitment == self.initial_account.code_commitment() {
+            self.account_delta.set_code(None);
+            return Ok(());
+        }
+
+        let code = self
+            .new_account_code
+            .as_ref()
+            .filter(|code| code.commitment() == code_commitment)
+            .ok_or(TransactionKernelError::MissingNewAccountCode(code_commitment))?;
+        self.account_delta.set_code(Some(code.clone()));
+
+        Ok(())
+    }
+
     // ACCOUNT STORAGE UPDATE HANDLERS
     // --------------------------------------------------------------------------------------------
 
@@ -380,35 +623,21 @@ impl<A: AdviceProvider> TransactionHost<A> {
 
     /// Returns a signature as a response to the `SigToStack` injector.
     ///
-    /// This signature is created during transaction execution and stored for use as advice map
-    /// inputs in the proving host. If not already present in the advice map, it is requested from
-    /// the host's authenticator.
+    /// The signature is read from the advice map. If it is not present there, it is requested from
+    /// the authenticator of the data resolver of this host, if any. Otherwise, or if the
+    /// authenticator does not provide it, the request is recorded and the execution of the
+    /// transaction is aborted, such that the caller can obtain the signature (e.g., from a
+    /// [TransactionAuthenticator](crate::auth::TransactionAuthenticator)) and re-execute the
+    /// transaction.
     pub fn on_signature_requested(&mut self, process: ProcessState) -> Result<(), ExecutionError> {
         let pub_key = process.get_stack_word(0);
         let msg = process.get_stack_word(1);
-        let signature_key = Hasher::merge(&[pub_key.into(), msg.into()]);
 
-        let signature = if let Some(signature) = self.adv_provider.get_mapped_values(&signature_key)
-        {
-            signature.to_vec()
-        } else {
-            let account_delta = self.account_delta.clone().into_delta();
-
-            let signature: Vec<Felt> = match &self.authenticator {
-                None => {
-                    return Err(ExecutionError::FailedSignatureGeneration(
-                        "No authenticator assigned to transaction host",
-                    ))
-                },
-                Some(authenticator) => {
-                    authenticator.get_signature(pub_key, msg, &account_delta).map_err(|_| {
-                        ExecutionError::FailedSignatureGeneration("Error generating signature")
-                    })
-                },
-            }?;
-
-            self.generated_signatures.insert(signature_key, signature.clone());
-            signature
+        let Some(signature) = self.get_signature(pub_key.into(), msg.into()) else {
+            self.record_signature_request(pub_key.into(), msg.into());
+            return Err(ExecutionError::FailedSignatureGeneration(
+                "signature not present in the advice provider",
+            ));
         };
 
         for r in signature {
@@ -418,6 +647,126 @@ impl<A: AdviceProvider> TransactionHost<A> {
         Ok(())
     }
 
+    /// Pushes 1 onto the advice stack if a signature over the message by the signer with the
+    /// specified public key is present in the advice map or was obtained from the authenticator of
+    /// the data resolver of this host, and 0 otherwise.
+    ///
+    /// Expected stack state: `[PUB_KEY, MESSAGE, ...]`
+    ///
+    /// If present, the signature is expected under `hash(PUB_KEY, MESSAGE)`, such that it is
+    /// provided by the subsequent `SigToStack` injector. Unlike [Self::on_signature_requested()], a
+    /// missing signature does not abort the transaction, which allows authentication components to
+    /// request signatures from several signers, only some of whom need to sign. The request is
+    /// still recorded, such that the caller can obtain the signature and re-execute the
+    /// transaction.
+    pub fn on_auth_request_signature(
+        &mut self,
+        process: ProcessState,
+    ) -> Result<(), ExecutionError> {
+        let pub_key = process.get_stack_word(0);
+        let msg = process.get_stack_word(1);
+
+        let has_signature = self.get_signature(pub_key.into(), msg.into()).is_some();
+        if !has_signature {
+            self.record_signature_request(pub_key.into(), msg.into());
+        }
+
+        self.adv_provider.push_stack(AdviceSource::Value(Felt::from(has_signature)))?;
+
+        Ok(())
+    }
+
+    /// Returns the signature over the message by the signer with the specified public key.
+    ///
+    /// The signature is read from the advice map, where it is expected under
+    /// `hash(PUB_KEY, MESSAGE)`. If it is not present there, it is requested from the authenticator
+    /// of the data resolver of this host and added to the advice map.
+    ///
+    /// Returns `None` if the signature is neither present in the advice map nor could be obtained.
+    fn get_signature(&mut self, pub_key: Digest, message: Digest) -> Option<Vec<Felt>> {
+        let signature_key = Hasher::merge(&[pub_key, message]);
+        if let Some(signature) = self.adv_provider.get_mapped_values(&signature_key) {
+            return Some(signature.to_vec());
+        }
+
+        #[cfg(not(feature = "async"))]
+        if self
+            .data_resolver
+            .as_ref()
+            .is_some_and(TransactionDataResolver::has_authenticator)
+        {
+            let account_delta = self.account_delta();
+            let output_notes = self.output_notes();
+            let signature = self.data_resolver.as_mut()?.resolve_signature(
+                pub_key,
+                message,
+                account_delta,
+                output_notes,
+            )?;
+            self.adv_provider.insert_into_map(signature_key.into(), signature.clone());
+
+            return Some(signature);
+        }
+
+        None
+    }
+
+    /// Records a request for a signature over the message by the specified public key which was
+    /// not present in the advice provider.
+    fn record_signature_request(&mut self, pub_key: Digest, message: Digest) {
+        self.signature_requests.push((pub_key, message));
+        self.data_request = Some(TransactionDataRequest::Signature { pub_key, message });
+    }
+
+    /// Invokes the provided user-defined handler for the event with the specified ID, and applies
+    /// the advice data provided by the handler to the advice provider.
+    fn on_user_event(
+        &mut self,
+        process: ProcessState,
+        event_id: u32,
+        handler: &dyn EventHandler,
+    ) -> Result<(), ExecutionError> {
+        let mut context = EventContext::new(process, &self.initial_account, &self.account_delta);
+        handler
+            .on_event(event_id, &mut context)
+            .map_err(|err| ExecutionError::EventError(Box::new(err)))?;
+
+        let (advice_stack, advice_map) = context.into_advice();
+        for value in advice_stack {
+            self.adv_provider.push_stack(AdviceSource::Value(value))?;
+        }
+        for (key, values) in advice_map {
+            self.adv_provider.insert_into_map(key.into(), values);
+        }
+
+        Ok(())
+    }
+
+    // DATA RESOLUTION
+    // --------------------------------------------------------------------------------------------
+
+    /// Fetches the data of the specified foreign account via the data resolver of this host, and
+    /// loads it into the advice provider and the MAST store.
+    ///
+    /// Returns true if the data was loaded, and false if there is no resolver or the data could
+    /// not be fetched.
+    #[cfg(not(feature = "async"))]
+    fn load_foreign_account(&mut self, account_id: AccountId) -> bool {
+        let Some((foreign_account, advice_inputs)) = self
+            .data_resolver
+            .as_mut()
+            .and_then(|resolver| resolver.resolve_foreign_account(account_id))
+        else {
+            return false;
+        };
+
+        self.mast_store.load_account_code(foreign_account.account_code());
+        self.acct_procedure_index_map.insert_code(foreign_account.account_code());
+        extend_advice_provider(&mut self.adv_provider, advice_inputs);
+
+        true
+    }
+
     // HELPER FUNCTIONS
     // --------------------------------------------------------------------------------------------
 
@@ -446,6 +795,69 @@ impl<A: AdviceProvider> TransactionHost<A> {
         }
     }
 
+    /// Returns true if the Merkle path to the leaf of the sparse Merkle tree with the specified
+    /// root which can contain the specified key is present in the advice provider.
+    fn has_smt_leaf_path(&self, root: Digest, key: Digest) -> bool {
+        let leaf_index = LeafIndex::<SMT_DEPTH>::from(key).value();
+        self.adv_provider
+            .get_tree_node(root.into(), &Felt::from(SMT_DEPTH), &Felt::new(leaf_index))
+            .is_ok()
+    }
+
+    /// Returns the ID of the current account if it is a foreign account, or None if the current
+    /// account is the native account.
+    ///
+    /// # Errors
+    /// Returns an error if the pointer to the current account data or the ID of the current
+    /// account have not been initialized in memory.
+    fn get_current_foreign_account_id(
+        process: ProcessState,
+    ) -> Result<Option<AccountId>, TransactionKernelError> {
+        let account_data_ptr = process
+            .get_mem_value(process.ctx(), CURRENT_ACCOUNT_DATA_PTR)
+            .ok_or(TransactionKernelError::CurrentAccountDataPtrMissing(CURRENT_ACCOUNT_DATA_PTR))?
+            .as_int() as u32;
+
+        if account_data_ptr == NATIVE_ACCOUNT_DATA_PTR {
+            return Ok(None);
+        }
+
+        let id_and_nonce_ptr = account_data_ptr + ACCT_ID_AND_NONCE_OFFSET;
+        let id_and_nonce = process
+            .get_mem_word(process.ctx(), id_and_nonce_ptr)
+            .ok()
+            .flatten()
+            .ok_or(TransactionKernelError::CurrentAccountIdMissing(id_and_nonce_ptr))?;
+
+        AccountId::try_from([id_and_nonce[ACCT_ID_PREFIX_IDX], id_and_nonce[ACCT_ID_SUFFIX_IDX]])
+            .map(Some)
+            .map_err(TransactionKernelError::MalformedForeignAccountId)
+    }
+
+    /// Returns the root of the storage map in the specified slot of the native account at the
+    /// beginning of the transaction.
+    ///
+    /// # Errors
+    /// Returns an error if the storage slots of the initial native account storage are not present
+    /// in the advice provider or if the slot index is out of bounds.
+    fn get_initial_map_root(&self, slot_index: usize) -> Result<Digest, TransactionKernelError> {
+        let storage_commitment = self.initial_account.storage_commitment();
+        let slots = self
+            .adv_provider
+            .get_mapped_values(&storage_commitment)
+            .ok_or(TransactionKernelError::MissingStorageSlots(storage_commitment))?;
+
+        let slot_start = slot_index * StorageSlot::NUM_ELEMENTS_PER_STORAGE_SLOT;
+        let slot_value = slots.get(slot_start..slot_start + 4).ok_or(
+            TransactionKernelError::InvalidStorageSlotIndex {
+                max: (slots.len() / StorageSlot::NUM_ELEMENTS_PER_STORAGE_SLOT) as u64,
+                actual: slot_index as u64,
+            },
+        )?;
+
+        Ok(Digest::from([slot_value[0], slot_value[1], slot_value[2], slot_value[3]]))
+    }
+
     /// Returns the number of storage slots initialized for the current account.
     ///
     /// # Errors
@@ -462,6 +874,26 @@ impl<A: AdviceProvider> TransactionHost<A> {
     }
 }
 
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Adds the advice map entries and the Merkle store nodes of the provided advice inputs to the
+/// provided advice provider.
+#[cfg(not(feature = "async"))]
+fn extend_advice_provider(adv_provider: &mut impl AdviceProvider, advice_inputs: AdviceInputs) {
+    // advice inputs do not expose their advice map, but the advice provider built from them does
+    let (_, map, store) = MemAdviceProvider::from(advice_inputs).into_parts();
+
+    for (key, values) in map {
+        adv_provider.insert_into_map(key.into(), values);
+    }
+    for node in store.inner_nodes() {
+        adv_provider
+            .merge_roots(node.left.into(), node.right.into())
+            .expect("failed to add a node to the Merkle store");
+    }
+}
+
 // HOST IMPLEMENTATION FOR TRANSACTION HOST
 // ================================================================================================
 
@@ -497,16 +929,29 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
             None => (),
         }
 
+        // events handled by user-defined handlers may be emitted from any context
+        if let Some(handler) = self.event_handlers.get(event_id).cloned() {
+            return self.on_user_event(process, event_id, handler.as_ref());
+        }
+
         let transaction_event = TransactionEvent::try_from(event_id)
             .map_err(|err| ExecutionError::EventError(Box::new(err)))?;
 
-        if process.ctx() != ContextId::root() {
+        // signatures are requested by authentication components, which are executed in the
+        // context of the account
+        if process.ctx() != ContextId::root()
+            && transaction_event != TransactionEvent::AuthRequestSignature
+        {
             return Err(ExecutionError::EventError(Box::new(
                 TransactionEventError::NotRootContext(event_id),
             )));
         }
 
         match transaction_event {
+            TransactionEvent::AccountVaultBeforeGetAsset => {
+                self.on_account_vault_before_get_asset(process)
+            },
+
             TransactionEvent::AccountVaultBeforeAddAsset => Ok(()),
             TransactionEvent::AccountVaultAfterAddAsset => {
                 self.on_account_vault_after_add_asset(process)
@@ -522,7 +967,15 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
                 self.on_account_storage_after_set_item(process)
             },
 
-            TransactionEvent::AccountStorageBeforeSetMapItem => Ok(()),
+            TransactionEvent::AccountStorageBeforeGetMapItem => {
+                // => [slot_index, KEY, MAP_ROOT, ...]
+                self.on_account_storage_before_map_access(process, 5)
+            },
+
+            TransactionEvent::AccountStorageBeforeSetMapItem => {
+                // => [slot_index, KEY, NEW_VALUE, MAP_ROOT, ...]
+                self.on_account_storage_before_map_access(process, 9)
+            },
             TransactionEvent::AccountStorageAfterSetMapItem => {
                 self.on_account_storage_after_set_map_item(process)
             },
@@ -532,15 +985,25 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
             },
             TransactionEvent::AccountAfterIncrementNonce => Ok(()),
 
+            TransactionEvent::AccountAfterSetCode => self.on_account_after_set_code(process),
+
             TransactionEvent::AccountPushProcedureIndex => {
                 self.on_account_push_procedure_index(process)
             },
 
+            TransactionEvent::AccountBeforeForeignLoad => {
+                self.on_account_before_foreign_load(process)
+            },
+
             TransactionEvent::NoteBeforeCreated => Ok(()),
             TransactionEvent::NoteAfterCreated => self.on_note_after_created(process),
 
             TransactionEvent::NoteBeforeAddAsset => self.on_note_before_add_asset(process),
             TransactionEvent::NoteAfterAddAsset => Ok(()),
+
+            TransactionEvent::AuthRequestSignature => {
+                return self.on_auth_request_signature(process);
+            },
         }
         .map_err(|err| ExecutionError::EventError(Box::new(err)))?;
 
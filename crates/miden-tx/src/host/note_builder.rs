@@ -11,6 +11,7 @@ use super::{AdviceProvider, Digest, Felt, OutputNote, TransactionKernelError};
 // ================================================================================================
 
 /// Builder of an output note, provided primarily to enable adding assets to a note incrementally.
+#[derive(Clone)]
 pub struct OutputNoteBuilder {
     metadata: NoteMetadata,
     assets: NoteAssets,
@@ -124,6 +124,16 @@ impl CycleInterval {
         Self { start: Some(start), end: None }
     }
 
+    /// Returns the cycle at which the interval started, or None if it hasn't started yet.
+    pub fn start(&self) -> Option<RowIndex> {
+        self.start
+    }
+
+    /// Returns the cycle at which the interval ended, or None if it hasn't ended yet.
+    pub fn end(&self) -> Option<RowIndex> {
+        self.end
+    }
+
     pub fn set_start(&mut self, s: RowIndex) {
         self.start = Some(s);
     }
@@ -9,25 +9,34 @@ extern crate std;
 pub use miden_objects::transaction::TransactionInputs;
 
 mod executor;
-pub use executor::{DataStore, TransactionExecutor, TransactionMastStore};
+pub use executor::{
+    DataStore, NoteConsumptionChecker, NoteConsumptionReport, NoteExecutionFailure,
+    SimulatedTransaction, TransactionExecutor, TransactionMastStore,
+};
 
 pub mod host;
 pub use host::{TransactionHost, TransactionProgress};
 
 mod prover;
-pub use prover::{LocalTransactionProver, ProvingOptions, TransactionProver};
+pub use prover::{
+    LocalBatchProver, LocalTransactionProver, ProvingCostEstimate, ProvingOptions,
+    TransactionProver,
+};
 
 mod verifier;
-pub use verifier::TransactionVerifier;
+pub use verifier::{BatchVerifier, ChainStateReader, TransactionVerifier};
 
 mod errors;
 pub use errors::{
-    AuthenticationError, DataStoreError, TransactionExecutorError, TransactionProverError,
-    TransactionVerifierError,
+    AuthenticationError, BatchProverError, BatchVerifierError, DataStoreError, EventHandlerError,
+    TransactionExecutorError, TransactionProverError, TransactionVerifierError,
 };
 
 pub mod auth;
 
+pub mod debugger;
+pub use debugger::{TransactionDebugger, TransactionProfile};
+
 #[cfg(any(feature = "testing", test))]
 pub mod testing;
 
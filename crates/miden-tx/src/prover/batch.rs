@@ -0,0 +1,56 @@
+use miden_objects::{
+    batch::{ProposedBatch, ProvenBatch},
+    transaction::TransactionHeader,
+};
+
+use crate::{errors::BatchProverError, TransactionVerifier};
+
+// LOCAL BATCH PROVER
+// ================================================================================================
+
+/// Local batch prover which verifies the proof of every transaction of a [ProposedBatch] and turns
+/// the batch into a [ProvenBatch].
+///
+/// The proofs of the transactions are verified natively and are not aggregated into a proof of the
+/// batch. The `proof_security_level` specifies the minimum security level that the transaction
+/// proofs must have in order to be considered valid.
+pub struct LocalBatchProver {
+    tx_verifier: TransactionVerifier,
+}
+
+impl LocalBatchProver {
+    /// Returns a new [LocalBatchProver] instantiated with the specified security level.
+    pub fn new(proof_security_level: u32) -> Self {
+        Self {
+            tx_verifier: TransactionVerifier::new(proof_security_level),
+        }
+    }
+
+    /// Verifies the proofs of the transactions of the provided [ProposedBatch] and returns the
+    /// resulting [ProvenBatch].
+    ///
+    /// # Errors
+    /// Returns an error if the proof of any transaction of the batch is invalid or does not have
+    /// the required security level.
+    pub fn prove(&self, proposed_batch: ProposedBatch) -> Result<ProvenBatch, BatchProverError> {
+        let (transactions, id, account_updates, input_notes, output_notes, expiration_block_num) =
+            proposed_batch.into_parts();
+        let tx_headers = transactions.iter().map(TransactionHeader::from).collect();
+
+        for (transaction_id, result) in self.tx_verifier.verify_many(transactions) {
+            result.map_err(|source| BatchProverError::TransactionVerificationFailed {
+                transaction_id,
+                source,
+            })?;
+        }
+
+        Ok(ProvenBatch::new(
+            id,
+            tx_headers,
+            account_updates,
+            input_notes,
+            output_notes,
+            expiration_block_num,
+        ))
+    }
+}
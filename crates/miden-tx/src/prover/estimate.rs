@@ -0,0 +1,181 @@
+use core::time::Duration;
+
+use miden_air::{
+    trace::{AUX_TRACE_RAND_ELEMENTS, AUX_TRACE_WIDTH, TRACE_WIDTH},
+    HashFunction, ProcessorAir, PublicInputs,
+};
+use miden_prover::ProvingOptions;
+use vm_processor::{ExecutionTrace, TraceLenSummary};
+use winter_air::{Air, ProofOptions as WinterProofOptions, TraceInfo};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The size of a field element in bytes.
+const FELT_SIZE: usize = 8;
+
+// PROVING COST ESTIMATE
+// ================================================================================================
+
+/// An estimate of the resources needed to prove a transaction.
+///
+/// The estimate is derived from the lengths of the execution trace of the transaction and the
+/// proving options, and mirrors the structure of the STARK prover:
+/// - the execution trace is padded to the next power of two, and then extended by the blowup
+///   factor of the proving options into the low-degree extension (LDE) domain.
+/// - the main, auxiliary and constraint composition columns are evaluated and committed to over
+///   the LDE domain, which dominates both the memory use and the time needed to build the proof.
+/// - the proof consists of the openings of these columns and of the FRI layers at the queried
+///   positions.
+///
+/// All figures are estimates: the memory use accounts only for the dominant allocations of the
+/// prover, and the proof size does not account for the deduplication of the nodes of Merkle paths
+/// which are shared between queries, and so is an upper bound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProvingCostEstimate {
+    trace_len_summary: TraceLenSummary,
+    lde_domain_size: usize,
+    num_lde_columns: usize,
+    memory_bytes: usize,
+    proof_size_bytes: usize,
+}
+
+impl ProvingCostEstimate {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the estimated cost of proving the provided execution trace with the specified
+    /// proving options.
+    pub fn new(trace: &ExecutionTrace, proof_options: &ProvingOptions) -> Self {
+        let trace_len_summary = *trace.trace_len_summary();
+        let trace_len = trace_len_summary.padded_trace_len();
+        let hash_fn = proof_options.hash_fn();
+        let proof_options: WinterProofOptions = proof_options.clone().into();
+
+        let trace_info = TraceInfo::new_multi_segment(
+            TRACE_WIDTH,
+            AUX_TRACE_WIDTH,
+            AUX_TRACE_RAND_ELEMENTS,
+            trace_len,
+            trace.meta().to_vec(),
+        );
+        let public_inputs = PublicInputs::new(
+            trace.program_info().clone(),
+            trace.init_stack_state(),
+            trace.stack_outputs().clone(),
+        );
+        let air = ProcessorAir::new(trace_info, public_inputs, proof_options.clone());
+        let num_composition_columns = air.context().num_constraint_composition_columns();
+
+        let extension_degree = proof_options.field_extension().degree() as usize;
+        let digest_size = match hash_fn {
+            HashFunction::Blake3_192 => 24,
+            HashFunction::Blake3_256 | HashFunction::Rpo256 | HashFunction::Rpx256 => 32,
+        };
+        let lde_domain_size = trace_len * proof_options.blowup_factor();
+        let lde_depth = lde_domain_size.ilog2() as usize;
+        let num_queries = proof_options.num_queries();
+
+        // the main trace columns are over the base field, while the auxiliary trace and the
+        // constraint composition columns are over the extension field
+        let num_lde_columns =
+            TRACE_WIDTH + (AUX_TRACE_WIDTH + num_composition_columns) * extension_degree;
+
+        // the prover keeps the execution trace, its LDE, and a Merkle tree over the rows of the
+        // LDE for each of the three committed segments
+        let fri_options = proof_options.to_fri_options();
+        let folding_factor = fri_options.folding_factor();
+        let num_fri_layers = fri_options.num_fri_layers(lde_domain_size);
+        let trace_bytes =
+            trace_len * (TRACE_WIDTH + AUX_TRACE_WIDTH * extension_degree) * FELT_SIZE;
+        let lde_bytes = lde_domain_size * num_lde_columns * FELT_SIZE;
+        let merkle_tree_bytes = 3 * 2 * lde_domain_size * digest_size;
+        let fri_bytes = (0..num_fri_layers)
+            .map(|layer| {
+                let domain_size = lde_domain_size / folding_factor.pow(layer as u32);
+                domain_size * extension_degree * FELT_SIZE + 2 * domain_size * digest_size
+            })
+            .sum::<usize>();
+        let memory_bytes = trace_bytes + lde_bytes + merkle_tree_bytes + fri_bytes;
+
+        // the proof contains the commitments, the out-of-domain evaluations, the opened rows and
+        // their Merkle paths, the FRI layer openings and the FRI remainder
+        let commitments_size = (3 + num_fri_layers) * digest_size;
+        let ood_frame_size = (2 * (TRACE_WIDTH + AUX_TRACE_WIDTH) + num_composition_columns)
+            * extension_degree
+            * FELT_SIZE;
+        let queries_size =
+            num_queries * (num_lde_columns * FELT_SIZE + 3 * lde_depth * digest_size);
+        let fri_layers_size = (0..num_fri_layers)
+            .map(|layer| {
+                let folded_depth = lde_depth - (layer + 1) * folding_factor.ilog2() as usize;
+                num_queries
+                    * (folding_factor * extension_degree * FELT_SIZE + folded_depth * digest_size)
+            })
+            .sum::<usize>();
+        let remainder_size =
+            (fri_options.remainder_max_degree() + 1) * extension_degree * FELT_SIZE;
+        let proof_size_bytes =
+            commitments_size + ood_frame_size + queries_size + fri_layers_size + remainder_size;
+
+        Self {
+            trace_len_summary,
+            lde_domain_size,
+            num_lde_columns,
+            memory_bytes,
+            proof_size_bytes,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the lengths of the components of the execution trace.
+    pub fn trace_len_summary(&self) -> &TraceLenSummary {
+        &self.trace_len_summary
+    }
+
+    /// Returns the length of the execution trace after padding it to the next power of two.
+    pub fn padded_trace_len(&self) -> usize {
+        self.trace_len_summary.padded_trace_len()
+    }
+
+    /// Returns the size of the low-degree extension domain of the execution trace.
+    pub fn lde_domain_size(&self) -> usize {
+        self.lde_domain_size
+    }
+
+    /// Returns the number of base field elements the prover evaluates and commits to over the
+    /// low-degree extension domain, i.e. the size of the LDE domain times the number of columns.
+    ///
+    /// The time needed to build the proof is roughly proportional to this number.
+    pub fn num_lde_cells(&self) -> usize {
+        self.lde_domain_size * self.num_lde_columns
+    }
+
+    /// Returns the estimated peak memory use of the prover in bytes.
+    pub fn memory_bytes(&self) -> usize {
+        self.memory_bytes
+    }
+
+    /// Returns the estimated size of the proof in bytes.
+    pub fn proof_size_bytes(&self) -> usize {
+        self.proof_size_bytes
+    }
+
+    /// Returns the estimated time needed to build the proof on a prover with the specified
+    /// throughput.
+    ///
+    /// The throughput is the number of LDE cells (see [Self::num_lde_cells()]) the prover processes
+    /// per second, and depends on the hardware of the prover and on whether it proves concurrently.
+    /// It can be calibrated by dividing the number of LDE cells of a proven transaction by the time
+    /// it took to prove it.
+    ///
+    /// # Panics
+    /// Panics if `lde_cells_per_second` is zero.
+    pub fn proving_time(&self, lde_cells_per_second: u64) -> Duration {
+        assert!(lde_cells_per_second > 0, "prover throughput must be positive");
+        let nanos = self.num_lde_cells() as u128 * 1_000_000_000 / lde_cells_per_second as u128;
+        Duration::from_nanos(nanos as u64)
+    }
+}
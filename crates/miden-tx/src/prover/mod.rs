@@ -4,9 +4,13 @@ use alloc::{sync::Arc, vec::Vec};
 
 use miden_lib::transaction::TransactionKernel;
 use miden_objects::{
-    account::delta::AccountUpdateDetails,
+    account::{delta::AccountUpdateDetails, AccountCode},
     assembly::Library,
-    transaction::{OutputNote, ProvenTransaction, ProvenTransactionBuilder, TransactionWitness},
+    transaction::{
+        OutputNote, ProvenTransaction, ProvenTransactionBuilder, TransactionArgs,
+        TransactionInputs, TransactionWitness,
+    },
+    vm::{AdviceInputs, StackInputs},
 };
 use miden_prover::prove;
 pub use miden_prover::ProvingOptions;
@@ -14,7 +18,13 @@ use vm_processor::MemAdviceProvider;
 use winter_maybe_async::*;
 
 use super::{TransactionHost, TransactionProverError};
-use crate::executor::TransactionMastStore;
+use crate::{executor::TransactionMastStore, host::EventHandlerRegistry};
+
+mod batch;
+pub use batch::LocalBatchProver;
+
+mod estimate;
+pub use estimate::ProvingCostEstimate;
 
 // TRANSACTION PROVER TRAIT
 // ================================================================================================
@@ -45,6 +55,7 @@ pub trait TransactionProver {
 pub struct LocalTransactionProver {
     mast_store: Arc<TransactionMastStore>,
     proof_options: ProvingOptions,
+    event_handlers: EventHandlerRegistry,
 }
 
 impl LocalTransactionProver {
@@ -53,9 +64,19 @@ impl LocalTransactionProver {
         Self {
             mast_store: Arc::new(TransactionMastStore::new()),
             proof_options,
+            event_handlers: EventHandlerRegistry::default(),
         }
     }
 
+    /// Sets the handlers for events emitted by user-defined code during the execution of the
+    /// transactions to be proven.
+    ///
+    /// These must be the same handlers which were used to execute the transactions.
+    pub fn with_event_handlers(mut self, event_handlers: EventHandlerRegistry) -> Self {
+        self.event_handlers = event_handlers;
+        self
+    }
+
     /// Loads the provided library code into the internal MAST forest store.
     ///
     /// TODO: this is a work-around to support accounts which were complied with user-defined
@@ -63,6 +84,85 @@ impl LocalTransactionProver {
     pub fn load_library(&mut self, library: &Library) {
         self.mast_store.insert(library.mast_forest().clone());
     }
+
+    /// Estimates the resources needed to prove the provided transaction with the proving options
+    /// of this prover.
+    ///
+    /// The lengths of the components of the execution trace (e.g., of the range checker and of the
+    /// chiplets) cannot be derived from the cycle counts of the transaction, and so the transaction
+    /// is executed again and its full [ExecutionTrace](vm_processor::ExecutionTrace) is built. The
+    /// time and memory needed to estimate the cost are therefore those of building the execution
+    /// trace, which is the first step of proving: only the low-degree extension of the trace, the
+    /// commitments to it and the construction of the proof are skipped.
+    ///
+    /// An [ExecutedTransaction](miden_objects::transaction::ExecutedTransaction) can be converted
+    /// into the required witness via [Into].
+    ///
+    /// # Errors
+    /// Returns an error if the transaction could not be executed.
+    pub fn estimate_cost(
+        &self,
+        tx_witness: TransactionWitness,
+    ) -> Result<ProvingCostEstimate, TransactionProverError> {
+        let TransactionWitness {
+            tx_inputs,
+            tx_args,
+            advice_witness,
+            account_codes,
+        } = tx_witness;
+
+        let (stack_inputs, mut host) =
+            self.prepare_execution(&tx_inputs, &tx_args, advice_witness, &account_codes)?;
+
+        let trace = vm_processor::execute(
+            &TransactionKernel::main(),
+            stack_inputs,
+            &mut host,
+            *self.proof_options.execution_options(),
+        )
+        .map_err(TransactionProverError::TransactionProgramExecutionFailed)?;
+
+        Ok(ProvingCostEstimate::new(&trace, &self.proof_options))
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Loads the code of the transaction into the MAST store, and returns the inputs to the
+    /// transaction kernel and the host to execute it with.
+    fn prepare_execution(
+        &self,
+        tx_inputs: &TransactionInputs,
+        tx_args: &TransactionArgs,
+        advice_witness: AdviceInputs,
+        account_codes: &[AccountCode],
+    ) -> Result<(StackInputs, TransactionHost<MemAdviceProvider>), TransactionProverError> {
+        for account_code in account_codes {
+            // load the code mast forest to the mast store
+            self.mast_store.load_account_code(account_code);
+        }
+
+        let (stack_inputs, advice_inputs) =
+            TransactionKernel::prepare_inputs(tx_inputs, tx_args, Some(advice_witness));
+        let advice_provider: MemAdviceProvider = advice_inputs.into();
+
+        // load the store with account/note/tx_script MASTs
+        self.mast_store.load_transaction_code(tx_inputs, tx_args);
+
+        let mut host = TransactionHost::new(
+            tx_inputs.account().into(),
+            advice_provider,
+            self.mast_store.clone(),
+            account_codes.iter().map(|c| c.commitment()).collect(),
+        )
+        .map_err(TransactionProverError::TransactionHostCreationFailed)?
+        .with_event_handlers(self.event_handlers.clone());
+        if let Some(code) = tx_args.new_account_code() {
+            host = host.with_new_account_code(code.clone());
+        }
+
+        Ok((stack_inputs, host))
+    }
 }
 
 impl Default for LocalTransactionProver {
@@ -70,6 +170,7 @@ impl Default for LocalTransactionProver {
         Self {
             mast_store: Arc::new(TransactionMastStore::new()),
             proof_options: Default::default(),
+            event_handlers: EventHandlerRegistry::default(),
         }
     }
 }
@@ -88,31 +189,13 @@ impl TransactionProver for LocalTransactionProver {
             account_codes,
         } = tx_witness;
 
-        for account_code in &account_codes {
-            // load the code mast forest to the mast store
-            self.mast_store.load_account_code(account_code);
-        }
-
         let account = tx_inputs.account();
         let input_notes = tx_inputs.input_notes();
         let block_hash = tx_inputs.block_header().hash();
 
         // execute and prove
-        let (stack_inputs, advice_inputs) =
-            TransactionKernel::prepare_inputs(&tx_inputs, &tx_args, Some(advice_witness));
-        let advice_provider: MemAdviceProvider = advice_inputs.into();
-
-        // load the store with account/note/tx_script MASTs
-        self.mast_store.load_transaction_code(&tx_inputs, &tx_args);
-
-        let mut host: TransactionHost<_> = TransactionHost::new(
-            account.into(),
-            advice_provider,
-            self.mast_store.clone(),
-            None,
-            account_codes.iter().map(|c| c.commitment()).collect(),
-        )
-        .map_err(TransactionProverError::TransactionHostCreationFailed)?;
+        let (stack_inputs, mut host) =
+            self.prepare_execution(&tx_inputs, &tx_args, advice_witness, &account_codes)?;
 
         let (stack_outputs, proof) = maybe_await!(prove(
             &TransactionKernel::main(),
@@ -123,8 +206,7 @@ impl TransactionProver for LocalTransactionProver {
         .map_err(TransactionProverError::TransactionProgramExecutionFailed)?;
 
         // extract transaction outputs and process transaction data
-        let (advice_provider, account_delta, output_notes, _signatures, _tx_progress) =
-            host.into_parts();
+        let (advice_provider, account_delta, output_notes, _tx_progress) = host.into_parts();
         let (_, map, _) = advice_provider.into_parts();
         let tx_outputs =
             TransactionKernel::from_transaction_parts(&stack_outputs, &map.into(), output_notes)
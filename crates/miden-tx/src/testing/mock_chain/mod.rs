@@ -1,7 +1,11 @@
 use alloc::{collections::BTreeMap, vec::Vec};
 
 use miden_lib::{
-    account::{auth::RpoFalcon512, faucets::BasicFungibleFaucet, wallets::BasicWallet},
+    account::{
+        auth::RpoFalcon512,
+        faucets::{BasicFungibleFaucet, BasicNonFungibleFaucet, RoleBasedFungibleFaucet},
+        wallets::BasicWallet,
+    },
     note::{create_p2id_note, create_p2idr_note},
     transaction::{memory, TransactionKernel},
 };
@@ -12,30 +16,27 @@ use miden_objects::{
     },
     asset::{Asset, FungibleAsset, TokenSymbol},
     block::{
-        compute_tx_hash, Block, BlockAccountUpdate, BlockHeader, BlockNoteIndex, BlockNoteTree,
-        BlockNumber, NoteBatch,
+        compute_tx_hash, AccountTree, Block, BlockAccountUpdate, BlockHeader, BlockNoteIndex,
+        BlockNoteTree, BlockNumber, NoteBatch, NullifierTree,
     },
     crypto::{
         dsa::rpo_falcon512::SecretKey,
-        merkle::{Mmr, MmrError, PartialMmr, Smt},
+        merkle::{Mmr, MmrError, PartialMmr},
     },
     note::{Note, NoteId, NoteInclusionProof, NoteType, Nullifier},
     testing::account_code::DEFAULT_AUTH_SCRIPT,
     transaction::{
-        ChainMmr, ExecutedTransaction, InputNote, InputNotes, OutputNote, ToInputNoteCommitments,
-        TransactionId, TransactionInputs, TransactionScript,
+        ChainMmr, ExecutedTransaction, ForeignAccountInputs, InputNote, InputNotes, OutputNote,
+        ToInputNoteCommitments, TransactionId, TransactionInputs, TransactionScript,
     },
-    AccountError, NoteError, ACCOUNT_TREE_DEPTH,
+    AccountError, NoteError,
 };
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
-use vm_processor::{
-    crypto::{RpoRandomCoin, SimpleSmt},
-    Digest, Felt, Word, ZERO,
-};
+use vm_processor::{crypto::RpoRandomCoin, Digest, Felt, Word, ZERO};
 
 use super::TransactionContextBuilder;
-use crate::auth::BasicAuthenticator;
+use crate::{auth::BasicAuthenticator, ChainStateReader};
 
 // CONSTANTS
 // ================================================================================================
@@ -258,10 +259,10 @@ pub struct MockChain {
     blocks: Vec<Block>,
 
     /// Tree containing the latest `Nullifier`'s tree.
-    nullifiers: Smt,
+    nullifiers: NullifierTree,
 
     /// Tree containing the latest hash of each account.
-    accounts: SimpleSmt<ACCOUNT_TREE_DEPTH>,
+    accounts: AccountTree,
 
     /// Objects that have not yet been finalized.
     ///
@@ -287,8 +288,8 @@ impl Default for MockChain {
         MockChain {
             chain: Mmr::default(),
             blocks: vec![],
-            nullifiers: Smt::default(),
-            accounts: SimpleSmt::<ACCOUNT_TREE_DEPTH>::new().expect("depth too big for SimpleSmt"),
+            nullifiers: NullifierTree::default(),
+            accounts: AccountTree::new(),
             pending_objects: PendingObjects::new(),
             available_notes: BTreeMap::new(),
             available_accounts: BTreeMap::new(),
@@ -506,6 +507,27 @@ impl MockChain {
         MockFungibleFaucet(account)
     }
 
+    /// Adds an existing (nonce == 1) non-fungible faucet with the specified authentication method.
+    pub fn add_existing_non_fungible_faucet(&mut self, auth_method: Auth) -> Account {
+        let account_builder = AccountBuilder::new(self.rng.gen())
+            .with_component(BasicNonFungibleFaucet)
+            .account_type(AccountType::NonFungibleFaucet);
+
+        self.add_from_account_builder(auth_method, account_builder, AccountState::Exists)
+    }
+
+    /// Adds an existing (nonce == 1) role-based fungible faucet built from the provided component.
+    ///
+    /// The faucet authorizes requests by the roles of their senders, so no authentication
+    /// component is added to it.
+    pub fn add_existing_role_based_faucet(&mut self, faucet: RoleBasedFungibleFaucet) -> Account {
+        let account_builder = AccountBuilder::new(self.rng.gen())
+            .with_component(faucet)
+            .account_type(AccountType::FungibleFaucet);
+
+        self.add_from_account_builder(Auth::NoAuth, account_builder, AccountState::Exists)
+    }
+
     /// Adds the [`AccountComponent`] corresponding to `auth_method` to the account in the builder
     /// and builds a new or existing account depending on `account_state`.
     ///
@@ -646,6 +668,15 @@ impl MockChain {
         .unwrap()
     }
 
+    /// Returns the [ForeignAccountInputs] of the specified account, authenticated against the
+    /// account root of the latest block.
+    pub fn get_foreign_account_inputs(&self, account_id: AccountId) -> ForeignAccountInputs {
+        let account = self.available_accounts.get(&account_id).unwrap().account();
+        let account_path = self.accounts.open(account_id).path().clone();
+
+        ForeignAccountInputs::from_account(account, account_path)
+    }
+
     // MODIFIERS
     // =========================================================================================
 
@@ -665,9 +696,19 @@ impl MockChain {
         let mut last_block: Option<Block> = None;
 
         for current_block_num in next_block_num..=target_block_num {
-            for update in self.pending_objects.updated_accounts.iter() {
-                self.accounts.insert(update.account_id().into(), *update.new_state_hash());
+            // later updates of an account override its earlier updates
+            let account_states: BTreeMap<AccountId, Digest> = self
+                .pending_objects
+                .updated_accounts
+                .iter()
+                .map(|update| (update.account_id(), update.new_state_hash()))
+                .collect();
+            self.accounts
+                .compute_mutations(account_states)
+                .and_then(|mutations| self.accounts.apply_mutations(mutations))
+                .expect("accounts of the mock chain should not share ID prefixes");
 
+            for update in self.pending_objects.updated_accounts.iter() {
                 if let Some(mock_account) = self.available_accounts.get(&update.account_id()) {
                     let account = match update.details() {
                         AccountUpdateDetails::New(acc) => acc.clone(),
@@ -685,10 +726,12 @@ impl MockChain {
             }
 
             // TODO: Implement nullifier tree reset once defined at the protocol level.
-            for nullifier in self.pending_objects.created_nullifiers.iter() {
-                self.nullifiers
-                    .insert(nullifier.inner(), [current_block_num.into(), ZERO, ZERO, ZERO]);
-            }
+            self.nullifiers
+                .mark_spent_many(
+                    self.pending_objects.created_nullifiers.iter().copied(),
+                    BlockNumber::from(current_block_num),
+                )
+                .expect("nullifiers should be spent once and after the genesis block");
             let notes_tree = self.pending_objects.build_notes_tree();
 
             let version = 0;
@@ -792,7 +835,7 @@ impl MockChain {
     }
 
     /// Gets a reference to the nullifier tree.
-    pub fn nullifiers(&self) -> &Smt {
+    pub fn nullifiers(&self) -> &NullifierTree {
         &self.nullifiers
     }
 
@@ -802,11 +845,25 @@ impl MockChain {
     }
 
     /// Get the reference to the accounts hash tree.
-    pub fn accounts(&self) -> &SimpleSmt<ACCOUNT_TREE_DEPTH> {
+    pub fn accounts(&self) -> &AccountTree {
         &self.accounts
     }
 }
 
+impl ChainStateReader for MockChain {
+    fn get_nullifier_block_num(&self, nullifier: Nullifier) -> Option<BlockNumber> {
+        self.nullifiers.get_block_num(&nullifier)
+    }
+
+    fn get_account_state_commitment(&self, account_id: AccountId) -> Option<Digest> {
+        self.accounts.get(account_id)
+    }
+
+    fn get_note_inclusion_proof(&self, note_id: NoteId) -> Option<NoteInclusionProof> {
+        self.available_notes.get(&note_id).and_then(|note| note.proof().cloned())
+    }
+}
+
 // HELPER TYPES
 // ================================================================================================
 
@@ -23,7 +23,9 @@ use miden_objects::{
         prepare_word,
         storage::prepare_assets,
     },
-    transaction::{OutputNote, TransactionArgs, TransactionInputs, TransactionScript},
+    transaction::{
+        ForeignAccountInputs, OutputNote, TransactionArgs, TransactionInputs, TransactionScript,
+    },
     vm::AdviceMap,
     FieldElement,
 };
@@ -73,6 +75,7 @@ pub struct TransactionContextBuilder {
     authenticator: Option<MockAuthenticator>,
     expected_output_notes: Vec<Note>,
     foreign_account_codes: Vec<AccountCode>,
+    foreign_accounts: Vec<ForeignAccountInputs>,
     input_notes: Vec<Note>,
     tx_script: Option<TransactionScript>,
     note_args: BTreeMap<NoteId, Word>,
@@ -95,6 +98,7 @@ impl TransactionContextBuilder {
             transaction_inputs: None,
             note_args: BTreeMap::new(),
             foreign_account_codes: vec![],
+            foreign_accounts: vec![],
         }
     }
 
@@ -122,6 +126,7 @@ impl TransactionContextBuilder {
             transaction_inputs: None,
             note_args: BTreeMap::new(),
             foreign_account_codes: vec![],
+            foreign_accounts: vec![],
         }
     }
 
@@ -173,6 +178,13 @@ impl TransactionContextBuilder {
         self
     }
 
+    /// Set the foreign accounts which can be fetched from the data store during transaction
+    /// execution
+    pub fn foreign_accounts(mut self, foreign_accounts: Vec<ForeignAccountInputs>) -> Self {
+        self.foreign_accounts = foreign_accounts;
+        self
+    }
+
     /// Extend the set of used input notes
     pub fn input_notes(mut self, input_notes: Vec<Note>) -> Self {
         self.input_notes.extend(input_notes);
@@ -667,6 +679,11 @@ impl TransactionContextBuilder {
             advice_inputs: self.advice_inputs,
             assembler: self.assembler,
             foreign_codes: self.foreign_account_codes,
+            foreign_accounts: self
+                .foreign_accounts
+                .into_iter()
+                .map(|account| (account.id(), account))
+                .collect(),
         }
     }
 }
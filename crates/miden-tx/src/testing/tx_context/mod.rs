@@ -1,15 +1,20 @@
 #[cfg(feature = "async")]
 use alloc::boxed::Box;
-use alloc::{rc::Rc, sync::Arc, vec::Vec};
+use alloc::{collections::BTreeMap, rc::Rc, sync::Arc, vec::Vec};
 
 use builder::MockAuthenticator;
 use miden_lib::transaction::TransactionKernel;
 use miden_objects::{
-    account::{Account, AccountCode, AccountId},
+    account::{Account, AccountCode, AccountId, StorageSlot},
     assembly::Assembler,
     block::BlockNumber,
+    crypto::merkle::SmtProof,
     note::{Note, NoteId},
-    transaction::{ExecutedTransaction, InputNote, InputNotes, TransactionArgs, TransactionInputs},
+    transaction::{
+        ExecutedTransaction, ForeignAccountInputs, InputNote, InputNotes, TransactionArgs,
+        TransactionInputs,
+    },
+    Digest,
 };
 use vm_processor::{AdviceInputs, ExecutionError, Process};
 use winter_maybe_async::*;
@@ -29,13 +34,14 @@ pub use builder::TransactionContextBuilder;
 #[derive(Clone)]
 /// Represents all needed data for executing a transaction, or arbitrary code.
 ///
-/// It implements [DataStore], so transactions may be executed with
-/// [TransactionExecutor](crate::TransactionExecutor)
+/// It provides a [DataStore] via [TransactionContext::get_data_store()], so transactions may be
+/// executed with [TransactionExecutor](crate::TransactionExecutor)
 pub struct TransactionContext {
     expected_output_notes: Vec<Note>,
     tx_args: TransactionArgs,
     tx_inputs: TransactionInputs,
     foreign_codes: Vec<AccountCode>,
+    foreign_accounts: BTreeMap<AccountId, ForeignAccountInputs>,
     advice_inputs: AdviceInputs,
     authenticator: Option<MockAuthenticator>,
     assembler: Assembler,
@@ -94,9 +100,10 @@ impl TransactionContext {
 
         let authenticator = self
             .authenticator
+            .clone()
             .map(|auth| Arc::new(auth) as Arc<dyn TransactionAuthenticator>);
 
-        let mut tx_executor = TransactionExecutor::new(Arc::new(self.tx_inputs), authenticator);
+        let mut tx_executor = TransactionExecutor::new(self.get_data_store(), authenticator);
 
         for code in self.foreign_codes {
             tx_executor.load_account_code(&code);
@@ -130,12 +137,31 @@ impl TransactionContext {
     }
 
     pub fn get_data_store(&self) -> Arc<dyn DataStore> {
-        Arc::new(self.tx_inputs().clone())
+        Arc::new(MockDataStore {
+            tx_inputs: self.tx_inputs.clone(),
+            foreign_accounts: self.foreign_accounts.clone(),
+        })
+    }
+}
+
+// MOCK DATA STORE
+// ================================================================================================
+
+/// A [DataStore] which serves the transaction inputs and foreign accounts of a
+/// [TransactionContext].
+struct MockDataStore {
+    tx_inputs: TransactionInputs,
+    foreign_accounts: BTreeMap<AccountId, ForeignAccountInputs>,
+}
+
+impl MockDataStore {
+    fn account(&self) -> &Account {
+        self.tx_inputs.account()
     }
 }
 
 #[maybe_async_trait]
-impl DataStore for TransactionInputs {
+impl DataStore for MockDataStore {
     #[maybe_async]
     fn get_transaction_inputs(
         &self,
@@ -144,9 +170,77 @@ impl DataStore for TransactionInputs {
         notes: &[NoteId],
     ) -> Result<TransactionInputs, DataStoreError> {
         assert_eq!(account_id, self.account().id());
-        assert_eq!(block_num, self.block_header().block_num());
-        assert_eq!(notes.len(), self.input_notes().num_notes());
+        assert_eq!(block_num, self.tx_inputs.block_header().block_num());
+        assert_eq!(notes.len(), self.tx_inputs.input_notes().num_notes());
+
+        Ok(self.tx_inputs.clone())
+    }
+
+    #[maybe_async]
+    fn get_foreign_account_inputs(
+        &self,
+        foreign_account_id: AccountId,
+        block_ref: BlockNumber,
+    ) -> Result<ForeignAccountInputs, DataStoreError> {
+        assert_eq!(block_ref, self.tx_inputs.block_header().block_num());
+
+        self.foreign_accounts
+            .get(&foreign_account_id)
+            .cloned()
+            .ok_or(DataStoreError::AccountNotFound(foreign_account_id))
+    }
+
+    #[maybe_async]
+    fn get_storage_map_witness(
+        &self,
+        account_id: AccountId,
+        map_root: Digest,
+        map_key: Digest,
+    ) -> Result<SmtProof, DataStoreError> {
+        let map = if account_id == self.account().id() {
+            self.account()
+                .storage()
+                .slots()
+                .iter()
+                .filter_map(|slot| match slot {
+                    StorageSlot::Map(map) => Some(map),
+                    StorageSlot::Value(_) => None,
+                })
+                .find(|map| map.root() == map_root)
+        } else {
+            self.foreign_accounts
+                .get(&account_id)
+                .ok_or(DataStoreError::AccountNotFound(account_id))?
+                .storage_maps()
+                .iter()
+                .find(|map| map.root() == map_root)
+        };
+
+        map.map(|map| map.open(&map_key)).ok_or_else(|| {
+            DataStoreError::other(format!(
+                "storage map with root {map_root} not found in account {account_id}"
+            ))
+        })
+    }
+
+    #[maybe_async]
+    fn get_vault_asset_witness(
+        &self,
+        account_id: AccountId,
+        vault_root: Digest,
+        vault_key: Digest,
+    ) -> Result<SmtProof, DataStoreError> {
+        if account_id != self.account().id() {
+            return Err(DataStoreError::AccountNotFound(account_id));
+        }
+
+        let vault = self.account().vault();
+        if vault.commitment() != vault_root {
+            return Err(DataStoreError::other(format!(
+                "asset vault with root {vault_root} not found in account {account_id}"
+            )));
+        }
 
-        Ok(self.clone())
+        Ok(vault.asset_tree().open(&vault_key))
     }
 }
@@ -2,9 +2,9 @@ use miden_lib::{
     errors::tx_kernel_errors::{
         ERR_ACCOUNT_ID_EPOCH_MUST_BE_LESS_THAN_U16_MAX,
         ERR_ACCOUNT_ID_LEAST_SIGNIFICANT_BYTE_MUST_BE_ZERO, ERR_ACCOUNT_ID_UNKNOWN_STORAGE_MODE,
-        ERR_ACCOUNT_ID_UNKNOWN_VERSION, TX_KERNEL_ERRORS,
+        ERR_ACCOUNT_ID_UNKNOWN_VERSION, ERR_STORAGE_SLOT_INDEX_OUT_OF_BOUNDS, TX_KERNEL_ERRORS,
     },
-    transaction::TransactionKernel,
+    transaction::{memory::NATIVE_ACCT_STORAGE_SLOTS_SECTION_PTR, TransactionKernel},
 };
 use miden_objects::{
     account::{
@@ -29,7 +29,10 @@ use rand_chacha::ChaCha20Rng;
 use vm_processor::{Digest, ExecutionError, MemAdviceProvider, ProcessState};
 
 use super::{Felt, StackInputs, Word, ONE, ZERO};
-use crate::testing::{executor::CodeExecutor, TransactionContextBuilder};
+use crate::{
+    assert_execution_error,
+    testing::{executor::CodeExecutor, TransactionContextBuilder},
+};
 
 // ACCOUNT CODE TESTS
 // ================================================================================================
@@ -490,6 +493,209 @@ fn test_set_map_item() {
     );
 }
 
+#[test]
+fn test_set_map_item_faucet_reserved_slot() {
+    // The component of the faucet is placed after the reserved slot, which holds the map of the
+    // issued non-fungible assets, so its local slot 0 is the account storage slot 1. Map updates
+    // of the component must land in its own map and leave the reserved map untouched.
+    let account = AccountBuilder::new(ChaCha20Rng::from_entropy().gen())
+        .account_type(AccountType::NonFungibleFaucet)
+        .with_component(
+            AccountMockComponent::new_with_slots(
+                TransactionKernel::testing_assembler(),
+                vec![AccountStorage::mock_item_2().slot],
+            )
+            .unwrap(),
+        )
+        .build_existing()
+        .unwrap();
+    let reserved_slot = account.storage().get_item(0).unwrap();
+
+    let tx_context = TransactionContextBuilder::new(account).build();
+
+    let (new_key, new_value) = (
+        Digest::new([Felt::new(109), Felt::new(110), Felt::new(111), Felt::new(112)]),
+        [Felt::new(9_u64), Felt::new(10_u64), Felt::new(11_u64), Felt::new(12_u64)],
+    );
+
+    let code = format!(
+        "
+        use.std::sys
+
+        use.test::account
+        use.kernel::prologue
+
+        begin
+            exec.prologue::prepare_transaction
+
+            # set the map item at the local index 0 of the component
+            push.{new_value}
+            push.{new_key}
+            push.0
+            call.account::set_map_item
+            dropw dropw
+
+            # assert the item has been set in the map of the component
+            push.{new_key}
+            push.0
+            call.account::get_map_item
+            push.{new_value}
+            assert_eqw
+
+            # truncate the stack
+            exec.sys::truncate_stack
+        end
+        ",
+        new_key = prepare_word(&new_key),
+        new_value = prepare_word(&new_value),
+    );
+
+    let process = &tx_context.execute_code(&code).unwrap();
+    let process_state: ProcessState = process.into();
+
+    // the map of the component must have been updated and the reserved slot must be left
+    // untouched
+    let mut new_storage_map = AccountStorage::mock_map();
+    new_storage_map.insert(new_key, new_value);
+
+    let storage_slot_ptr = |index: u32| {
+        NATIVE_ACCT_STORAGE_SLOTS_SECTION_PTR
+            + index * StorageSlot::NUM_ELEMENTS_PER_STORAGE_SLOT as u32
+    };
+    assert_eq!(
+        process_state
+            .get_mem_word(process_state.ctx(), storage_slot_ptr(1))
+            .unwrap()
+            .unwrap(),
+        Word::from(new_storage_map.root()),
+    );
+    assert_eq!(
+        process_state
+            .get_mem_word(process_state.ctx(), storage_slot_ptr(0))
+            .unwrap()
+            .unwrap(),
+        Word::from(reserved_slot),
+    );
+
+    // the component cannot address any slot outside of its own storage
+    let code = format!(
+        "
+        use.test::account
+        use.kernel::prologue
+
+        begin
+            exec.prologue::prepare_transaction
+
+            push.{new_value}
+            push.{new_key}
+            push.1
+            call.account::set_map_item
+        end
+        ",
+        new_key = prepare_word(&new_key),
+        new_value = prepare_word(&new_value),
+    );
+
+    let process = tx_context.execute_code(&code);
+    assert_execution_error!(process, ERR_STORAGE_SLOT_INDEX_OUT_OF_BOUNDS);
+}
+
+#[test]
+fn test_set_item_faucet_reserved_slot() {
+    // The component of the faucet is placed after the reserved slot, so its local slot 0 is the
+    // account storage slot 1. The reserved slot check must be applied to the offset index, which
+    // lets the component write its own slot 0 without giving it access to the reserved slot.
+    let account = AccountBuilder::new(ChaCha20Rng::from_entropy().gen())
+        .account_type(AccountType::FungibleFaucet)
+        .with_component(
+            AccountMockComponent::new_with_slots(
+                TransactionKernel::testing_assembler(),
+                vec![StorageSlot::Value(Word::default())],
+            )
+            .unwrap(),
+        )
+        .build_existing()
+        .unwrap();
+    let reserved_slot = account.storage().get_item(0).unwrap();
+
+    let tx_context = TransactionContextBuilder::new(account).build();
+
+    let new_storage_item: Word = [Felt::new(91), Felt::new(92), Felt::new(93), Felt::new(94)];
+
+    let code = format!(
+        "
+        use.std::sys
+
+        use.test::account
+        use.kernel::prologue
+
+        begin
+            exec.prologue::prepare_transaction
+
+            # set the storage item at the local index 0 of the component
+            push.{new_storage_item}
+            push.0
+            call.account::set_item
+            dropw dropw
+
+            # assert the item has been set at the local index 0 of the component
+            push.0
+            call.account::get_item
+            push.{new_storage_item}
+            assert_eqw
+
+            # truncate the stack
+            exec.sys::truncate_stack
+        end
+        ",
+        new_storage_item = prepare_word(&new_storage_item),
+    );
+
+    let process = &tx_context.execute_code(&code).unwrap();
+    let process_state: ProcessState = process.into();
+
+    // the item must have been written to the account storage slot 1 and the reserved slot must be
+    // left untouched
+    let storage_slot_ptr = |index: u32| {
+        NATIVE_ACCT_STORAGE_SLOTS_SECTION_PTR
+            + index * StorageSlot::NUM_ELEMENTS_PER_STORAGE_SLOT as u32
+    };
+    assert_eq!(
+        process_state
+            .get_mem_word(process_state.ctx(), storage_slot_ptr(1))
+            .unwrap()
+            .unwrap(),
+        new_storage_item,
+    );
+    assert_eq!(
+        process_state
+            .get_mem_word(process_state.ctx(), storage_slot_ptr(0))
+            .unwrap()
+            .unwrap(),
+        Word::from(reserved_slot),
+    );
+
+    // the component cannot address any slot outside of its own storage
+    let code = format!(
+        "
+        use.test::account
+        use.kernel::prologue
+
+        begin
+            exec.prologue::prepare_transaction
+
+            push.{new_storage_item}
+            push.1
+            call.account::set_item
+        end
+        ",
+        new_storage_item = prepare_word(&new_storage_item),
+    );
+
+    let process = tx_context.execute_code(&code);
+    assert_execution_error!(process, ERR_STORAGE_SLOT_INDEX_OUT_OF_BOUNDS);
+}
+
 #[test]
 fn test_account_component_storage_offset() {
     // setup assembler
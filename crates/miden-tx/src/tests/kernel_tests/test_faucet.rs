@@ -174,8 +174,6 @@ fn test_mint_fungible_asset_fails_saturate_max_amount() {
 // NON-FUNGIBLE FAUCET MINT TESTS
 // ================================================================================================
 
-// TODO: reenable once storage map support is implemented
-#[ignore]
 #[test]
 fn test_mint_non_fungible_asset_succeeds() {
     let tx_context = TransactionContextBuilder::with_non_fungible_faucet(
@@ -189,19 +187,17 @@ fn test_mint_non_fungible_asset_succeeds() {
 
     let code = format!(
         "
-        use.std::collections::smt
-
-        use.kernel::account
+        use.kernel::account->kernel_account
         use.kernel::asset_vault
         use.kernel::memory
         use.kernel::prologue
-        use.miden::faucet
+        use.test::account
 
         begin
             # mint asset
             exec.prologue::prepare_transaction
             push.{non_fungible_asset}
-            exec.faucet::mint
+            call.account::mint
 
             # assert the correct asset is returned
             push.{non_fungible_asset}
@@ -213,16 +209,16 @@ fn test_mint_non_fungible_asset_succeeds() {
             exec.asset_vault::has_non_fungible_asset
             assert
 
-            # assert the non-fungible asset has been added to the faucet smt
+            # assert the non-fungible asset has been added to the faucet storage map
+            push.{vault_key}
             push.{FAUCET_STORAGE_DATA_SLOT}
-            exec.account::get_item
-            push.{non_fungible_asset}
-            exec.smt::get
+            exec.kernel_account::get_map_item
             push.{non_fungible_asset}
             assert_eqw
         end
         ",
-        non_fungible_asset = prepare_word(&non_fungible_asset.into())
+        non_fungible_asset = prepare_word(&non_fungible_asset.into()),
+        vault_key = prepare_word(&non_fungible_asset.vault_key()),
     );
 
     tx_context.execute_code(&code).unwrap();
@@ -461,8 +457,6 @@ fn test_burn_fungible_asset_insufficient_input_amount() {
 // NON-FUNGIBLE FAUCET BURN TESTS
 // ================================================================================================
 
-// TODO: reenable once storage map support is implemented
-#[ignore]
 #[test]
 fn test_burn_non_fungible_asset_succeeds() {
     let tx_context = TransactionContextBuilder::with_non_fungible_faucet(
@@ -472,23 +466,23 @@ fn test_burn_non_fungible_asset_succeeds() {
     )
     .build();
 
-    let non_fungible_asset_burnt = NonFungibleAsset::mock(&[1, 2, 3]);
+    let non_fungible_asset_burnt = NonFungibleAsset::mock(&NON_FUNGIBLE_ASSET_DATA);
 
+    // the burnt asset must be provided as input to the transaction, and so it is minted first
     let code = format!(
         "
-        use.std::collections::smt
-
-        use.kernel::account
+        use.kernel::account->kernel_account
         use.kernel::asset_vault
         use.kernel::memory
         use.kernel::prologue
-        use.miden::faucet
+        use.test::account
 
         begin
-            # burn asset
+            # mint and burn asset
             exec.prologue::prepare_transaction
             push.{non_fungible_asset}
-            exec.faucet::burn
+            call.account::mint
+            call.account::burn
 
             # assert the correct asset is returned
             push.{non_fungible_asset}
@@ -500,16 +494,16 @@ fn test_burn_non_fungible_asset_succeeds() {
             exec.asset_vault::has_non_fungible_asset
             not assert
 
-            # assert the non-fungible asset has been removed from the faucet smt
+            # assert the non-fungible asset has been removed from the faucet storage map
+            push.{vault_key}
             push.{FAUCET_STORAGE_DATA_SLOT}
-            exec.account::get_item
-            push.{non_fungible_asset}
-            exec.smt::get
+            exec.kernel_account::get_map_item
             padw
             assert_eqw
         end
         ",
-        non_fungible_asset = prepare_word(&non_fungible_asset_burnt.into())
+        non_fungible_asset = prepare_word(&non_fungible_asset_burnt.into()),
+        vault_key = prepare_word(&non_fungible_asset_burnt.vault_key()),
     );
 
     tx_context.execute_code(&code).unwrap();
@@ -620,6 +614,85 @@ fn test_burn_non_fungible_asset_fails_inconsistent_faucet_id() {
     assert_execution_error!(process, ERR_FAUCET_NON_FUNGIBLE_ASSET_TO_BURN_NOT_FOUND);
 }
 
+// IS NON-FUNGIBLE ASSET ISSUED TESTS
+// ================================================================================================
+
+#[test]
+fn test_is_non_fungible_asset_issued_succeeds() {
+    let tx_context = TransactionContextBuilder::with_non_fungible_faucet(
+        NonFungibleAsset::mock_issuer().into(),
+        ONE,
+        false,
+    )
+    .build();
+
+    let issued_asset = NonFungibleAsset::mock(&NON_FUNGIBLE_ASSET_DATA_2);
+    let non_issued_asset = NonFungibleAsset::mock(&NON_FUNGIBLE_ASSET_DATA);
+
+    let code = format!(
+        "
+        use.kernel::prologue
+        use.miden::faucet
+        use.test::account
+
+        begin
+            exec.prologue::prepare_transaction
+
+            # assert the issued asset is reported as issued
+            push.{issued_asset}
+            exec.faucet::is_non_fungible_asset_issued
+            assert
+
+            # assert the non-issued asset is reported as not issued
+            push.{non_issued_asset}
+            exec.faucet::is_non_fungible_asset_issued
+            assertz
+
+            # assert the asset is reported as issued once it is minted
+            push.{non_issued_asset}
+            call.account::mint dropw
+            push.{non_issued_asset}
+            exec.faucet::is_non_fungible_asset_issued
+            assert
+        end
+        ",
+        issued_asset = prepare_word(&issued_asset.into()),
+        non_issued_asset = prepare_word(&non_issued_asset.into()),
+    );
+
+    tx_context.execute_code(&code).unwrap();
+}
+
+#[test]
+fn test_is_non_fungible_asset_issued_fails_inconsistent_faucet_id() {
+    let tx_context = TransactionContextBuilder::with_non_fungible_faucet(
+        ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN_1,
+        ONE,
+        false,
+    )
+    .build();
+
+    let non_fungible_asset = NonFungibleAsset::mock(&NON_FUNGIBLE_ASSET_DATA);
+
+    let code = format!(
+        "
+        use.kernel::prologue
+        use.miden::faucet
+
+        begin
+            exec.prologue::prepare_transaction
+            push.{non_fungible_asset}
+            exec.faucet::is_non_fungible_asset_issued
+        end
+        ",
+        non_fungible_asset = prepare_word(&non_fungible_asset.into()),
+    );
+
+    let process = tx_context.execute_code(&code);
+
+    assert_execution_error!(process, ERR_NON_FUNGIBLE_ASSET_FAUCET_IS_NOT_ORIGIN);
+}
+
 // GET TOTAL ISSUANCE TESTS
 // ================================================================================================
 
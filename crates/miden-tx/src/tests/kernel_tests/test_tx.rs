@@ -1,6 +1,7 @@
 use alloc::vec::Vec;
 use std::string::{String, ToString};
 
+use assert_matches::assert_matches;
 use miden_lib::{
     errors::tx_kernel_errors::{
         ERR_NON_FUNGIBLE_ASSET_ALREADY_EXISTS, ERR_TX_NUMBER_OF_OUTPUT_NOTES_EXCEEDS_LIMIT,
@@ -23,7 +24,6 @@ use miden_objects::{
         StorageSlot,
     },
     asset::NonFungibleAsset,
-    crypto::merkle::{LeafIndex, MerklePath},
     note::{
         Note, NoteAssets, NoteExecutionHint, NoteExecutionMode, NoteInputs, NoteMetadata,
         NoteRecipient, NoteTag, NoteType,
@@ -35,8 +35,8 @@ use miden_objects::{
         prepare_word,
         storage::STORAGE_LEAVES_2,
     },
-    transaction::{OutputNote, OutputNotes, TransactionScript},
-    FieldElement, ACCOUNT_TREE_DEPTH,
+    transaction::{OutputNote, OutputNotes, TransactionScript, TransactionWitness},
+    FieldElement,
 };
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
@@ -47,7 +47,8 @@ use crate::{
     assert_execution_error,
     testing::{MockChain, TransactionContextBuilder},
     tests::kernel_tests::{read_root_mem_word, try_read_root_mem_word},
-    TransactionExecutor,
+    DataStoreError, LocalTransactionProver, TransactionExecutor, TransactionExecutorError,
+    TransactionProver,
 };
 
 #[test]
@@ -1074,6 +1075,230 @@ fn test_fpi_execute_foreign_procedure() {
         .unwrap();
 }
 
+/// Test that the data of foreign accounts is fetched from the data store when it is not provided
+/// via the advice inputs.
+///
+/// It checks that the executor fetches the foreign account inputs, loads the code of the foreign
+/// account and includes it in the executed transaction, and that an error is returned if the data
+/// store does not contain the requested foreign account.
+#[test]
+fn test_fpi_execute_foreign_procedure_with_data_store() {
+    let storage_slots = vec![AccountStorage::mock_item_0().slot];
+    let foreign_account_code_source = "
+        use.miden::account
+
+        export.get_item_foreign
+            # make this foreign procedure unique to make sure that we invoke the procedure of the 
+            # foreign account, not the native one
+            push.1 drop
+            exec.account::get_item
+
+            # truncate the stack
+            movup.6 movup.6 movup.6 drop drop drop
+        end
+    ";
+
+    let foreign_account_component = AccountComponent::compile(
+        foreign_account_code_source,
+        TransactionKernel::testing_assembler(),
+        storage_slots,
+    )
+    .unwrap()
+    .with_supports_all_types();
+
+    let foreign_account = AccountBuilder::new(ChaCha20Rng::from_entropy().gen())
+        .with_component(foreign_account_component)
+        .build_existing()
+        .unwrap();
+
+    let native_account = AccountBuilder::new(ChaCha20Rng::from_entropy().gen())
+        .with_component(
+            AccountMockComponent::new_with_slots(TransactionKernel::testing_assembler(), vec![])
+                .unwrap(),
+        )
+        .build_existing()
+        .unwrap();
+
+    let mut mock_chain =
+        MockChain::with_accounts(&[native_account.clone(), foreign_account.clone()]);
+    mock_chain.seal_block(None);
+    let foreign_account_inputs = mock_chain.get_foreign_account_inputs(foreign_account.id());
+
+    let code = format!(
+        "
+        use.std::sys
+
+        use.miden::tx
+
+        begin
+            # pad the stack for the `execute_foreign_procedure`execution
+            padw padw padw push.0.0
+            # => [pad(14)]
+
+            # push the index of desired storage item
+            push.0
+
+            # get the hash of the `get_item` account procedure
+            push.{get_item_foreign_hash}
+
+            # push the foreign account ID
+            push.{foreign_suffix}.{foreign_prefix}
+            # => [foreign_account_id_prefix, foreign_account_id_suffix, FOREIGN_PROC_ROOT, storage_item_index, pad(14)]
+
+            exec.tx::execute_foreign_procedure
+            # => [STORAGE_VALUE]
+
+            # assert the correctness of the obtained value
+            push.1.2.3.4 assert_eqw
+            # => []
+
+            # truncate the stack
+            exec.sys::truncate_stack
+        end
+        ",
+        foreign_prefix = foreign_account.id().prefix().as_felt(),
+        foreign_suffix = foreign_account.id().suffix(),
+        get_item_foreign_hash = foreign_account.code().procedures()[0].mast_root(),
+    );
+
+    let tx_script =
+        TransactionScript::compile(code, vec![], TransactionKernel::testing_assembler()).unwrap();
+
+    // the data of the foreign account is fetched from the data store
+    let executed_transaction = mock_chain
+        .build_tx_context(native_account.id(), &[], &[])
+        .foreign_accounts(vec![foreign_account_inputs])
+        .tx_script(tx_script.clone())
+        .build()
+        .execute()
+        .unwrap();
+
+    let tx_witness = TransactionWitness::from(executed_transaction);
+    assert!(tx_witness.account_codes.contains(foreign_account.code()));
+
+    // the data of the foreign account fetched during execution is part of the transaction witness
+    LocalTransactionProver::default().prove(tx_witness).unwrap();
+
+    // execution fails if the data store does not contain the foreign account
+    let result = mock_chain
+        .build_tx_context(native_account.id(), &[], &[])
+        .tx_script(tx_script)
+        .build()
+        .execute();
+
+    assert_matches!(
+        result,
+        Err(TransactionExecutorError::FetchForeignAccountInputsFailed(
+            account_id,
+            DataStoreError::AccountNotFound(_)
+        )) if account_id == foreign_account.id()
+    );
+}
+
+#[test]
+fn test_fpi_lazy_loading_foreign_storage_map() {
+    let storage_slots = vec![AccountStorage::mock_item_2().slot];
+    let foreign_account_code_source = "
+        use.miden::account
+
+        export.get_map_item_foreign
+            # make this foreign procedure unique to make sure that we invoke the procedure of the 
+            # foreign account, not the native one
+            push.2 drop
+            exec.account::get_map_item
+        end
+    ";
+
+    let foreign_account_component = AccountComponent::compile(
+        foreign_account_code_source,
+        TransactionKernel::testing_assembler(),
+        storage_slots,
+    )
+    .unwrap()
+    .with_supports_all_types();
+
+    let foreign_account = AccountBuilder::new(ChaCha20Rng::from_entropy().gen())
+        .with_component(foreign_account_component)
+        .build_existing()
+        .unwrap();
+
+    let native_account = AccountBuilder::new(ChaCha20Rng::from_entropy().gen())
+        .with_component(
+            AccountMockComponent::new_with_slots(TransactionKernel::testing_assembler(), vec![])
+                .unwrap(),
+        )
+        .build_existing()
+        .unwrap();
+
+    let mut mock_chain =
+        MockChain::with_accounts(&[native_account.clone(), foreign_account.clone()]);
+    mock_chain.seal_block(None);
+    let foreign_account_inputs = mock_chain.get_foreign_account_inputs(foreign_account.id());
+
+    let code = format!(
+        "
+        use.std::sys
+
+        use.miden::tx
+
+        begin
+            # pad the stack for the `execute_foreign_procedure` execution
+            padw padw push.0.0
+            # => [pad(10)]
+
+            # push the key of desired storage item
+            push.{map_key}
+
+            # push the index of desired storage item
+            push.0
+
+            # get the hash of the `get_map_item_foreign` account procedure
+            push.{get_map_item_foreign_hash}
+
+            # push the foreign account ID
+            push.{foreign_suffix}.{foreign_prefix}
+            # => [foreign_account_id_prefix, foreign_account_id_suffix, FOREIGN_PROC_ROOT, storage_item_index, MAP_ITEM_KEY, pad(10)]
+
+            exec.tx::execute_foreign_procedure
+            # => [MAP_VALUE]
+
+            # assert the correctness of the obtained value
+            push.{map_value} assert_eqw
+            # => []
+
+            # truncate the stack
+            exec.sys::truncate_stack
+        end
+        ",
+        foreign_prefix = foreign_account.id().prefix().as_felt(),
+        foreign_suffix = foreign_account.id().suffix(),
+        map_key = STORAGE_LEAVES_2[0].0,
+        map_value = prepare_word(&STORAGE_LEAVES_2[0].1),
+        get_map_item_foreign_hash = foreign_account.code().procedures()[0].mast_root(),
+    );
+
+    let tx_script =
+        TransactionScript::compile(code, vec![], TransactionKernel::testing_assembler()).unwrap();
+
+    let tx_context = mock_chain
+        .build_tx_context(native_account.id(), &[], &[])
+        .foreign_accounts(vec![foreign_account_inputs])
+        .tx_script(tx_script)
+        .build();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    // the foreign account is loaded without its storage map, and the accessed map entry is fetched
+    // from the data store when it is read
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None).with_lazy_loading();
+    let executed_transaction = executor
+        .execute_transaction(native_account.id(), block_ref, &[], tx_context.tx_args().clone())
+        .unwrap();
+
+    // the map entry fetched during execution is part of the transaction witness
+    let tx_witness = TransactionWitness::from(executed_transaction);
+    LocalTransactionProver::default().prove(tx_witness).unwrap();
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -1087,14 +1312,7 @@ fn get_mock_fpi_adv_inputs(foreign_account: &Account, mock_chain: &MockChain) ->
         // Provide the merkle path of the foreign account to be able to verify that the account
         // database has the hash of this foreign account. Verification is done during the
         // execution of the `kernel::account::validate_current_foreign_account` procedure.
-        &MerklePath::new(
-            mock_chain
-                .accounts()
-                  // TODO: Update.
-                .open(&LeafIndex::<ACCOUNT_TREE_DEPTH>::new(foreign_account.id().prefix().as_felt().as_int()).unwrap())
-                .path
-                .into(),
-        ),
+        mock_chain.accounts().open(foreign_account.id()).path(),
     )
     .unwrap();
 
@@ -1,19 +1,36 @@
 use alloc::{
     collections::{BTreeMap, BTreeSet},
-    string::String,
+    string::{String, ToString},
     sync::Arc,
     vec::Vec,
 };
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
 
 use ::assembly::{
     ast::{Module, ModuleKind},
     LibraryPath,
 };
-use miden_lib::transaction::TransactionKernel;
+use assert_matches::assert_matches;
+use miden_lib::{
+    account::{auth::RpoFalcon512Multisig, wallets::BasicWallet},
+    errors::tx_kernel_errors::{
+        ERR_ACCOUNT_CODE_IS_NOT_UPDATABLE, ERR_EPILOGUE_TOTAL_NUMBER_OF_ASSETS_MUST_STAY_THE_SAME,
+        ERR_MULTISIG_THRESHOLD_NOT_MET, ERR_P2ID_TARGET_ACCT_MISMATCH,
+    },
+    transaction::{TransactionKernel, TransactionTrace},
+};
 use miden_objects::{
-    account::{AccountBuilder, AccountComponent, AccountStorage, StorageSlot},
+    account::{
+        AccountBuilder, AccountCode, AccountComponent, AccountId, AccountStorage, AccountType,
+        AuthSecretKey, StorageSlot,
+    },
     assembly::DefaultSourceManager,
     asset::{Asset, AssetVault, FungibleAsset, NonFungibleAsset},
+    batch::{ProposedBatch, ProvenBatch},
+    crypto::dsa::rpo_falcon512::SecretKey,
     note::{
         Note, NoteAssets, NoteExecutionHint, NoteExecutionMode, NoteHeader, NoteId, NoteInputs,
         NoteMetadata, NoteRecipient, NoteScript, NoteTag, NoteType,
@@ -25,26 +42,39 @@ use miden_objects::{
             ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
         },
         constants::{FUNGIBLE_ASSET_AMOUNT, NON_FUNGIBLE_ASSET_DATA},
-        note::DEFAULT_NOTE_CODE,
+        note::{NoteBuilder, DEFAULT_NOTE_CODE},
         prepare_word,
-        storage::{STORAGE_INDEX_0, STORAGE_INDEX_2},
+        storage::{STORAGE_INDEX_0, STORAGE_INDEX_2, STORAGE_LEAVES_2},
     },
-    transaction::{ProvenTransaction, TransactionArgs, TransactionScript},
-    Felt, Word, MIN_PROOF_SECURITY_LEVEL,
+    transaction::{
+        ExecutedTransaction, ProvenTransaction, TransactionArgs, TransactionHeader,
+        TransactionScript, TransactionWitness,
+    },
+    Felt, ProposedBatchError, Word, MIN_PROOF_SECURITY_LEVEL,
 };
 use miden_prover::ProvingOptions;
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
 use vm_processor::{
     utils::{Deserializable, Serializable},
-    Digest, MemAdviceProvider, ONE,
+    Digest, ExecutionError, MemAdviceProvider, ONE,
 };
 
 use super::{
-    LocalTransactionProver, TransactionExecutor, TransactionHost, TransactionProver,
-    TransactionVerifier,
+    BatchVerifier, LocalBatchProver, LocalTransactionProver, NoteConsumptionChecker,
+    TransactionExecutor, TransactionHost, TransactionProver, TransactionVerifier,
+};
+use crate::{
+    auth::{
+        BasicAuthenticator, PartiallySignedTransaction, RemoteAuthenticator, SignerServer,
+        SigningRequest, TransactionAuthenticator,
+    },
+    debugger::{Breakpoint, DebuggerStop},
+    host::{EventContext, EventHandler, EventHandlerRegistry},
+    testing::{Auth, MockChain, TransactionContextBuilder},
+    AuthenticationError, BatchProverError, BatchVerifierError, EventHandlerError,
+    TransactionExecutorError, TransactionMastStore, TransactionVerifierError,
 };
-use crate::{testing::TransactionContextBuilder, TransactionMastStore};
 
 mod kernel_tests;
 
@@ -92,7 +122,6 @@ fn transaction_executor_witness() {
         tx_inputs.account().into(),
         mem_advice_provider,
         mast_store,
-        None,
         BTreeSet::new(),
     )
     .unwrap();
@@ -104,7 +133,7 @@ fn transaction_executor_witness() {
     )
     .unwrap();
 
-    let (advice_provider, _, output_notes, _signatures, _tx_progress) = host.into_parts();
+    let (advice_provider, _, output_notes, _tx_progress) = host.into_parts();
     let (_, map, _) = advice_provider.into_parts();
     let tx_outputs = TransactionKernel::from_transaction_parts(
         result.stack_outputs(),
@@ -413,6 +442,109 @@ fn test_empty_delta_nonce_update() {
     assert_eq!(executed_transaction.account_delta().storage().maps().len(), 0);
 }
 
+/// Tests that an account with updatable code can set its code during a transaction, and that the
+/// new code is recorded in the account delta.
+#[test]
+fn executed_transaction_account_code_update() {
+    let upgradable_component = AccountComponent::compile(
+        "
+        use.miden::account
+
+        export.upgrade
+            exec.account::set_code
+            # => []
+
+            push.1 exec.account::incr_nonce
+            # => []
+        end
+        ",
+        TransactionKernel::testing_assembler(),
+        vec![],
+    )
+    .unwrap()
+    .with_supports_all_types();
+
+    let new_component = AccountComponent::compile(
+        "
+        export.foo
+            push.1 drop
+        end
+        ",
+        TransactionKernel::testing_assembler(),
+        vec![],
+    )
+    .unwrap()
+    .with_supports_all_types();
+    let new_code =
+        AccountCode::from_components(&[new_component], AccountType::RegularAccountUpdatableCode)
+            .unwrap();
+
+    let tx_script = TransactionScript::compile(
+        format!(
+            "
+            begin
+                push.{new_code_commitment}
+                call.{upgrade}
+                dropw
+            end
+            ",
+            new_code_commitment = new_code.commitment(),
+            upgrade = upgradable_component.library().mast_forest()[upgradable_component
+                .library()
+                .get_export_node_id(upgradable_component.library().exports().next().unwrap())]
+            .digest(),
+        ),
+        [],
+        TransactionKernel::testing_assembler(),
+    )
+    .unwrap();
+
+    let execute = |account_type: AccountType, tx_args: TransactionArgs| {
+        let account = AccountBuilder::new(ChaCha20Rng::from_entropy().gen())
+            .account_type(account_type)
+            .with_component(upgradable_component.clone())
+            .build_existing()
+            .unwrap();
+        let tx_context = TransactionContextBuilder::new(account).build();
+        let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+        let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+        executor.execute_transaction(tx_context.account().id(), block_ref, &[], tx_args)
+    };
+
+    let tx_args =
+        TransactionArgs::with_tx_script(tx_script.clone()).with_new_account_code(new_code.clone());
+    let executed_transaction =
+        execute(AccountType::RegularAccountUpdatableCode, tx_args.clone()).unwrap();
+
+    assert_eq!(executed_transaction.account_delta().code(), Some(&new_code));
+    assert_eq!(executed_transaction.final_account().code_commitment(), new_code.commitment());
+
+    let mut account = executed_transaction.initial_account().clone();
+    account.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(account.code(), &new_code);
+    assert_eq!(account.hash(), executed_transaction.final_account().hash());
+
+    // the new code must be provided with the transaction arguments
+    assert_matches!(
+        execute(
+            AccountType::RegularAccountUpdatableCode,
+            TransactionArgs::with_tx_script(tx_script)
+        ),
+        Err(TransactionExecutorError::TransactionProgramExecutionFailed(
+            ExecutionError::EventError(_)
+        ))
+    );
+
+    // the code of accounts with immutable code cannot be set
+    assert_matches!(
+        execute(AccountType::RegularAccountImmutableCode, tx_args),
+        Err(TransactionExecutorError::TransactionProgramExecutionFailed(
+            ExecutionError::FailedAssertion { err_code, .. }
+        )) if err_code == ERR_ACCOUNT_CODE_IS_NOT_UPDATABLE
+    );
+}
+
 #[test]
 fn test_send_note_proc() {
     let tx_context = TransactionContextBuilder::with_standard_account(ONE)
@@ -832,6 +964,173 @@ fn prove_witness_and_verify() {
     assert!(verifier.verify(proven_transaction).is_ok());
 }
 
+#[test]
+fn estimate_proving_cost() {
+    let tx_witness: TransactionWitness = execute_mock_transaction().into();
+    let prover = LocalTransactionProver::new(ProvingOptions::default());
+    let estimate = prover.estimate_cost(tx_witness.clone()).unwrap();
+    let proven_transaction = prover.prove(tx_witness).unwrap();
+
+    // the trace length is estimated exactly, and the proof size is an upper bound
+    let proof = &proven_transaction.proof().proof;
+    assert_eq!(estimate.padded_trace_len(), proof.trace_info().length());
+    assert_eq!(estimate.lde_domain_size(), proof.lde_domain_size());
+    assert!(proof.to_bytes().len() <= estimate.proof_size_bytes());
+}
+
+#[test]
+fn verify_many_transactions() {
+    let executed_transaction = execute_mock_transaction();
+    let prover = LocalTransactionProver::new(ProvingOptions::default());
+    let proven_transaction = prover.prove(executed_transaction.into()).unwrap();
+
+    // a result keyed by the transaction ID is returned for every provided transaction, in the
+    // order of the transactions, including for duplicate transactions
+    let tx_id = proven_transaction.id();
+    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+    let results = verifier.verify_many([proven_transaction.clone(), proven_transaction.clone()]);
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|(id, result)| *id == tx_id && result.is_ok()));
+
+    let results = TransactionVerifier::new(u32::MAX).verify_many([proven_transaction]);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].0, tx_id);
+    assert_matches!(
+        results[0].1,
+        Err(TransactionVerifierError::InsufficientProofSecurityLevel { .. })
+    );
+
+    assert!(verifier.verify_many([]).is_empty());
+}
+
+#[test]
+fn prove_batch_and_verify() {
+    let executed_transaction = execute_mock_transaction();
+    let prover = LocalTransactionProver::new(ProvingOptions::default());
+    let proven_transaction = prover.prove(executed_transaction.into()).unwrap();
+    let tx_id = proven_transaction.id();
+
+    let proposed_batch = ProposedBatch::new(vec![proven_transaction.clone()]).unwrap();
+    let batch_prover = LocalBatchProver::new(MIN_PROOF_SECURITY_LEVEL);
+    let proven_batch = batch_prover.prove(proposed_batch.clone()).unwrap();
+
+    assert_eq!(proven_batch.id(), proposed_batch.id());
+    assert_eq!(proven_batch.transactions(), [TransactionHeader::from(&proven_transaction)]);
+    assert_eq!(proven_batch.input_notes(), proposed_batch.input_notes());
+    assert_eq!(proven_batch.output_notes_tree().root(), proposed_batch.batch_note_tree().root());
+
+    // a batch is not proven if the proof of any of its transactions is not accepted
+    assert_matches!(
+        LocalBatchProver::new(u32::MAX).prove(proposed_batch.clone()),
+        Err(BatchProverError::TransactionVerificationFailed {
+            transaction_id,
+            source: TransactionVerifierError::InsufficientProofSecurityLevel { .. },
+        }) if transaction_id == tx_id
+    );
+
+    let proven_batch = ProvenBatch::read_from_bytes(&proven_batch.to_bytes()).unwrap();
+    let verifier = BatchVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+    verifier.verify(&proven_batch, vec![proven_transaction.clone()]).unwrap();
+
+    // a batch whose contents do not match its transactions is rejected
+    let (_, id, account_updates, input_notes, _, expiration_block_num) =
+        proposed_batch.into_parts();
+    let tampered_batch = ProvenBatch::new(
+        id,
+        proven_batch.transactions().to_vec(),
+        account_updates,
+        input_notes,
+        Vec::new(),
+        expiration_block_num,
+    );
+    assert_matches!(
+        verifier.verify(&tampered_batch, vec![proven_transaction.clone()]),
+        Err(BatchVerifierError::BatchContentMismatch(batch_id)) if batch_id == id
+    );
+    assert_matches!(
+        verifier.verify(&tampered_batch, Vec::new()),
+        Err(BatchVerifierError::InvalidBatch(ProposedBatchError::EmptyTransactionBatch))
+    );
+    assert_matches!(
+        BatchVerifier::new(u32::MAX).verify(&proven_batch, vec![proven_transaction]),
+        Err(BatchVerifierError::TransactionVerificationFailed { transaction_id, .. })
+            if transaction_id == tx_id
+    );
+}
+
+#[test]
+fn prove_and_verify_with_chain_state() {
+    let mut mock_chain = MockChain::new();
+    let sender_account = mock_chain.add_new_wallet(Auth::BasicAuth);
+    let target_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    mock_chain.add_pending_account(target_account.clone());
+    let [authenticated_note, unauthenticated_note] = [123, 456].map(|amount| {
+        mock_chain
+            .add_p2id_note(
+                sender_account.id(),
+                target_account.id(),
+                &[FungibleAsset::mock(amount)],
+                NoteType::Public,
+                None,
+            )
+            .unwrap()
+    });
+    mock_chain.seal_block(None);
+
+    let tx_context = mock_chain
+        .build_tx_context(
+            target_account.id(),
+            &[authenticated_note.id()],
+            &[unauthenticated_note.clone()],
+        )
+        .build();
+    let block_header = *tx_context.tx_inputs().block_header();
+    let chain_mmr = tx_context.tx_inputs().block_chain().clone();
+    let executed_transaction = tx_context.execute().unwrap();
+    let proven_transaction = LocalTransactionProver::new(ProvingOptions::default())
+        .prove(executed_transaction.into())
+        .unwrap();
+
+    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+    verifier
+        .verify_with_chain_state(proven_transaction.clone(), &block_header, &chain_mmr, &mock_chain)
+        .unwrap();
+
+    // the chain MMR must commit to the blocks preceding the latest block
+    let mut invalid_chain_mmr = chain_mmr.clone();
+    invalid_chain_mmr.add_block(block_header, false);
+    assert_matches!(
+        verifier.verify_with_chain_state(
+            proven_transaction.clone(),
+            &block_header,
+            &invalid_chain_mmr,
+            &mock_chain
+        ),
+        Err(TransactionVerifierError::ChainRootMismatch { .. })
+    );
+
+    // unauthenticated notes must have been created in a block of the chain
+    assert_matches!(
+        verifier.verify_with_chain_state(
+            proven_transaction.clone(),
+            &block_header,
+            &chain_mmr,
+            &MockChain::new()
+        ),
+        Err(TransactionVerifierError::UnauthenticatedNoteNotFound(note_id))
+            if note_id == unauthenticated_note.id()
+    );
+
+    // consumed notes must not have been consumed already
+    mock_chain.add_nullifier(authenticated_note.nullifier());
+    mock_chain.seal_block(None);
+    assert_matches!(
+        verifier.verify_with_chain_state(proven_transaction, &block_header, &chain_mmr, &mock_chain),
+        Err(TransactionVerifierError::NullifierAlreadySpent { nullifier, .. })
+            if nullifier == authenticated_note.nullifier()
+    );
+}
+
 // TEST TRANSACTION SCRIPT
 // ================================================================================================
 
@@ -893,6 +1192,326 @@ fn test_tx_script() {
     );
 }
 
+/// Tests that a transaction executed with lazily loaded account data produces the same result as
+/// a transaction executed with all account data loaded up front.
+#[test]
+fn transaction_executor_lazy_loading() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    let account_id = tx_context.tx_inputs().account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    let (map_key, map_value) = STORAGE_LEAVES_2[0];
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let balance = tx_context.account().vault().get_balance(faucet_id).unwrap();
+    let new_map_value = [Felt::new(9), Felt::new(8), Felt::new(7), Felt::new(6)];
+    let removed_asset: Asset = FungibleAsset::new(faucet_id, 10).unwrap().into();
+
+    let tx_script_src = format!(
+        "
+        use.miden::account
+
+        begin
+            # read an entry of the storage map
+            push.{map_key}
+            push.{map_index}
+            call.::test::account::get_map_item
+            push.{map_value}
+            assert_eqw drop
+
+            # read the balance of a fungible asset
+            push.{faucet_id_suffix}.{faucet_id_prefix}
+            exec.account::get_balance
+            push.{balance}
+            assert_eq
+
+            # update an entry of the storage map
+            push.{new_map_value}
+            push.{map_key}
+            push.{map_index}
+            call.::test::account::set_map_item dropw dropw dropw
+
+            # remove a fungible asset from the vault and add it back
+            push.{removed_asset}
+            call.::test::account::remove_asset dropw
+            push.{removed_asset}
+            call.::test::account::add_asset dropw
+
+            push.1 call.::test::account::incr_nonce drop
+        end
+        ",
+        map_key = prepare_word(&map_key),
+        map_index = STORAGE_INDEX_2,
+        map_value = prepare_word(&map_value),
+        new_map_value = prepare_word(&new_map_value),
+        removed_asset = prepare_word(&Word::from(removed_asset)),
+        faucet_id_suffix = faucet_id.suffix(),
+        faucet_id_prefix = faucet_id.prefix().as_felt(),
+    );
+    let tx_script = TransactionScript::compile(
+        tx_script_src,
+        [],
+        TransactionKernel::testing_assembler_with_mock_account(),
+    )
+    .unwrap();
+    let tx_args = TransactionArgs::new(
+        Some(tx_script),
+        None,
+        tx_context.tx_args().advice_inputs().clone().map,
+    );
+
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+    let eager_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_args.clone())
+        .unwrap();
+
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None).with_lazy_loading();
+    let lazy_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_args.clone())
+        .unwrap();
+
+    // the debugger replays the transaction against the lazily loaded data as well
+    let mut debugger =
+        executor.debug_transaction(account_id, block_ref, &note_ids, tx_args).unwrap();
+    assert_eq!(debugger.resume(), DebuggerStop::Finished);
+
+    assert_eq!(
+        lazy_transaction.final_account().hash(),
+        eager_transaction.final_account().hash()
+    );
+    assert_eq!(lazy_transaction.output_notes(), eager_transaction.output_notes());
+    assert_eq!(lazy_transaction.account_delta(), eager_transaction.account_delta());
+    let map_delta = lazy_transaction.account_delta().storage().maps().get(&STORAGE_INDEX_2);
+    assert_eq!(map_delta.and_then(|delta| delta.leaves().get(&map_key)), Some(&new_map_value));
+}
+
+#[test]
+fn transaction_executor_simulation() {
+    let mut mock_chain = MockChain::new();
+    let sender_account = mock_chain.add_new_wallet(Auth::BasicAuth);
+    let target_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let note = mock_chain
+        .add_p2id_note(
+            sender_account.id(),
+            target_account.id(),
+            &[FungibleAsset::mock(123)],
+            NoteType::Public,
+            None,
+        )
+        .unwrap();
+    mock_chain.seal_block(None);
+
+    let tx_context = mock_chain.build_tx_context(target_account.id(), &[note.id()], &[]).build();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    // simulate the transaction without an authenticator
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+    let simulated_transaction = executor
+        .simulate_transaction(
+            target_account.id(),
+            block_ref,
+            &[note.id()],
+            tx_context.tx_args().clone(),
+        )
+        .unwrap();
+
+    // execute the same transaction with the authenticator of the account
+    let executed_transaction = tx_context.execute().unwrap();
+
+    // the signature requested by the authentication procedure is reported as pending, and the
+    // transaction was completed with a placeholder signature
+    let (pub_key, _) = simulated_transaction.pending_signature().unwrap();
+    assert!(!simulated_transaction.is_complete());
+    assert_eq!(pub_key, target_account.storage().get_item(0).unwrap());
+
+    assert_eq!(simulated_transaction.account_id(), target_account.id());
+    assert_eq!(simulated_transaction.account_delta(), executed_transaction.account_delta());
+    assert_eq!(
+        simulated_transaction.output_notes(),
+        executed_transaction.output_notes().iter().cloned().collect::<Vec<_>>()
+    );
+
+    // cycle counts are recorded for all stages, including the verification of the signature and
+    // the epilogue
+    let measurements = simulated_transaction.measurements();
+    assert!(measurements.prologue > 0);
+    assert_eq!(measurements.note_execution.len(), 1);
+    assert_eq!(measurements.note_execution[0].0, note.id());
+    assert!(measurements.note_execution[0].1 > 0);
+    assert!(measurements.epilogue > 0);
+}
+
+#[test]
+fn note_consumption_checker() {
+    let mut mock_chain = MockChain::new();
+    let sender_account = mock_chain.add_new_wallet(Auth::BasicAuth);
+    let target_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let other_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let consumable_note = mock_chain
+        .add_p2id_note(
+            sender_account.id(),
+            target_account.id(),
+            &[FungibleAsset::mock(123)],
+            NoteType::Public,
+            None,
+        )
+        .unwrap();
+    let foreign_note = mock_chain
+        .add_p2id_note(
+            sender_account.id(),
+            other_account.id(),
+            &[FungibleAsset::mock(456)],
+            NoteType::Public,
+            None,
+        )
+        .unwrap();
+
+    // the script of this note adds an asset which the note does not carry to the account vault,
+    // which only fails the asset preservation check of the epilogue
+    let unbalanced_note = NoteBuilder::new(sender_account.id(), ChaCha20Rng::from_entropy())
+        .code(format!(
+            "
+            begin
+                dropw padw padw padw push.{asset}
+                call.::miden::contracts::wallets::basic::receive_asset
+                dropw dropw dropw dropw
+            end
+            ",
+            asset = prepare_word(&Word::from(FungibleAsset::mock(789)))
+        ))
+        .build(&TransactionKernel::assembler())
+        .unwrap();
+    mock_chain.add_pending_note(unbalanced_note.clone());
+    mock_chain.seal_block(None);
+
+    let tx_context = mock_chain
+        .build_tx_context(
+            target_account.id(),
+            &[consumable_note.id(), foreign_note.id(), unbalanced_note.id()],
+            &[],
+        )
+        .build();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+    let report = NoteConsumptionChecker::new(&executor)
+        .check_notes_consumability(
+            target_account.id(),
+            block_ref,
+            tx_context.input_notes().clone(),
+            tx_context.tx_args().clone(),
+        )
+        .unwrap();
+
+    assert_eq!(report.successful_notes().collect::<Vec<_>>(), vec![consumable_note.id()]);
+
+    let failed_notes = report.failed_notes().collect::<Vec<_>>();
+    assert_eq!(failed_notes.len(), 2);
+    let (failed_note_id, failure) = failed_notes[0];
+    assert_eq!(failed_note_id, foreign_note.id());
+    assert_eq!(failure.note_id(), Some(foreign_note.id()));
+    assert_eq!(failure.err_code(), Some(ERR_P2ID_TARGET_ACCT_MISMATCH));
+    assert_eq!(
+        failure.kernel_error_message(),
+        Some("P2ID's target account address and transaction address do not match")
+    );
+
+    // the transaction consuming the unbalanced note requests a signature, and is then executed
+    // with a placeholder signature up to the epilogue, which fails
+    let (failed_note_id, failure) = failed_notes[1];
+    assert_eq!(failed_note_id, unbalanced_note.id());
+    assert_eq!(failure.note_id(), None);
+    assert_eq!(failure.err_code(), Some(ERR_EPILOGUE_TOTAL_NUMBER_OF_ASSETS_MUST_STAY_THE_SAME));
+
+    // consuming both notes together fails on the note which cannot be consumed in isolation
+    assert!(!report.can_consume_all());
+    let combined_failure = report.combined_failure().unwrap();
+    assert_eq!(combined_failure.note_id(), Some(foreign_note.id()));
+    assert_eq!(combined_failure.err_code(), Some(ERR_P2ID_TARGET_ACCT_MISMATCH));
+}
+
+#[test]
+fn transaction_executor_event_handlers() {
+    const ORACLE_EVENT: u32 = 0x3_0000;
+
+    /// Pushes twice the value at the top of the operand stack and the initial nonce of the account
+    /// onto the advice stack.
+    struct MockOracle;
+
+    impl EventHandler for MockOracle {
+        fn on_event(
+            &self,
+            _event_id: u32,
+            context: &mut EventContext<'_>,
+        ) -> Result<(), EventHandlerError> {
+            let value = context.process().get_stack_item(0);
+            let nonce = context.initial_account().nonce();
+            context.extend_advice_stack([value + value, nonce]);
+            Ok(())
+        }
+    }
+
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let mut event_handlers = EventHandlerRegistry::new();
+    event_handlers
+        .register(ORACLE_EVENT..=ORACLE_EVENT + 10, Arc::new(MockOracle))
+        .unwrap();
+
+    // ranges of registered handlers and of the transaction kernel events cannot be handled
+    assert_matches!(
+        event_handlers.register(ORACLE_EVENT + 10..=ORACLE_EVENT + 20, Arc::new(MockOracle)),
+        Err(EventHandlerError::OverlappingEventIds { .. })
+    );
+    assert_matches!(
+        event_handlers.register(0x1_ffff..=0x2_0000, Arc::new(MockOracle)),
+        Err(EventHandlerError::ReservedEventIds { .. })
+    );
+
+    let tx_script_src = format!(
+        "
+        begin
+            push.21
+            emit.{oracle_event}
+            adv_push.2
+            push.42 assert_eq
+            push.1 assert_eq
+            drop
+        end
+        ",
+        oracle_event = ORACLE_EVENT + 3,
+    );
+    let tx_script =
+        TransactionScript::compile(tx_script_src, [], TransactionKernel::testing_assembler())
+            .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None)
+        .with_event_handlers(event_handlers);
+    executor
+        .execute_transaction(account_id, block_ref, &[], tx_args.clone())
+        .unwrap();
+
+    // without a registered handler, the event is rejected by the host
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+    assert_matches!(
+        executor.execute_transaction(account_id, block_ref, &[], tx_args),
+        Err(TransactionExecutorError::TransactionProgramExecutionFailed(
+            ExecutionError::EventError(_)
+        ))
+    );
+}
+
 /// Tests that an account can call code in a custom library when loading that library into the
 /// executor.
 ///
@@ -988,3 +1607,585 @@ fn transaction_executor_account_code_using_custom_library() {
     // Account's initial nonce of 1 should have been incremented by 4.
     assert_eq!(executed_tx.account_delta().nonce().unwrap(), Felt::new(5));
 }
+
+#[test]
+fn transaction_debugger() {
+    const ACCOUNT_COMPONENT_CODE: &str = "
+      use.miden::account
+
+      export.custom_nonce_incr
+        push.1 exec.account::incr_nonce
+      end";
+
+    let source_manager = Arc::new(DefaultSourceManager::default());
+    let account_component_module = Module::parser(ModuleKind::Library)
+        .parse_str(
+            LibraryPath::new("account_component::account_module").unwrap(),
+            ACCOUNT_COMPONENT_CODE,
+            &source_manager,
+        )
+        .unwrap();
+
+    // the component is assembled in debug mode so that the debugger can identify its procedures
+    let account_component_lib = TransactionKernel::assembler()
+        .with_debug_mode(true)
+        .assemble_library([account_component_module])
+        .unwrap();
+
+    let account_component =
+        AccountComponent::new(account_component_lib.clone(), vec![StorageSlot::empty_value()])
+            .unwrap()
+            .with_supports_all_types();
+
+    // Build an existing account with nonce 1.
+    let native_account = AccountBuilder::new(ChaCha20Rng::from_entropy().gen())
+        .with_component(account_component)
+        .build_existing()
+        .unwrap();
+
+    let input_note = NoteBuilder::new(native_account.id(), ChaCha20Rng::from_entropy())
+        .build(&TransactionKernel::assembler())
+        .unwrap();
+
+    let tx_context = TransactionContextBuilder::new(native_account.clone())
+        .input_notes(vec![input_note.clone()])
+        .build();
+
+    let tx_script_src = "\
+          use.account_component::account_module
+
+          begin
+            call.account_module::custom_nonce_incr
+          end";
+    let tx_script = TransactionScript::compile(
+        tx_script_src,
+        [],
+        TransactionKernel::assembler().with_library(&account_component_lib).unwrap(),
+    )
+    .unwrap();
+    let tx_args = TransactionArgs::new(
+        Some(tx_script),
+        None,
+        tx_context.tx_args().advice_inputs().clone().map,
+    );
+
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let note_ids = [input_note.id()];
+
+    let debugger = executor.debug_transaction(account_id, block_ref, &note_ids, tx_args).unwrap();
+
+    let first_trace = debugger.trace_events().next().map(|(_, trace)| trace.clone());
+    assert_eq!(first_trace, Some(TransactionTrace::PrologueStart));
+
+    let proc_name = account_component_lib.exports().next().unwrap();
+    let proc_root = account_component_lib.mast_forest()
+        [account_component_lib.get_export_node_id(proc_name)]
+    .digest();
+    let mut debugger = debugger.with_library(&account_component_lib);
+    debugger.add_breakpoint(Breakpoint::Trace(TransactionTrace::PrologueEnd));
+    debugger.add_breakpoint(Breakpoint::Trace(TransactionTrace::NoteExecutionStart));
+    debugger.add_breakpoint(Breakpoint::Procedure(proc_root));
+    debugger.add_breakpoint(Breakpoint::Trace(TransactionTrace::EpilogueStart));
+
+    // after the prologue, the native account is loaded into kernel memory
+    let stop = debugger.resume();
+    assert_eq!(stop, DebuggerStop::Breakpoint(Breakpoint::Trace(TransactionTrace::PrologueEnd)));
+    let memory = debugger.kernel_memory();
+    assert_eq!(memory.current_account_id(), Some(account_id));
+    assert_eq!(memory.current_account_nonce(), ONE);
+    assert_eq!(memory.current_account_vault_root(), native_account.vault().commitment());
+    assert_eq!(
+        memory.current_account_storage_commitment(),
+        native_account.storage().commitment()
+    );
+    assert_eq!(memory.current_account_code_commitment(), native_account.code().commitment());
+    assert_eq!(
+        memory.current_account_storage_slots(),
+        native_account
+            .storage()
+            .slots()
+            .iter()
+            .map(StorageSlot::value)
+            .collect::<Vec<_>>()
+    );
+    assert!(memory.output_notes().is_empty());
+
+    // while a note is executed, its data can be read from kernel memory
+    let stop = debugger.resume();
+    assert_eq!(
+        stop,
+        DebuggerStop::Breakpoint(Breakpoint::Trace(TransactionTrace::NoteExecutionStart))
+    );
+    let current_note = debugger.kernel_memory().current_input_note().unwrap();
+    assert_eq!(current_note.id, input_note.id());
+    assert_eq!(current_note.serial_num, input_note.serial_num());
+    assert_eq!(current_note.script_root, input_note.script().hash());
+    assert!(current_note.assets.is_empty());
+
+    // the account procedure is executed in its own context, but kernel memory remains available
+    let stop = debugger.resume();
+    assert_eq!(stop, DebuggerStop::Breakpoint(Breakpoint::Procedure(proc_root)));
+    assert_eq!(debugger.procedure(), Some(proc_name.to_string().as_str()));
+    assert_eq!(debugger.kernel_memory().current_account_nonce(), ONE);
+
+    let stop = debugger.resume();
+    assert_eq!(
+        stop,
+        DebuggerStop::Breakpoint(Breakpoint::Trace(TransactionTrace::EpilogueStart))
+    );
+    let memory = debugger.kernel_memory();
+    assert_eq!(memory.current_account_nonce(), Felt::new(2));
+    assert!(memory.current_input_note().is_none());
+
+    assert!(debugger.remove_breakpoint(&Breakpoint::Procedure(proc_root)));
+    assert_eq!(debugger.resume(), DebuggerStop::Finished);
+    assert!(debugger.error().is_none());
+}
+
+#[test]
+fn transaction_profiler() {
+    const ACCOUNT_COMPONENT_CODE: &str = "
+      use.miden::account
+
+      export.custom_nonce_incr
+        push.1 exec.account::incr_nonce
+      end";
+
+    let source_manager = Arc::new(DefaultSourceManager::default());
+    let account_component_module = Module::parser(ModuleKind::Library)
+        .parse_str(
+            LibraryPath::new("account_component::account_module").unwrap(),
+            ACCOUNT_COMPONENT_CODE,
+            &source_manager,
+        )
+        .unwrap();
+    let account_component_lib = TransactionKernel::assembler()
+        .assemble_library([account_component_module])
+        .unwrap();
+
+    let account_component =
+        AccountComponent::new(account_component_lib.clone(), vec![StorageSlot::empty_value()])
+            .unwrap()
+            .with_supports_all_types();
+    let native_account = AccountBuilder::new(ChaCha20Rng::from_entropy().gen())
+        .with_component(account_component)
+        .build_existing()
+        .unwrap();
+
+    let input_note = NoteBuilder::new(native_account.id(), ChaCha20Rng::from_entropy())
+        .build(&TransactionKernel::assembler())
+        .unwrap();
+    let tx_context = TransactionContextBuilder::new(native_account)
+        .input_notes(vec![input_note.clone()])
+        .build();
+
+    let tx_script_src = "\
+          use.account_component::account_module
+
+          begin
+            call.account_module::custom_nonce_incr
+          end";
+    let tx_script = TransactionScript::compile(
+        tx_script_src,
+        [],
+        TransactionKernel::assembler().with_library(&account_component_lib).unwrap(),
+    )
+    .unwrap();
+    let tx_args = TransactionArgs::new(
+        Some(tx_script.clone()),
+        None,
+        tx_context.tx_args().advice_inputs().clone().map,
+    );
+
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None).with_tracing();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = [input_note.id()];
+
+    let executed_tx = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_args.clone())
+        .unwrap();
+    let profile = executor
+        .profile_transaction(account_id, block_ref, &note_ids, tx_args)
+        .unwrap()
+        .with_library(&account_component_lib);
+
+    // the replayed transaction spends the same number of cycles in each stage
+    assert_eq!(profile.measurements(), executed_tx.measurements());
+
+    // the account procedure is invoked once by the transaction script, and invokes the kernel
+    let proc_name = account_component_lib.exports().next().unwrap();
+    let proc_root = account_component_lib.mast_forest()
+        [account_component_lib.get_export_node_id(proc_name)]
+    .digest();
+    let proc_profile = profile.procedure(&proc_root).unwrap();
+    assert_eq!(proc_profile.name, Some(proc_name.to_string()));
+    assert_eq!(proc_profile.num_calls, 1);
+    assert!(proc_profile.self_cycles > 0);
+    assert!(proc_profile.total_cycles > proc_profile.self_cycles);
+
+    let tx_script_profile = profile.procedure(&tx_script.hash()).unwrap();
+    assert!(tx_script_profile.total_cycles > proc_profile.total_cycles);
+    assert!(tx_script_profile.total_cycles <= profile.measurements().tx_script_processing);
+
+    let note_script_profile = profile.procedure(&input_note.script().hash()).unwrap();
+    assert_eq!(note_script_profile.num_calls, 1);
+
+    // every cycle of the transaction is attributed to exactly one call stack
+    let stacks = profile.to_collapsed_stacks();
+    let total_cycles: usize = stacks
+        .lines()
+        .map(|line| line.rsplit_once(' ').unwrap().1.parse::<usize>().unwrap())
+        .sum();
+    assert_eq!(total_cycles, profile.total_cycles());
+
+    let account_proc_stack = format!(
+        "transaction;tx_script_processing;{};{proc_name} {}",
+        tx_script.hash().to_hex(),
+        proc_profile.self_cycles
+    );
+    assert!(stacks.lines().any(|line| line == account_proc_stack), "{stacks}");
+
+    // the kernel procedure invoked by the account procedure is attributed to its own frame
+    let kernel_proc_prefix = format!("{};", account_proc_stack.rsplit_once(' ').unwrap().0);
+    assert!(stacks
+        .lines()
+        .any(|line| line.starts_with(&kernel_proc_prefix) && line.contains("account_incr_nonce")));
+}
+
+#[test]
+fn multisig_authentication() {
+    const MULTISIG_AUTH_SCRIPT: &str = "
+    begin
+        padw padw padw padw
+        call.::miden::contracts::auth::multisig::auth_tx_rpo_falcon512_multisig
+        dropw dropw dropw dropw
+    end
+    ";
+
+    // returns an authenticator managing the keys of the specified signers
+    let authenticator = |signers: &[&SecretKey]| {
+        let keys: Vec<(Word, AuthSecretKey)> = signers
+            .iter()
+            .map(|sec_key| {
+                (sec_key.public_key().into(), AuthSecretKey::RpoFalcon512((*sec_key).clone()))
+            })
+            .collect();
+        BasicAuthenticator::<ChaCha20Rng>::new_with_rng(
+            &keys,
+            ChaCha20Rng::from_seed(Default::default()),
+        )
+    };
+
+    // create a 2-of-3 multisig wallet
+    let mut rng = ChaCha20Rng::from_seed(Default::default());
+    let sec_keys: Vec<SecretKey> = (0..3).map(|_| SecretKey::with_rng(&mut rng)).collect();
+    let approvers = sec_keys.iter().map(SecretKey::public_key).collect();
+
+    let multisig_account = AccountBuilder::new(rng.gen())
+        .with_component(RpoFalcon512Multisig::new(2, approvers).unwrap())
+        .with_component(BasicWallet)
+        .build_existing()
+        .unwrap();
+    let account_id = multisig_account.id();
+
+    let mut mock_chain = MockChain::with_accounts(&[multisig_account.clone()]);
+    let sender_account = mock_chain.add_new_wallet(Auth::BasicAuth);
+    let note = mock_chain
+        .add_p2id_note(
+            sender_account.id(),
+            account_id,
+            &[FungibleAsset::mock(123)],
+            NoteType::Public,
+            None,
+        )
+        .unwrap();
+    mock_chain.seal_block(None);
+
+    let tx_script =
+        TransactionScript::compile(MULTISIG_AUTH_SCRIPT, vec![], TransactionKernel::assembler())
+            .unwrap();
+    let tx_context = mock_chain
+        .build_tx_context(account_id, &[note.id()], &[])
+        .tx_script(tx_script)
+        .build();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let tx_args = tx_context.tx_args().clone();
+
+    // a single signature does not reach the threshold
+    let executor = TransactionExecutor::new(
+        tx_context.get_data_store(),
+        Some(Arc::new(authenticator(&[&sec_keys[0]]))),
+    );
+    let result = executor.execute_transaction(account_id, block_ref, &[note.id()], tx_args.clone());
+    assert_matches!(
+        result,
+        Err(TransactionExecutorError::TransactionProgramExecutionFailed(
+            ExecutionError::FailedAssertion { err_code, .. }
+        )) if err_code == ERR_MULTISIG_THRESHOLD_NOT_MET
+    );
+
+    // signatures of all signers managed by the authenticator are collected during execution
+    let executor = TransactionExecutor::new(
+        tx_context.get_data_store(),
+        Some(Arc::new(authenticator(&[&sec_keys[0], &sec_keys[2]]))),
+    );
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &[note.id()], tx_args.clone())
+        .unwrap();
+    assert_eq!(executed_transaction.final_account().nonce(), multisig_account.nonce() + ONE);
+
+    // the signers which do not share an authenticator sign a serialized copy of the unsigned
+    // transaction one after the other
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+    let mut partial_tx = executor
+        .propose_transaction(account_id, block_ref, &[note.id()], tx_args)
+        .unwrap();
+    assert_eq!(partial_tx.num_signatures(), 0);
+    assert_eq!(partial_tx.signing_request().compute_message(), partial_tx.message());
+    assert_eq!(
+        partial_tx.simulated_transaction().account_delta(),
+        executed_transaction.account_delta()
+    );
+
+    for sec_key in sec_keys.iter().skip(1) {
+        let mut signer_tx =
+            PartiallySignedTransaction::read_from_bytes(&partial_tx.to_bytes()).unwrap();
+        assert_eq!(signer_tx, partial_tx);
+
+        signer_tx.sign(sec_key.public_key().into(), &authenticator(&[sec_key])).unwrap();
+        partial_tx = signer_tx;
+    }
+    assert_eq!(partial_tx.num_signatures(), 2);
+    assert!(!partial_tx.is_signed_by(&Word::from(sec_keys[0].public_key()).into()));
+
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &[note.id()], partial_tx.into_tx_args())
+        .unwrap();
+
+    let executed_transaction_id = executed_transaction.id();
+    let prover = LocalTransactionProver::new(ProvingOptions::default());
+    let proven_transaction = prover.prove(executed_transaction.into()).unwrap();
+    assert_eq!(proven_transaction.id(), executed_transaction_id);
+}
+
+#[test]
+fn remote_authenticator() {
+    let mut mock_chain = MockChain::new();
+    let sender_account = mock_chain.add_new_wallet(Auth::BasicAuth);
+    let target_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let note = mock_chain
+        .add_p2id_note(
+            sender_account.id(),
+            target_account.id(),
+            &[FungibleAsset::mock(123)],
+            NoteType::Public,
+            None,
+        )
+        .unwrap();
+    mock_chain.seal_block(None);
+
+    let tx_context = mock_chain.build_tx_context(target_account.id(), &[note.id()], &[]).build();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    // the secret key of the account is managed by a signer running in a separate thread
+    let mut rng = ChaCha20Rng::from_seed(Default::default());
+    let sec_key = SecretKey::with_rng(&mut rng);
+    let pub_key: Word = sec_key.public_key().into();
+    assert_eq!(Digest::from(pub_key), target_account.storage().get_item(0).unwrap());
+
+    let signer_authenticator = BasicAuthenticator::<ChaCha20Rng>::new_with_rng(
+        &[(pub_key, AuthSecretKey::RpoFalcon512(sec_key))],
+        rng,
+    );
+    let server = SignerServer::bind("127.0.0.1:0", signer_authenticator).unwrap();
+    let server_address = server.local_addr().unwrap();
+    let authenticator = RemoteAuthenticator::new(server_address);
+    std::thread::spawn(move || server.serve());
+
+    // an idle client does not delay the requests of other clients
+    let _idle_connection = std::net::TcpStream::connect(server_address).unwrap();
+
+    let executor = TransactionExecutor::new(
+        tx_context.get_data_store(),
+        Some(Arc::new(authenticator.clone())),
+    );
+    let executed_transaction = executor
+        .execute_transaction(
+            target_account.id(),
+            block_ref,
+            &[note.id()],
+            tx_context.tx_args().clone(),
+        )
+        .unwrap();
+    assert_eq!(executed_transaction.final_account().nonce(), target_account.nonce() + ONE);
+
+    // the signer signs only messages derived from the signing request
+    let signing_request = SigningRequest::new(
+        target_account.id(),
+        target_account.nonce(),
+        executed_transaction.account_delta().clone(),
+        executed_transaction.input_notes().clone(),
+        executed_transaction.output_notes().clone(),
+    );
+    assert_eq!(signing_request.input_note_ids().collect::<Vec<_>>(), vec![note.id()]);
+    let message: Word = signing_request.compute_message().into();
+    authenticator.get_signature(pub_key, message, &signing_request).unwrap();
+
+    let result = authenticator.get_signature(pub_key, Word::default(), &signing_request);
+    assert_matches!(result, Err(AuthenticationError::RejectedSignature(_)));
+
+    // the signer rejects requests for keys it does not manage
+    let unknown_pub_key: Word = SecretKey::new().public_key().into();
+    let result = authenticator.get_signature(unknown_pub_key, message, &signing_request);
+    assert_matches!(result, Err(AuthenticationError::RejectedSignature(_)));
+
+    // requests to a signer which does not respond time out
+    let unresponsive_signer = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let authenticator = RemoteAuthenticator::new(unresponsive_signer.local_addr().unwrap())
+        .with_timeout(Duration::from_millis(100));
+    let result = authenticator.get_signature(pub_key, message, &signing_request);
+    assert_matches!(result, Err(AuthenticationError::Other { .. }));
+}
+
+#[test]
+fn transaction_executor_signs_in_host() {
+    const EXECUTION_EVENT: u32 = 0x3_0000;
+
+    /// Counts the executions of the transaction program which reach the emitted event.
+    #[derive(Default)]
+    struct ExecutionCounter(AtomicUsize);
+
+    impl EventHandler for ExecutionCounter {
+        fn on_event(
+            &self,
+            _event_id: u32,
+            _context: &mut EventContext<'_>,
+        ) -> Result<(), EventHandlerError> {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            Ok(())
+        }
+    }
+
+    /// Counts the signatures requested from the wrapped authenticator.
+    struct CountingAuthenticator {
+        authenticator: BasicAuthenticator<ChaCha20Rng>,
+        num_requests: AtomicUsize,
+    }
+
+    impl TransactionAuthenticator for CountingAuthenticator {
+        fn get_signature(
+            &self,
+            pub_key: Word,
+            message: Word,
+            signing_request: &SigningRequest,
+        ) -> Result<Vec<Felt>, AuthenticationError> {
+            self.num_requests.fetch_add(1, Ordering::Relaxed);
+            self.authenticator.get_signature(pub_key, message, signing_request)
+        }
+    }
+
+    let mut mock_chain = MockChain::new();
+    let account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    mock_chain.seal_block(None);
+
+    // the secret key of the account is derived from the same seed as in the mock chain
+    let mut rng = ChaCha20Rng::from_seed(Default::default());
+    let sec_key = SecretKey::with_rng(&mut rng);
+    let authenticator = Arc::new(CountingAuthenticator {
+        authenticator: BasicAuthenticator::<ChaCha20Rng>::new_with_rng(
+            &[(sec_key.public_key().into(), AuthSecretKey::RpoFalcon512(sec_key))],
+            rng,
+        ),
+        num_requests: AtomicUsize::new(0),
+    });
+
+    let tx_script_src = format!(
+        "
+        begin
+            emit.{EXECUTION_EVENT}
+            padw padw padw padw
+            call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+            dropw dropw dropw dropw
+        end
+        "
+    );
+    let tx_script =
+        TransactionScript::compile(tx_script_src, [], TransactionKernel::testing_assembler())
+            .unwrap();
+    let tx_context = mock_chain.build_tx_context(account.id(), &[], &[]).build();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let execution_counter = Arc::new(ExecutionCounter::default());
+    let mut event_handlers = EventHandlerRegistry::new();
+    event_handlers
+        .register(EXECUTION_EVENT..=EXECUTION_EVENT, execution_counter.clone())
+        .unwrap();
+
+    // the signature is obtained by the host during the only execution of the transaction
+    let executor =
+        TransactionExecutor::new(tx_context.get_data_store(), Some(authenticator.clone()))
+            .with_event_handlers(event_handlers.clone());
+    let executed_transaction = executor
+        .execute_transaction(
+            account.id(),
+            block_ref,
+            &[],
+            TransactionArgs::with_tx_script(tx_script.clone()),
+        )
+        .unwrap();
+    assert_eq!(executed_transaction.final_account().nonce(), account.nonce() + ONE);
+    assert_eq!(execution_counter.0.load(Ordering::Relaxed), 1);
+    assert_eq!(authenticator.num_requests.load(Ordering::Relaxed), 1);
+
+    // the signature is part of the advice witness of the transaction, and so it can be proven
+    let prover = LocalTransactionProver::new(ProvingOptions::default())
+        .with_event_handlers(event_handlers.clone());
+    prover.prove(executed_transaction.into()).unwrap();
+
+    // without an authenticator, the execution is aborted at the signature request
+    execution_counter.0.store(0, Ordering::Relaxed);
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None)
+        .with_event_handlers(event_handlers);
+    let result = executor.execute_transaction(
+        account.id(),
+        block_ref,
+        &[],
+        TransactionArgs::with_tx_script(tx_script),
+    );
+    assert_matches!(
+        result,
+        Err(TransactionExecutorError::TransactionProgramExecutionFailed(
+            ExecutionError::FailedSignatureGeneration(_)
+        ))
+    );
+    assert_eq!(execution_counter.0.load(Ordering::Relaxed), 1);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Executes a transaction against the standard mock account which consumes the mock notes, and
+/// returns the executed transaction.
+pub(crate) fn execute_mock_transaction() -> ExecutedTransaction {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    let account_id = tx_context.tx_inputs().account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    let executor = TransactionExecutor::new(tx_context.get_data_store(), None);
+    executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap()
+}
@@ -0,0 +1,75 @@
+use alloc::vec::Vec;
+
+use miden_objects::{
+    batch::{ProposedBatch, ProvenBatch},
+    transaction::{ProvenTransaction, TransactionHeader},
+};
+
+use crate::{errors::BatchVerifierError, TransactionVerifier};
+
+// BATCH VERIFIER
+// ================================================================================================
+
+/// The [BatchVerifier] is used to verify [ProvenBatch]es.
+///
+/// A [ProvenBatch] is verified against the transactions it was built from: the verifier checks
+/// that the transactions form a valid batch whose effects match the proven batch, and verifies the
+/// proof of every transaction. The `proof_security_level` specifies the minimum security level
+/// that the transaction proofs must have in order to be considered valid.
+pub struct BatchVerifier {
+    tx_verifier: TransactionVerifier,
+}
+
+impl BatchVerifier {
+    /// Returns a new [BatchVerifier] instantiated with the specified security level.
+    pub fn new(proof_security_level: u32) -> Self {
+        Self {
+            tx_verifier: TransactionVerifier::new(proof_security_level),
+        }
+    }
+
+    /// Verifies the provided [ProvenBatch] against the provided transactions.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The transactions do not form a valid batch.
+    /// - The batch built from the transactions does not match the provided batch.
+    /// - The proof of any transaction is invalid.
+    pub fn verify(
+        &self,
+        batch: &ProvenBatch,
+        transactions: Vec<ProvenTransaction>,
+    ) -> Result<(), BatchVerifierError> {
+        let proposed_batch =
+            ProposedBatch::new(transactions).map_err(BatchVerifierError::InvalidBatch)?;
+
+        if proposed_batch.id() != batch.id() {
+            return Err(BatchVerifierError::BatchIdMismatch {
+                expected: batch.id(),
+                actual: proposed_batch.id(),
+            });
+        }
+        if !proposed_batch
+            .transactions()
+            .iter()
+            .map(TransactionHeader::from)
+            .eq(batch.transactions().iter().copied())
+            || proposed_batch.account_updates() != batch.account_updates()
+            || proposed_batch.input_notes() != batch.input_notes()
+            || proposed_batch.output_notes() != batch.output_notes()
+            || proposed_batch.batch_expiration_block_num() != batch.batch_expiration_block_num()
+        {
+            return Err(BatchVerifierError::BatchContentMismatch(batch.id()));
+        }
+
+        let (transactions, ..) = proposed_batch.into_parts();
+        for (transaction_id, result) in self.tx_verifier.verify_many(transactions) {
+            result.map_err(|source| BatchVerifierError::TransactionVerificationFailed {
+                transaction_id,
+                source,
+            })?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use miden_objects::{
+    account::AccountId,
+    block::BlockNumber,
+    note::{NoteId, NoteInclusionProof, Nullifier},
+    Digest,
+};
+
+// CHAIN STATE READER
+// ================================================================================================
+
+/// The [ChainStateReader] trait defines the interface transaction verifiers use to look up the
+/// current state of the chain.
+///
+/// The state is expected to be the state as of the latest block of the chain, i.e. the block
+/// against which transactions are verified.
+pub trait ChainStateReader {
+    /// Returns the number of the block in which the note with the specified nullifier was
+    /// consumed, or `None` if the note was not consumed yet.
+    fn get_nullifier_block_num(&self, nullifier: Nullifier) -> Option<BlockNumber>;
+
+    /// Returns the commitment to the current state of the specified account, or `None` if the
+    /// account does not exist yet.
+    fn get_account_state_commitment(&self, account_id: AccountId) -> Option<Digest>;
+
+    /// Returns the proof of inclusion of the specified note in the note tree of the block in which
+    /// it was created, or `None` if the note was not created in any block yet.
+    fn get_note_inclusion_proof(&self, note_id: NoteId) -> Option<NoteInclusionProof>;
+}
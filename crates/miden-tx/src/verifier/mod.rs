@@ -1,9 +1,23 @@
+use alloc::vec::Vec;
+
 use miden_lib::transaction::TransactionKernel;
-use miden_objects::{transaction::ProvenTransaction, vm::ProgramInfo};
+use miden_objects::{
+    block::BlockHeader,
+    transaction::{ChainMmr, ProvenTransaction, TransactionId},
+    vm::ProgramInfo,
+};
 use miden_verifier::verify;
+#[cfg(feature = "concurrent")]
+use rayon::prelude::*;
 
 use super::TransactionVerifierError;
 
+mod chain_state;
+pub use chain_state::ChainStateReader;
+
+mod batch;
+pub use batch::BatchVerifier;
+
 // TRANSACTION VERIFIER
 // ================================================================================================
 
@@ -63,4 +77,126 @@ impl TransactionVerifier {
 
         Ok(())
     }
+
+    /// Verifies the provided [ProvenTransaction]s against the transaction kernel, and returns the
+    /// ID of each transaction along with the result of verifying it.
+    ///
+    /// The results are returned in the order in which the transactions were provided, and a
+    /// transaction provided more than once gets one result per occurrence. All transactions are
+    /// verified against the [ProgramInfo] of the transaction kernel held by this verifier. When
+    /// the `concurrent` feature is enabled, the transactions are verified in parallel.
+    pub fn verify_many(
+        &self,
+        transactions: impl IntoIterator<Item = ProvenTransaction>,
+    ) -> Vec<(TransactionId, Result<(), TransactionVerifierError>)> {
+        let transactions: Vec<ProvenTransaction> = transactions.into_iter().collect();
+
+        #[cfg(not(feature = "concurrent"))]
+        let results = transactions.into_iter().map(|tx| (tx.id(), self.verify(tx))).collect();
+        #[cfg(feature = "concurrent")]
+        let results = transactions.into_par_iter().map(|tx| (tx.id(), self.verify(tx))).collect();
+
+        results
+    }
+
+    /// Verifies the provided [ProvenTransaction] against the transaction kernel and against the
+    /// state of the chain as of the provided latest block.
+    ///
+    /// The transaction is verified for inclusion in the block following the latest block.
+    /// `chain_mmr` must commit to all blocks preceding the latest block, and contain the headers
+    /// of the blocks in which the unauthenticated input notes of the transaction were created.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The chain MMR does not match the chain root of the latest block.
+    /// - The reference block of the transaction is neither the latest block nor a block of the
+    ///   chain MMR.
+    /// - The transaction expires before the block following the latest block.
+    /// - Any of the notes consumed by the transaction was already consumed.
+    /// - Any of the unauthenticated input notes of the transaction was not created in a block of
+    ///   the chain, or its inclusion proof is invalid.
+    /// - The initial state of the account does not match the current state of the account.
+    /// - Transaction verification fails.
+    /// - The security level of the verified proof is insufficient.
+    pub fn verify_with_chain_state(
+        &self,
+        transaction: ProvenTransaction,
+        block_header: &BlockHeader,
+        chain_mmr: &ChainMmr,
+        chain_state: &impl ChainStateReader,
+    ) -> Result<(), TransactionVerifierError> {
+        let chain_root = chain_mmr.peaks().hash_peaks();
+        if chain_root != block_header.chain_root() {
+            return Err(TransactionVerifierError::ChainRootMismatch {
+                expected: block_header.chain_root(),
+                actual: chain_root,
+            });
+        }
+
+        // check that the transaction executed against a block of the chain
+        let block_ref = transaction.block_ref();
+        if block_ref != block_header.hash()
+            && chain_mmr.block_headers().all(|header| header.hash() != block_ref)
+        {
+            return Err(TransactionVerifierError::ReferenceBlockNotFound(block_ref));
+        }
+
+        let block_num = block_header.block_num().child();
+        if transaction.expiration_block_num() < block_num {
+            return Err(TransactionVerifierError::TransactionExpired {
+                expiration_block_num: transaction.expiration_block_num(),
+                block_num,
+            });
+        }
+
+        for nullifier in transaction.get_nullifiers() {
+            if let Some(block_num) = chain_state.get_nullifier_block_num(nullifier) {
+                return Err(TransactionVerifierError::NullifierAlreadySpent {
+                    nullifier,
+                    block_num,
+                });
+            }
+        }
+
+        // check that the unauthenticated notes were created in blocks of the chain
+        for note in transaction.get_unauthenticated_notes() {
+            let note_id = note.id();
+            let proof = chain_state
+                .get_note_inclusion_proof(note_id)
+                .ok_or(TransactionVerifierError::UnauthenticatedNoteNotFound(note_id))?;
+
+            let note_block_num = proof.location().block_num();
+            let note_block = if note_block_num == block_header.block_num() {
+                block_header
+            } else {
+                chain_mmr.get_block(note_block_num).ok_or(
+                    TransactionVerifierError::NoteBlockNotFound {
+                        note_id,
+                        block_num: note_block_num,
+                    },
+                )?
+            };
+
+            proof
+                .note_path()
+                .verify(
+                    proof.location().node_index_in_block().into(),
+                    note.hash(),
+                    &note_block.note_root(),
+                )
+                .map_err(|_| TransactionVerifierError::InvalidNoteInclusionProof(note_id))?;
+        }
+
+        let current_state = chain_state
+            .get_account_state_commitment(transaction.account_id())
+            .unwrap_or_default();
+        if transaction.account_update().init_state_hash() != current_state {
+            return Err(TransactionVerifierError::AccountStateMismatch {
+                expected: current_state,
+                actual: transaction.account_update().init_state_hash(),
+            });
+        }
+
+        self.verify(transaction)
+    }
 }
@@ -1,23 +1,47 @@
 extern crate alloc;
 
 use miden_lib::{
-    errors::tx_kernel_errors::ERR_FUNGIBLE_ASSET_DISTRIBUTE_WOULD_CAUSE_MAX_SUPPLY_TO_BE_EXCEEDED,
-    transaction::TransactionKernel,
+    account::faucets::{FaucetRoles, RoleBasedFungibleFaucet},
+    errors::tx_kernel_errors::{
+        ERR_ACCOUNT_NONCE_DID_NOT_INCREASE_AFTER_STATE_CHANGE,
+        ERR_FAUCET_NON_FUNGIBLE_ASSET_ALREADY_ISSUED,
+        ERR_FUNGIBLE_ASSET_DISTRIBUTE_WOULD_CAUSE_MAX_SUPPLY_TO_BE_EXCEEDED,
+        ERR_ROLE_BASED_FAUCET_IS_PAUSED, ERR_ROLE_BASED_FAUCET_MAX_SUPPLY_BELOW_TOTAL_ISSUANCE,
+        ERR_ROLE_BASED_FAUCET_MAX_SUPPLY_TOO_LARGE, ERR_ROLE_BASED_FAUCET_MINT_ALLOWANCE_EXCEEDED,
+        ERR_ROLE_BASED_FAUCET_ROLE_FLAG_IS_NOT_BOOLEAN, ERR_ROLE_BASED_FAUCET_SENDER_IS_NOT_ADMIN,
+        ERR_ROLE_BASED_FAUCET_SENDER_IS_NOT_BURNER, ERR_ROLE_BASED_FAUCET_SENDER_IS_NOT_MINTER,
+    },
+    note::utils::build_p2id_recipient,
+    transaction::{memory::FAUCET_STORAGE_DATA_SLOT, TransactionKernel},
 };
 use miden_objects::{
-    asset::{Asset, FungibleAsset},
-    note::{NoteAssets, NoteExecutionHint, NoteId, NoteMetadata, NoteTag, NoteType},
+    account::{Account, AccountId},
+    asset::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails, TokenSymbol},
+    crypto::rand::{FeltRng, RpoRandomCoin},
+    note::{
+        Note, NoteAssets, NoteExecutionHint, NoteExecutionMode, NoteId, NoteInputs, NoteMetadata,
+        NoteRecipient, NoteScript, NoteTag, NoteType,
+    },
     testing::prepare_word,
-    transaction::TransactionScript,
-    Felt,
+    transaction::{ExecutedTransaction, TransactionScript},
+    Felt, Word, ONE, ZERO,
+};
+use miden_tx::{
+    testing::{Auth, MockChain},
+    TransactionExecutorError,
 };
-use miden_tx::testing::{Auth, MockChain};
 
 use crate::{
     assert_transaction_executor_error, get_note_with_fungible_asset_and_script,
     prove_and_verify_transaction,
 };
 
+/// The slot of the token metadata of a role-based fungible faucet without other components.
+const METADATA_SLOT: u8 = 1;
+
+/// The slot of the roles map of a role-based fungible faucet without other components.
+const ROLES_SLOT: u8 = 3;
+
 // TESTS MINT FUNGIBLE ASSET
 // ================================================================================================
 
@@ -214,3 +238,567 @@ fn prove_faucet_contract_burn_fungible_asset_succeeds() {
     assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(3)));
     assert_eq!(executed_transaction.input_notes().get_note(0).id(), note.id());
 }
+
+// TESTS MINT NON-FUNGIBLE ASSET
+// ================================================================================================
+
+#[test]
+fn prove_faucet_contract_mint_non_fungible_asset_succeeds() {
+    let mut mock_chain = MockChain::new();
+    let faucet = mock_chain.add_existing_non_fungible_faucet(Auth::BasicAuth);
+    let target_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+
+    let details = NonFungibleAssetDetails::new(faucet.id().prefix(), vec![1, 2, 3, 4, 5]).unwrap();
+    let non_fungible_asset = NonFungibleAsset::new(&details).unwrap();
+
+    let serial_num = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let recipient = build_p2id_recipient(target_account.id(), serial_num).unwrap();
+    let tag = NoteTag::from_account_id(target_account.id(), NoteExecutionMode::Local).unwrap();
+    let note_type = NoteType::Private;
+
+    let tx_script = distribute_non_fungible_asset_script(
+        non_fungible_asset,
+        recipient.digest().into(),
+        tag,
+        note_type,
+    );
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let executed_transaction = mock_chain
+        .build_tx_context(faucet.id(), &[], &[])
+        .tx_script(tx_script.clone())
+        .build()
+        .execute()
+        .unwrap();
+
+    let output_note = executed_transaction.output_notes().get_note(0).clone();
+    let assets = NoteAssets::new(vec![non_fungible_asset.into()]).unwrap();
+    assert_eq!(output_note.id(), NoteId::new(recipient.digest(), assets.commitment()));
+    assert_eq!(
+        output_note.metadata(),
+        &NoteMetadata::new(faucet.id(), note_type, tag, NoteExecutionHint::always(), ZERO).unwrap()
+    );
+
+    prove_and_verify_transaction(executed_transaction.clone()).unwrap();
+
+    // the faucet records the asset as issued
+    let faucet = mock_chain.apply_executed_transaction(&executed_transaction);
+    assert_eq!(
+        faucet
+            .storage()
+            .get_map_item(FAUCET_STORAGE_DATA_SLOT, non_fungible_asset.vault_key())
+            .unwrap(),
+        Word::from(non_fungible_asset)
+    );
+    mock_chain.seal_block(None);
+
+    // CONSTRUCT AND EXECUTE TX (Failure - the asset is minted again)
+    // --------------------------------------------------------------------------------------------
+    let executed_transaction = mock_chain
+        .build_tx_context(faucet.id(), &[], &[])
+        .tx_script(tx_script)
+        .build()
+        .execute();
+
+    assert_transaction_executor_error!(
+        executed_transaction,
+        ERR_FAUCET_NON_FUNGIBLE_ASSET_ALREADY_ISSUED
+    );
+}
+
+// TESTS BURN NON-FUNGIBLE ASSET
+// ================================================================================================
+
+#[test]
+fn prove_faucet_contract_burn_non_fungible_asset_succeeds() {
+    let mut mock_chain = MockChain::new();
+    let faucet = mock_chain.add_existing_non_fungible_faucet(Auth::BasicAuth);
+    let target_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+
+    let details = NonFungibleAssetDetails::new(faucet.id().prefix(), vec![1, 2, 3, 4, 5]).unwrap();
+    let non_fungible_asset = NonFungibleAsset::new(&details).unwrap();
+
+    // mint the asset to be burned
+    let recipient =
+        build_p2id_recipient(target_account.id(), [Felt::new(1), ZERO, ZERO, ZERO]).unwrap();
+    let tx_script = distribute_non_fungible_asset_script(
+        non_fungible_asset,
+        recipient.digest().into(),
+        NoteTag::from_account_id(target_account.id(), NoteExecutionMode::Local).unwrap(),
+        NoteType::Private,
+    );
+    let executed_transaction = mock_chain
+        .build_tx_context(faucet.id(), &[], &[])
+        .tx_script(tx_script)
+        .build()
+        .execute()
+        .unwrap();
+    let faucet = mock_chain.apply_executed_transaction(&executed_transaction);
+
+    // the holder of the asset sends it back to the faucet in a note which burns it
+    let note_script = "
+        # burn the asset
+        begin
+            dropw
+
+            # pad the stack before call
+            padw padw padw padw
+            # => [pad(16)]
+
+            exec.::miden::note::get_assets drop
+            mem_loadw
+            # => [ASSET, pad(12)]
+
+            call.::miden::contracts::faucets::basic_non_fungible::burn
+
+            # truncate the stack
+            dropw dropw dropw dropw
+        end
+        ";
+    let note = get_note_with_assets_and_script(
+        target_account.id(),
+        vec![non_fungible_asset.into()],
+        note_script,
+        [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)],
+    );
+
+    mock_chain.add_pending_note(note.clone());
+    mock_chain.seal_block(None);
+
+    // CONSTRUCT AND EXECUTE TX (Failure - burning is not authenticated by the faucet owner)
+    // --------------------------------------------------------------------------------------------
+    let unauthenticated_script =
+        TransactionScript::compile("begin push.0 drop end", vec![], TransactionKernel::assembler())
+            .unwrap();
+    let executed_transaction = mock_chain
+        .build_tx_context(faucet.id(), &[note.id()], &[])
+        .tx_script(unauthenticated_script)
+        .build()
+        .execute();
+
+    assert_transaction_executor_error!(
+        executed_transaction,
+        ERR_ACCOUNT_NONCE_DID_NOT_INCREASE_AFTER_STATE_CHANGE
+    );
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let executed_transaction = mock_chain
+        .build_tx_context(faucet.id(), &[note.id()], &[])
+        .build()
+        .execute()
+        .unwrap();
+
+    prove_and_verify_transaction(executed_transaction.clone()).unwrap();
+
+    // the faucet no longer records the asset as issued
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(3)));
+    let faucet = mock_chain.apply_executed_transaction(&executed_transaction);
+    assert_eq!(
+        faucet
+            .storage()
+            .get_map_item(FAUCET_STORAGE_DATA_SLOT, non_fungible_asset.vault_key())
+            .unwrap(),
+        Word::default()
+    );
+}
+
+// TESTS ROLE-BASED FUNGIBLE FAUCET
+// ================================================================================================
+
+#[test]
+fn prove_role_based_faucet_distribute_succeeds() {
+    let mut mock_chain = MockChain::new();
+    let admin = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let minter = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let faucet = mock_chain.add_existing_role_based_faucet(
+        RoleBasedFungibleFaucet::new(
+            TokenSymbol::new("STB").unwrap(),
+            6,
+            Felt::new(1000),
+            admin.id(),
+        )
+        .unwrap()
+        .with_roles(minter.id(), FaucetRoles::new().with_minter(Felt::new(150))),
+    );
+    let mut rng = RpoRandomCoin::new([ONE; 4]);
+    let recipient = [Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)];
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let note = distribute_request_note(minter.id(), 100, recipient, &mut rng);
+    let executed_transaction = execute_request(&mut mock_chain, faucet.id(), &note).unwrap();
+
+    prove_and_verify_transaction(executed_transaction.clone()).unwrap();
+
+    let fungible_asset: Asset = FungibleAsset::new(faucet.id(), 100).unwrap().into();
+    let output_note = executed_transaction.output_notes().get_note(0).clone();
+    let assets = NoteAssets::new(vec![fungible_asset]).unwrap();
+    assert_eq!(output_note.id(), NoteId::new(recipient.into(), assets.commitment()));
+
+    // the minted amount was deducted from the mint allowance of the minter
+    let faucet = apply_request(&mut mock_chain, &executed_transaction);
+    assert_eq!(
+        faucet
+            .storage()
+            .get_map_item(ROLES_SLOT, RoleBasedFungibleFaucet::roles_key(minter.id()).into())
+            .unwrap(),
+        Word::from(FaucetRoles::new().with_minter(Felt::new(50)))
+    );
+
+    // CONSTRUCT AND EXECUTE TX (Failure - the mint allowance is exceeded)
+    // --------------------------------------------------------------------------------------------
+    let note = distribute_request_note(minter.id(), 100, recipient, &mut rng);
+    let executed_transaction = execute_request(&mut mock_chain, faucet.id(), &note);
+
+    assert_transaction_executor_error!(
+        executed_transaction,
+        ERR_ROLE_BASED_FAUCET_MINT_ALLOWANCE_EXCEEDED
+    );
+
+    // CONSTRUCT AND EXECUTE TX (Failure - the sender is not a minter)
+    // --------------------------------------------------------------------------------------------
+    let note = distribute_request_note(admin.id(), 10, recipient, &mut rng);
+    let executed_transaction = execute_request(&mut mock_chain, faucet.id(), &note);
+
+    assert_transaction_executor_error!(
+        executed_transaction,
+        ERR_ROLE_BASED_FAUCET_SENDER_IS_NOT_MINTER
+    );
+}
+
+#[test]
+fn role_based_faucet_burn_and_pause() {
+    let mut mock_chain = MockChain::new();
+    let admin = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let minter = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let burner = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let faucet = mock_chain.add_existing_role_based_faucet(
+        RoleBasedFungibleFaucet::new(
+            TokenSymbol::new("STB").unwrap(),
+            6,
+            Felt::new(1000),
+            admin.id(),
+        )
+        .unwrap()
+        .with_roles(minter.id(), FaucetRoles::new().with_minter(Felt::new(1000)))
+        .with_roles(burner.id(), FaucetRoles::new().with_burner()),
+    );
+    let mut rng = RpoRandomCoin::new([ONE; 4]);
+    let recipient = [Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)];
+    let fungible_asset: Asset = FungibleAsset::new(faucet.id(), 100).unwrap().into();
+
+    // mint the tokens to be burned
+    let note = distribute_request_note(minter.id(), 100, recipient, &mut rng);
+    let executed_transaction = execute_request(&mut mock_chain, faucet.id(), &note).unwrap();
+    apply_request(&mut mock_chain, &executed_transaction);
+
+    // CONSTRUCT AND EXECUTE TX (Failure - the sender is not a burner)
+    // --------------------------------------------------------------------------------------------
+    let note = burn_request_note(minter.id(), fungible_asset, &mut rng);
+    let executed_transaction = execute_request(&mut mock_chain, faucet.id(), &note);
+
+    assert_transaction_executor_error!(
+        executed_transaction,
+        ERR_ROLE_BASED_FAUCET_SENDER_IS_NOT_BURNER
+    );
+
+    // CONSTRUCT AND EXECUTE TX (Failure - the sender is not an admin)
+    // --------------------------------------------------------------------------------------------
+    let note = admin_request_note(burner.id(), "pause", "padw padw padw padw", &mut rng);
+    let executed_transaction = execute_request(&mut mock_chain, faucet.id(), &note);
+
+    assert_transaction_executor_error!(
+        executed_transaction,
+        ERR_ROLE_BASED_FAUCET_SENDER_IS_NOT_ADMIN
+    );
+
+    // CONSTRUCT AND EXECUTE TX (Failure - minting and burning while the faucet is paused)
+    // --------------------------------------------------------------------------------------------
+    let note = admin_request_note(admin.id(), "pause", "padw padw padw padw", &mut rng);
+    let executed_transaction = execute_request(&mut mock_chain, faucet.id(), &note).unwrap();
+    apply_request(&mut mock_chain, &executed_transaction);
+
+    let note = burn_request_note(burner.id(), fungible_asset, &mut rng);
+    let executed_transaction = execute_request(&mut mock_chain, faucet.id(), &note);
+    assert_transaction_executor_error!(executed_transaction, ERR_ROLE_BASED_FAUCET_IS_PAUSED);
+
+    let note = distribute_request_note(minter.id(), 100, recipient, &mut rng);
+    let executed_transaction = execute_request(&mut mock_chain, faucet.id(), &note);
+    assert_transaction_executor_error!(executed_transaction, ERR_ROLE_BASED_FAUCET_IS_PAUSED);
+
+    // CONSTRUCT AND EXECUTE TX (Success - burning after the faucet is unpaused)
+    // --------------------------------------------------------------------------------------------
+    let note = admin_request_note(admin.id(), "unpause", "padw padw padw padw", &mut rng);
+    let executed_transaction = execute_request(&mut mock_chain, faucet.id(), &note).unwrap();
+    apply_request(&mut mock_chain, &executed_transaction);
+
+    let note = burn_request_note(burner.id(), fungible_asset, &mut rng);
+    let executed_transaction = execute_request(&mut mock_chain, faucet.id(), &note).unwrap();
+
+    // the total issuance of the faucet is back to zero
+    let faucet = apply_request(&mut mock_chain, &executed_transaction);
+    assert_eq!(
+        faucet.storage().get_item(FAUCET_STORAGE_DATA_SLOT).unwrap(),
+        Word::default().into()
+    );
+}
+
+#[test]
+fn role_based_faucet_administration() {
+    let mut mock_chain = MockChain::new();
+    let admin = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let minter = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let token_symbol = TokenSymbol::new("STB").unwrap();
+    let faucet = mock_chain.add_existing_role_based_faucet(
+        RoleBasedFungibleFaucet::new(token_symbol, 6, Felt::new(1000), admin.id()).unwrap(),
+    );
+    let mut rng = RpoRandomCoin::new([ONE; 4]);
+    let recipient = [Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)];
+
+    // CONSTRUCT AND EXECUTE TX (Success - granting the minter role)
+    // --------------------------------------------------------------------------------------------
+    let minter_roles = FaucetRoles::new().with_minter(Felt::new(20));
+    let note = set_roles_request_note(admin.id(), minter.id(), minter_roles.into(), &mut rng);
+    let executed_transaction = execute_request(&mut mock_chain, faucet.id(), &note).unwrap();
+
+    let faucet = apply_request(&mut mock_chain, &executed_transaction);
+    assert_eq!(
+        faucet
+            .storage()
+            .get_map_item(ROLES_SLOT, RoleBasedFungibleFaucet::roles_key(minter.id()).into())
+            .unwrap(),
+        Word::from(minter_roles)
+    );
+
+    let note = distribute_request_note(minter.id(), 20, recipient, &mut rng);
+    let executed_transaction = execute_request(&mut mock_chain, faucet.id(), &note).unwrap();
+    apply_request(&mut mock_chain, &executed_transaction);
+
+    // CONSTRUCT AND EXECUTE TX (Failure - the role flags are not booleans)
+    // --------------------------------------------------------------------------------------------
+    let invalid_roles = [Felt::new(2), ZERO, ZERO, ZERO];
+    let note = set_roles_request_note(admin.id(), minter.id(), invalid_roles, &mut rng);
+    let executed_transaction = execute_request(&mut mock_chain, faucet.id(), &note);
+
+    assert_transaction_executor_error!(
+        executed_transaction,
+        ERR_ROLE_BASED_FAUCET_ROLE_FLAG_IS_NOT_BOOLEAN
+    );
+
+    // CONSTRUCT AND EXECUTE TX (Failure - the sender is not an admin)
+    // --------------------------------------------------------------------------------------------
+    let note = set_max_supply_request_note(minter.id(), 500, &mut rng);
+    let executed_transaction = execute_request(&mut mock_chain, faucet.id(), &note);
+
+    assert_transaction_executor_error!(
+        executed_transaction,
+        ERR_ROLE_BASED_FAUCET_SENDER_IS_NOT_ADMIN
+    );
+
+    // CONSTRUCT AND EXECUTE TX (Failure - the max supply is lower than the total issuance)
+    // --------------------------------------------------------------------------------------------
+    let note = set_max_supply_request_note(admin.id(), 19, &mut rng);
+    let executed_transaction = execute_request(&mut mock_chain, faucet.id(), &note);
+
+    assert_transaction_executor_error!(
+        executed_transaction,
+        ERR_ROLE_BASED_FAUCET_MAX_SUPPLY_BELOW_TOTAL_ISSUANCE
+    );
+
+    // CONSTRUCT AND EXECUTE TX (Failure - the max supply is too large)
+    // --------------------------------------------------------------------------------------------
+    let note = set_max_supply_request_note(admin.id(), FungibleAsset::MAX_AMOUNT + 1, &mut rng);
+    let executed_transaction = execute_request(&mut mock_chain, faucet.id(), &note);
+
+    assert_transaction_executor_error!(
+        executed_transaction,
+        ERR_ROLE_BASED_FAUCET_MAX_SUPPLY_TOO_LARGE
+    );
+
+    // CONSTRUCT AND EXECUTE TX (Success - updating the max supply)
+    // --------------------------------------------------------------------------------------------
+    let note = set_max_supply_request_note(admin.id(), 20, &mut rng);
+    let executed_transaction = execute_request(&mut mock_chain, faucet.id(), &note).unwrap();
+
+    let faucet = apply_request(&mut mock_chain, &executed_transaction);
+    assert_eq!(
+        faucet.storage().get_item(METADATA_SLOT).unwrap(),
+        [Felt::new(20), Felt::new(6), token_symbol.into(), ZERO].into()
+    );
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a transaction script which distributes the specified non-fungible asset to the
+/// specified recipient, and authenticates the transaction.
+fn distribute_non_fungible_asset_script(
+    asset: NonFungibleAsset,
+    recipient: Word,
+    tag: NoteTag,
+    note_type: NoteType,
+) -> TransactionScript {
+    let tx_script_code = format!(
+        "
+            begin
+                # pad the stack before call
+                padw
+
+                push.{recipient}
+                push.{note_execution_hint}
+                push.{note_type}
+                push.0
+                push.{tag}
+                push.{asset}
+                # => [ASSET, tag, aux, note_type, execution_hint, RECIPIENT, pad(4)]
+
+                call.::miden::contracts::faucets::basic_non_fungible::distribute
+                # => [note_idx, pad(15)]
+
+                call.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
+                # => [note_idx, pad(15)]
+
+                # truncate the stack
+                dropw dropw dropw dropw
+            end
+            ",
+        asset = prepare_word(&asset.into()),
+        recipient = prepare_word(&recipient),
+        note_execution_hint = Felt::from(NoteExecutionHint::always()),
+        note_type = note_type as u8,
+        tag = u32::from(tag),
+    );
+
+    TransactionScript::compile(tx_script_code, vec![], TransactionKernel::testing_assembler())
+        .unwrap()
+}
+
+/// Returns a note sent by the specified sender which holds the specified assets and has the
+/// provided note script and serial number.
+fn get_note_with_assets_and_script(
+    sender: AccountId,
+    assets: Vec<Asset>,
+    note_script: &str,
+    serial_num: Word,
+) -> Note {
+    let note_script = NoteScript::compile(note_script, TransactionKernel::assembler()).unwrap();
+
+    let vault = NoteAssets::new(assets).unwrap();
+    let metadata =
+        NoteMetadata::new(sender, NoteType::Public, 1.into(), NoteExecutionHint::always(), ZERO)
+            .unwrap();
+    let recipient = NoteRecipient::new(serial_num, note_script, NoteInputs::new(vec![]).unwrap());
+
+    Note::new(vault, metadata, recipient)
+}
+
+/// Returns a note sent by `sender` which invokes the provided procedure of the role-based fungible
+/// faucet. `stack_inputs` must push the 16 inputs of the procedure onto the stack.
+fn role_based_faucet_request_note(
+    sender: AccountId,
+    assets: Vec<Asset>,
+    procedure: &str,
+    stack_inputs: &str,
+    rng: &mut RpoRandomCoin,
+) -> Note {
+    let note_script = format!(
+        "
+        begin
+            dropw
+
+            {stack_inputs}
+            call.::miden::contracts::faucets::role_based_fungible::{procedure}
+
+            # truncate the stack
+            dropw dropw dropw dropw
+        end
+        "
+    );
+
+    get_note_with_assets_and_script(sender, assets, &note_script, rng.draw_word())
+}
+
+/// Returns a note which requests the faucet to distribute `amount` tokens to `recipient`.
+fn distribute_request_note(
+    sender: AccountId,
+    amount: u64,
+    recipient: Word,
+    rng: &mut RpoRandomCoin,
+) -> Note {
+    let stack_inputs = format!(
+        "push.0.0.0 padw push.{recipient} push.{note_execution_hint} push.{note_type} push.0 push.{tag} push.{amount}",
+        recipient = prepare_word(&recipient),
+        note_execution_hint = Felt::from(NoteExecutionHint::always()),
+        note_type = NoteType::Private as u8,
+        tag = u32::from(NoteTag::for_local_use_case(0, 0).unwrap()),
+    );
+
+    role_based_faucet_request_note(sender, vec![], "distribute", &stack_inputs, rng)
+}
+
+/// Returns a note which holds `asset` and requests the faucet to burn it.
+fn burn_request_note(sender: AccountId, asset: Asset, rng: &mut RpoRandomCoin) -> Note {
+    let stack_inputs = "padw padw padw padw exec.::miden::note::get_assets drop mem_loadw";
+
+    role_based_faucet_request_note(sender, vec![asset], "burn", stack_inputs, rng)
+}
+
+/// Returns a note which invokes one of the administration procedures of the faucet.
+fn admin_request_note(
+    sender: AccountId,
+    procedure: &str,
+    stack_inputs: &str,
+    rng: &mut RpoRandomCoin,
+) -> Note {
+    role_based_faucet_request_note(sender, vec![], procedure, stack_inputs, rng)
+}
+
+/// Returns a note which requests the faucet to set the roles of `account_id` to `roles`.
+fn set_roles_request_note(
+    sender: AccountId,
+    account_id: AccountId,
+    roles: Word,
+    rng: &mut RpoRandomCoin,
+) -> Note {
+    let stack_inputs = format!(
+        "padw padw push.0.0 push.{suffix} push.{prefix} push.{roles}",
+        suffix = account_id.suffix(),
+        prefix = account_id.prefix().as_felt(),
+        roles = prepare_word(&roles),
+    );
+
+    admin_request_note(sender, "set_roles", &stack_inputs, rng)
+}
+
+/// Returns a note which requests the faucet to set its maximum supply to `max_supply`.
+fn set_max_supply_request_note(
+    sender: AccountId,
+    max_supply: u64,
+    rng: &mut RpoRandomCoin,
+) -> Note {
+    let stack_inputs = format!("push.0.0.0 padw padw padw push.{max_supply}");
+
+    admin_request_note(sender, "set_max_supply", &stack_inputs, rng)
+}
+
+/// Executes a transaction against the faucet which consumes the provided request note.
+fn execute_request(
+    mock_chain: &mut MockChain,
+    faucet_id: AccountId,
+    note: &Note,
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    mock_chain.build_tx_context(faucet_id, &[], &[note.clone()]).build().execute()
+}
+
+/// Applies the executed transaction to the mock chain and seals a block, returning the updated
+/// faucet.
+fn apply_request(
+    mock_chain: &mut MockChain,
+    executed_transaction: &ExecutedTransaction,
+) -> Account {
+    let faucet = mock_chain.apply_executed_transaction(executed_transaction);
+    mock_chain.seal_block(None);
+    faucet
+}
@@ -0,0 +1,206 @@
+use std::collections::BTreeMap;
+
+use assert_matches::assert_matches;
+use miden_lib::{
+    errors::tx_kernel_errors::{
+        ERR_HTLC_INVALID_PREIMAGE, ERR_HTLC_RECLAIM_ACCT_IS_NOT_SENDER,
+        ERR_HTLC_RECLAIM_HEIGHT_NOT_REACHED,
+    },
+    note::{
+        create_htlc_note,
+        utils::{
+            build_htlc_hashlock, build_htlc_preimage_advice, build_htlc_preimage_note,
+            extract_htlc_preimage,
+        },
+    },
+};
+use miden_objects::{
+    account::AccountId,
+    asset::{Asset, FungibleAsset},
+    block::BlockNumber,
+    crypto::rand::RpoRandomCoin,
+    note::{Note, NoteDetails, NoteExecutionMode, NoteTag, NoteType},
+    transaction::{ExecutedTransaction, OutputNote, TransactionArgs},
+    vm::AdviceMap,
+    Digest, Felt, Hasher, ONE, ZERO,
+};
+use miden_tx::{
+    testing::{Auth, MockChain},
+    TransactionExecutorError,
+};
+
+use vm_processor::ExecutionError;
+
+use crate::{assert_transaction_executor_error, prove_and_verify_transaction};
+
+#[test]
+fn htlc_script() {
+    let mut mock_chain = MockChain::new();
+    mock_chain.seal_block(Some(3));
+
+    let fungible_asset: Asset = FungibleAsset::mock(100);
+
+    let sender_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let target_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let malicious_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+
+    let preimage = [7u8; 32];
+    let wrong_preimage = [8u8; 32];
+    let hashlock = build_htlc_hashlock(&preimage);
+
+    let mut rng = RpoRandomCoin::new([ONE; 4]);
+    let mut create_note = |reclaim_height: BlockNumber| {
+        create_htlc_note(
+            sender_account.id(),
+            target_account.id(),
+            vec![fungible_asset],
+            NoteType::Public,
+            ZERO,
+            hashlock,
+            reclaim_height,
+            &mut rng,
+        )
+        .unwrap()
+    };
+    let note_in_time = create_note(7.into());
+    let note_reclaimable = create_note(2.into());
+
+    mock_chain.add_pending_note(note_in_time.clone());
+    mock_chain.add_pending_note(note_reclaimable.clone());
+    mock_chain.seal_block(None);
+
+    // --------------------------------------------------------------------------------------------
+    // Case "in time": Only the target account can consume the note, with the preimage.
+    // --------------------------------------------------------------------------------------------
+    // CONSTRUCT AND EXECUTE TX (Failure - Target Account provides wrong preimage)
+    let executed_transaction_1 =
+        consume_htlc_note(&mut mock_chain, target_account.id(), &note_in_time, &wrong_preimage);
+
+    assert_transaction_executor_error!(executed_transaction_1, ERR_HTLC_INVALID_PREIMAGE);
+
+    // CONSTRUCT AND EXECUTE TX (Failure - Target Account provides a preimage which is not encoded
+    // as u32 values)
+    let invalid_preimage = vec![Felt::new(u64::from(u32::MAX) + 1); 8];
+    assert_matches!(
+        consume_htlc_note_with_advice(
+            &mut mock_chain,
+            target_account.id(),
+            &note_in_time,
+            Hasher::hash_elements(&invalid_preimage),
+            [&[Felt::new(8)][..], &invalid_preimage].concat(),
+            vec![],
+        ),
+        Err(TransactionExecutorError::TransactionProgramExecutionFailed(
+            ExecutionError::NotU32Value(_, err_code)
+        )) if err_code == Felt::from(ERR_HTLC_INVALID_PREIMAGE)
+    );
+
+    // CONSTRUCT AND EXECUTE TX (Failure - Sender Account tries to consume too early)
+    let executed_transaction_2 =
+        consume_htlc_note(&mut mock_chain, sender_account.id(), &note_in_time, &preimage);
+
+    assert_transaction_executor_error!(executed_transaction_2, ERR_HTLC_RECLAIM_HEIGHT_NOT_REACHED);
+
+    // CONSTRUCT AND EXECUTE TX (Failure - Malicious Account tries to consume with the preimage)
+    let executed_transaction_3 =
+        consume_htlc_note(&mut mock_chain, malicious_account.id(), &note_in_time, &preimage);
+
+    assert_transaction_executor_error!(executed_transaction_3, ERR_HTLC_RECLAIM_ACCT_IS_NOT_SENDER);
+
+    // CONSTRUCT AND EXECUTE TX (Success - Target Account provides the preimage)
+    let executed_transaction_4 =
+        consume_htlc_note(&mut mock_chain, target_account.id(), &note_in_time, &preimage).unwrap();
+
+    let target_account_after = mock_chain.apply_executed_transaction(&executed_transaction_4);
+    assert_eq!(target_account_after.vault().assets().collect::<Vec<_>>(), vec![fungible_asset]);
+
+    // the sender can recover the preimage from the public note created for it by the transaction
+    assert_eq!(executed_transaction_4.output_notes().num_notes(), 1);
+    let preimage_note = match executed_transaction_4.output_notes().get_note(0) {
+        OutputNote::Full(note) => note,
+        _ => panic!("preimage note should be a full note"),
+    };
+    assert_eq!(preimage_note.metadata().note_type(), NoteType::Public);
+    assert_eq!(
+        preimage_note.metadata().tag(),
+        NoteTag::from_account_id(sender_account.id(), NoteExecutionMode::Local).unwrap()
+    );
+    assert!(preimage_note.assets().is_empty());
+
+    let recovered_preimage = extract_htlc_preimage(&preimage_note.into()).unwrap();
+    assert_eq!(recovered_preimage, preimage);
+    assert_eq!(build_htlc_hashlock(&recovered_preimage), hashlock);
+
+    assert!(prove_and_verify_transaction(executed_transaction_4).is_ok());
+
+    // --------------------------------------------------------------------------------------------
+    // Case "reclaimable": The sender account can consume the note without the preimage.
+    // --------------------------------------------------------------------------------------------
+    // CONSTRUCT AND EXECUTE TX (Failure - Target Account provides wrong preimage)
+    let executed_transaction_5 =
+        consume_htlc_note(&mut mock_chain, target_account.id(), &note_reclaimable, &wrong_preimage);
+
+    assert_transaction_executor_error!(executed_transaction_5, ERR_HTLC_INVALID_PREIMAGE);
+
+    // CONSTRUCT AND EXECUTE TX (Success - Sender Account reclaims the note)
+    let executed_transaction_6 = mock_chain
+        .build_tx_context(sender_account.id(), &[note_reclaimable.id()], &[])
+        .build()
+        .execute()
+        .unwrap();
+
+    assert_eq!(executed_transaction_6.account_delta().nonce(), Some(Felt::new(2)));
+    assert_eq!(
+        executed_transaction_6
+            .account_delta()
+            .vault()
+            .added_assets()
+            .collect::<Vec<_>>(),
+        vec![fungible_asset]
+    );
+}
+
+/// Consumes the provided HTLC note with the specified account, providing the preimage via the
+/// advice map and the note disclosing the preimage as an expected output note.
+fn consume_htlc_note(
+    mock_chain: &mut MockChain,
+    account_id: AccountId,
+    note: &Note,
+    preimage: &[u8; 32],
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let preimage_note = build_htlc_preimage_note(note, preimage).unwrap();
+    let (preimage_key, preimage) = build_htlc_preimage_advice(preimage);
+    consume_htlc_note_with_advice(
+        mock_chain,
+        account_id,
+        note,
+        preimage_key,
+        preimage,
+        vec![preimage_note],
+    )
+}
+
+/// Consumes the provided HTLC note with the specified account, providing the specified elements
+/// as the encoded preimage and the specified expected output notes.
+fn consume_htlc_note_with_advice(
+    mock_chain: &mut MockChain,
+    account_id: AccountId,
+    note: &Note,
+    preimage_key: Digest,
+    preimage: Vec<Felt>,
+    expected_output_notes: Vec<NoteDetails>,
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let mut tx_context = mock_chain.build_tx_context(account_id, &[note.id()], &[]).build();
+
+    let note_args = BTreeMap::from([(note.id(), preimage_key.into())]);
+    let mut tx_args = TransactionArgs::new(
+        tx_context.tx_args().tx_script().cloned(),
+        Some(note_args),
+        AdviceMap::from_iter([(preimage_key, preimage)]),
+    )
+    .with_advice_inputs(tx_context.tx_args().advice_inputs().clone());
+    tx_args.extend_expected_output_notes(expected_output_notes.iter());
+    tx_context.set_tx_args(tx_args);
+
+    tx_context.execute()
+}
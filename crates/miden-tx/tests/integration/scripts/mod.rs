@@ -1,4 +1,6 @@
 mod faucet;
+mod htlc;
 mod p2id;
 mod p2idr;
+mod pswap;
 mod swap;
@@ -0,0 +1,161 @@
+use std::collections::BTreeMap;
+
+use assert_matches::assert_matches;
+use miden_lib::{
+    errors::tx_kernel_errors::ERR_PSWAP_FILL_AMOUNT_EXCEEDS_REQUESTED_AMOUNT,
+    note::{create_partial_swap_note, utils::build_partial_swap_fill_notes},
+};
+use miden_objects::{
+    account::AccountId,
+    asset::FungibleAsset,
+    crypto::rand::RpoRandomCoin,
+    note::{Note, NoteDetails, NoteType},
+    transaction::{ExecutedTransaction, OutputNote, TransactionArgs},
+    vm::AdviceMap,
+    Felt, ZERO,
+};
+use miden_tx::{
+    testing::{Auth, MockChain},
+    TransactionExecutorError,
+};
+use vm_processor::ExecutionError;
+
+use crate::prove_and_verify_transaction;
+
+// Consumes a PSWAP note partially, then consumes the remainder PSWAP note completely, and proves
+// the transactions. The sender account then consumes both payback notes.
+#[test]
+fn prove_consume_partial_swap_note() {
+    let mut mock_chain = MockChain::new();
+    let offered_asset = mock_chain.add_new_faucet(Auth::BasicAuth, "USDT", 100000u64).mint(2000);
+    let requested_asset = mock_chain.add_new_faucet(Auth::BasicAuth, "ETH", 100000u64).mint(500);
+    let offered_faucet_id = offered_asset.unwrap_fungible().faucet_id();
+    let requested_faucet_id = requested_asset.unwrap_fungible().faucet_id();
+    let sender_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    let target_account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![requested_asset]);
+
+    let note = create_partial_swap_note(
+        sender_account.id(),
+        offered_asset.unwrap_fungible(),
+        requested_asset.unwrap_fungible(),
+        NoteType::Public,
+        Felt::new(0),
+        &mut RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+    mock_chain.add_pending_note(note.clone());
+    mock_chain.seal_block(None);
+
+    // FILL THE NOTE PARTIALLY
+    // --------------------------------------------------------------------------------------------
+
+    let (payback_note, remainder_note) = build_partial_swap_fill_notes(&note, 100).unwrap();
+    let remainder_note = remainder_note.unwrap();
+
+    let partial_fill_tx = consume_partial_swap_note(
+        &mut mock_chain,
+        target_account.id(),
+        &note,
+        100,
+        &[payback_note.clone(), remainder_note.clone()],
+    )
+    .unwrap();
+    let target_account = mock_chain.apply_executed_transaction(&partial_fill_tx);
+    mock_chain.seal_block(None);
+
+    // the consumer receives 2000 * 100 / 500 = 400 of the offered asset
+    assert_eq!(target_account.vault().get_balance(offered_faucet_id).unwrap(), 400);
+    assert_eq!(target_account.vault().get_balance(requested_faucet_id).unwrap(), 400);
+
+    let output_notes: Vec<_> = partial_fill_tx.output_notes().iter().cloned().collect();
+    assert_eq!(output_notes.len(), 2);
+    assert_eq!(output_notes[0].id(), payback_note.id());
+    assert_eq!(output_notes[1].id(), remainder_note.id());
+    assert_eq!(
+        remainder_note.assets().iter().next().unwrap(),
+        &FungibleAsset::new(offered_faucet_id, 1600).unwrap().into()
+    );
+    let full_payback_note_1 = full_note(&payback_note, &output_notes[0]);
+    let OutputNote::Full(remainder_note) = output_notes[1].clone() else {
+        panic!("remainder note must be public");
+    };
+
+    assert!(prove_and_verify_transaction(partial_fill_tx).is_ok());
+
+    // FILL THE REMAINDER NOTE COMPLETELY
+    // --------------------------------------------------------------------------------------------
+
+    // a fill amount of 0 fills the note completely
+    let (payback_note, no_remainder_note) =
+        build_partial_swap_fill_notes(&remainder_note, 0).unwrap();
+    assert!(no_remainder_note.is_none());
+
+    // the fill amount must not exceed the requested amount
+    assert_matches!(
+        consume_partial_swap_note(&mut mock_chain, target_account.id(), &remainder_note, 401, &[]),
+        Err(TransactionExecutorError::TransactionProgramExecutionFailed(
+            ExecutionError::FailedAssertion { err_code, .. }
+        )) if err_code == ERR_PSWAP_FILL_AMOUNT_EXCEEDS_REQUESTED_AMOUNT
+    );
+
+    let complete_fill_tx = consume_partial_swap_note(
+        &mut mock_chain,
+        target_account.id(),
+        &remainder_note,
+        0,
+        &[payback_note.clone()],
+    )
+    .unwrap();
+    let target_account = mock_chain.apply_executed_transaction(&complete_fill_tx);
+
+    assert_eq!(target_account.vault().get_balance(offered_faucet_id).unwrap(), 2000);
+    assert_eq!(target_account.vault().get_balance(requested_faucet_id).unwrap(), 0);
+
+    assert_eq!(complete_fill_tx.output_notes().num_notes(), 1);
+    let output_payback_note = complete_fill_tx.output_notes().get_note(0).clone();
+    assert_eq!(output_payback_note.id(), payback_note.id());
+    let full_payback_note_2 = full_note(&payback_note, &output_payback_note);
+
+    assert!(prove_and_verify_transaction(complete_fill_tx).is_ok());
+
+    // CONSUME PAYBACK P2ID NOTES
+    // --------------------------------------------------------------------------------------------
+
+    let consume_payback_tx = mock_chain
+        .build_tx_context(sender_account.id(), &[], &[full_payback_note_1, full_payback_note_2])
+        .build()
+        .execute()
+        .unwrap();
+
+    let sender_account = mock_chain.apply_executed_transaction(&consume_payback_tx);
+    assert_eq!(sender_account.vault().get_balance(requested_faucet_id).unwrap(), 500);
+}
+
+/// Consumes the provided PSWAP note with the specified account, providing the fill amount via the
+/// note args and the notes created by the PSWAP note as expected output notes.
+fn consume_partial_swap_note(
+    mock_chain: &mut MockChain,
+    account_id: AccountId,
+    note: &Note,
+    fill_amount: u64,
+    expected_output_notes: &[NoteDetails],
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let mut tx_context = mock_chain.build_tx_context(account_id, &[note.id()], &[]).build();
+
+    let note_args = BTreeMap::from([(note.id(), [Felt::new(fill_amount), ZERO, ZERO, ZERO])]);
+    let mut tx_args = TransactionArgs::new(
+        tx_context.tx_args().tx_script().cloned(),
+        Some(note_args),
+        AdviceMap::default(),
+    )
+    .with_advice_inputs(tx_context.tx_args().advice_inputs().clone());
+    tx_args.extend_expected_output_notes(expected_output_notes.iter());
+    tx_context.set_tx_args(tx_args);
+
+    tx_context.execute()
+}
+
+/// Returns the full note built from the details of a private output note and its metadata.
+fn full_note(details: &NoteDetails, output_note: &OutputNote) -> Note {
+    Note::new(details.assets().clone(), *output_note.metadata(), details.recipient().clone())
+}